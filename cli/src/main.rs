@@ -33,28 +33,46 @@ async fn process_setup_extra_metas(
     payer: &Arc<dyn Signer>,
     mint_address: &Pubkey,
     lists: &[Pubkey],
+    freeze: bool,
 ) -> Result<Signature, Box<dyn Error>> {
     let token_acl_mint_config = token_acl_client::accounts::MintConfig::find_pda(mint_address).0;
     let extra_metas = token_acl_interface::get_thaw_extra_account_metas_address(
         mint_address,
         &token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID,
     );
-    let ix = token_acl_gate_client::instructions::SetupExtraMetasBuilder::new()
-        .authority(payer.pubkey())
-        .payer(payer.pubkey())
-        .token_acl_mint_config(token_acl_mint_config)
-        .mint(*mint_address)
-        .extra_metas(extra_metas)
-        .add_remaining_accounts(
-            lists
-                .iter()
-                .map(|list| AccountMeta::new_readonly(*list, false))
-                .collect::<Vec<_>>()
-                .as_slice(),
-        )
-        .instruction();
+    let list_metas = lists
+        .iter()
+        .map(|list| AccountMeta::new_readonly(*list, false))
+        .collect::<Vec<_>>();
+    let mut ixs = vec![
+        token_acl_gate_client::instructions::SetupExtraMetasBuilder::new()
+            .authority(payer.pubkey())
+            .payer(payer.pubkey())
+            .token_acl_mint_config(token_acl_mint_config)
+            .mint(*mint_address)
+            .extra_metas(extra_metas)
+            .add_remaining_accounts(&list_metas)
+            .instruction(),
+    ];
 
-    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+    if freeze {
+        let freeze_extra_metas = token_acl_interface::get_freeze_extra_account_metas_address(
+            mint_address,
+            &token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID,
+        );
+        ixs.push(
+            token_acl_gate_client::instructions::SetupFreezeExtraMetasBuilder::new()
+                .authority(payer.pubkey())
+                .payer(payer.pubkey())
+                .token_acl_mint_config(token_acl_mint_config)
+                .mint(*mint_address)
+                .freeze_extra_metas(freeze_extra_metas)
+                .add_remaining_accounts(&list_metas)
+                .instruction(),
+        );
+    }
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&ixs, Some(&payer.pubkey())));
 
     let blockhash = rpc_client
         .get_latest_blockhash()
@@ -73,10 +91,30 @@ async fn process_setup_extra_metas(
     Ok(signature)
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn process_create_list(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
     mode: token_acl_gate_client::types::Mode,
+    namespace: [u8; 16],
+    enroll_window_secs: u64,
+    max_enrolls_per_window: u32,
+    key_by_token_account: bool,
+    warmup_secs: u64,
+    token_program: Pubkey,
+    transfer_hook_check: u8,
+    appeal_period_secs: u64,
+    require_nonzero_balance: bool,
+    allowed_window: (u32, u32),
+    require_authority_cosign: bool,
+    allow_threshold: u8,
+    allow_freeze_authority_delegate: bool,
+    strict_block_mode: bool,
+    allow_precedence: bool,
+    sunset_at: i64,
+    max_wallets: u32,
+    allow_multisig_owner: bool,
+    owner_extensions: u8,
 ) -> Result<Signature, Box<dyn Error>> {
     let seed = Keypair::new().pubkey();
     let list_config =
@@ -85,6 +123,26 @@ async fn process_create_list(
         .authority(payer.pubkey())
         .seed(seed)
         .mode(mode)
+        .namespace(namespace)
+        .enroll_window_secs(enroll_window_secs)
+        .max_enrolls_per_window(max_enrolls_per_window)
+        .key_by_token_account(key_by_token_account)
+        .warmup_secs(warmup_secs)
+        .token_program(token_program)
+        .transfer_hook_check(transfer_hook_check)
+        .appeal_period_secs(appeal_period_secs)
+        .require_nonzero_balance(require_nonzero_balance)
+        .allowed_window_start(allowed_window.0)
+        .allowed_window_end(allowed_window.1)
+        .require_authority_cosign(require_authority_cosign)
+        .allow_threshold(allow_threshold)
+        .allow_freeze_authority_delegate(allow_freeze_authority_delegate)
+        .strict_block_mode(strict_block_mode)
+        .allow_precedence(allow_precedence)
+        .sunset_at(sunset_at)
+        .max_wallets(max_wallets)
+        .allow_multisig_owner(allow_multisig_owner)
+        .owner_extensions(owner_extensions)
         .list_config(list_config)
         .instruction();
 
@@ -139,19 +197,286 @@ async fn process_delete_list(
     Ok(signature)
 }
 
+async fn process_update_mode(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    mode: token_acl_gate_client::types::Mode,
+    acknowledge: bool,
+    eoa_grace_period_secs: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = token_acl_gate_client::instructions::UpdateModeBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .mode(mode)
+        .acknowledge(acknowledge)
+        .eoa_grace_period_secs(eoa_grace_period_secs)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_max_wallets(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    max_wallets: u32,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = token_acl_gate_client::client::PolicyUpdate {
+        max_wallets: Some(max_wallets),
+        ..Default::default()
+    }
+    .builder()
+    .authority(payer.pubkey())
+    .list_config(*list_address)
+    .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_update_merkle_root(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    merkle_root: [u8; 32],
+    expected_root_version: Option<u64>,
+) -> Result<Signature, Box<dyn Error>> {
+    let mut builder = token_acl_gate_client::instructions::UpdateMerkleRootBuilder::new();
+    builder
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .merkle_root(merkle_root);
+    let ix = match expected_root_version {
+        Some(root_version) => builder.instruction_expecting(root_version),
+        None => builder.instruction(),
+    };
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_paused(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    paused: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = token_acl_gate_client::instructions::SetPausedBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .paused(paused)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_frozen(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    frozen: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = token_acl_gate_client::instructions::SetFrozenBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .frozen(frozen)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_manager(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    manager: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = token_acl_gate_client::instructions::SetManagerBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .manager(*manager)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_close_merkle_proof(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    member: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let (merkle_proof, _) =
+        token_acl_gate_client::accounts::MerkleProof::find_pda(list_address, member);
+    let ix = token_acl_gate_client::instructions::CloseMerkleProofBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .merkle_proof(merkle_proof)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn get_list_namespace(
+    rpc_client: &Arc<RpcClient>,
+    list_address: &Pubkey,
+) -> Result<[u8; 16], Box<dyn Error>> {
+    let data = rpc_client
+        .get_account_data(list_address)
+        .await
+        .map_err(|err| format!("error: unable to fetch list {}: {}", list_address, err))?;
+    let list_config = token_acl_gate_client::accounts::ListConfig::from_bytes(&data)
+        .map_err(|err| format!("error: unable to decode list {}: {}", list_address, err))?;
+
+    Ok(list_config.namespace)
+}
+
+/// Pads a wallet entry label with zeros to its 32 bytes.
+fn parse_label(label: &str) -> Result<[u8; 32], String> {
+    let mut bytes = [0; 32];
+    bytes
+        .get_mut(..label.len())
+        .ok_or_else(|| format!("label is {} bytes, at most 32 fit", label.len()))?
+        .copy_from_slice(label.as_bytes());
+    Ok(bytes)
+}
+
 async fn process_add_wallet(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
     wallet_address: &Pubkey,
     list_address: &Pubkey,
+    expires_at: i64,
+    label: [u8; 32],
+    idempotent: bool,
 ) -> Result<Signature, Box<dyn Error>> {
+    let namespace = get_list_namespace(rpc_client, list_address).await?;
     let ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
         .authority(payer.pubkey())
         .list_config(*list_address)
         .wallet(*wallet_address)
         .wallet_entry(
-            token_acl_gate_client::accounts::WalletEntry::find_pda(list_address, wallet_address).0,
+            token_acl_gate_client::accounts::WalletEntry::find_pda(
+                &namespace,
+                list_address,
+                wallet_address,
+            )
+            .0,
         )
+        .expires_at(expires_at)
+        .label(label)
+        .idempotent(idempotent)
         .instruction();
 
     let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
@@ -179,11 +504,17 @@ async fn process_remove_wallet(
     wallet_address: &Pubkey,
     list_address: &Pubkey,
 ) -> Result<Signature, Box<dyn Error>> {
+    let namespace = get_list_namespace(rpc_client, list_address).await?;
     let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
         .authority(payer.pubkey())
         .list_config(*list_address)
         .wallet_entry(
-            token_acl_gate_client::accounts::WalletEntry::find_pda(list_address, wallet_address).0,
+            token_acl_gate_client::accounts::WalletEntry::find_pda(
+                &namespace,
+                list_address,
+                wallet_address,
+            )
+            .0,
         )
         .instruction();
 
@@ -264,10 +595,168 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .takes_value(true)
                         .short('m')
                         .long("mode")
-                        .possible_values(["allow", "allow-all-eoas", "block"])
+                        .possible_values(["allow", "allow-all-eoas", "allow-all-pdas", "block", "merkle-allow"])
                         .required(true)
                         .help("Specify the mode"),
                 )
+                .arg(
+                    Arg::new("namespace")
+                        .value_name("NAMESPACE")
+                        .takes_value(true)
+                        .short('n')
+                        .long("namespace")
+                        .validator(|namespace| {
+                            if namespace.len() > 16 {
+                                Err("namespace must be at most 16 bytes")
+                            } else {
+                                Ok(())
+                            }
+                        })
+                        .help("Specify the namespace the list's wallet entries are scoped to [default: empty]"),
+                )
+                .arg(
+                    Arg::new("enroll_window_secs")
+                        .value_name("SECONDS")
+                        .takes_value(true)
+                        .long("enroll-window-secs")
+                        .value_parser(clap::value_parser!(u64))
+                        .requires("max_enrolls_per_window")
+                        .help("Length of the window new enrollments are rate limited over [default: no limit]"),
+                )
+                .arg(
+                    Arg::new("max_enrolls_per_window")
+                        .value_name("COUNT")
+                        .takes_value(true)
+                        .long("max-enrolls-per-window")
+                        .value_parser(clap::value_parser!(u32))
+                        .requires("enroll_window_secs")
+                        .help("Maximum number of new enrollments per window"),
+                )
+                .arg(
+                    Arg::new("key_by_token_account")
+                        .long("key-by-token-account")
+                        .takes_value(false)
+                        .help("Key wallet entries by token account address instead of owner"),
+                )
+                .arg(
+                    Arg::new("warmup_secs")
+                        .value_name("SECONDS")
+                        .takes_value(true)
+                        .long("warmup-secs")
+                        .value_parser(clap::value_parser!(u64))
+                        .help("Allow all thaws for this long after creation while entries are populated [default: 0]"),
+                )
+                .arg(
+                    Arg::new("token_program")
+                        .value_name("TOKEN_PROGRAM")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .long("token-program")
+                        .help("Only gate token accounts owned by this token program [default: any]"),
+                )
+                .arg(
+                    Arg::new("transfer_hook_check")
+                        .value_name("CHECK")
+                        .takes_value(true)
+                        .long("transfer-hook-check")
+                        .possible_values(["off", "warn", "error"])
+                        .help("How to handle mints whose transfer hook isn't the gate program [default: off]"),
+                )
+                .arg(
+                    Arg::new("appeal_period_secs")
+                        .value_name("SECONDS")
+                        .takes_value(true)
+                        .long("appeal-period-secs")
+                        .value_parser(clap::value_parser!(u64))
+                        .help("Lift block entries this long after they are added unless reaffirmed [default: never]"),
+                )
+                .arg(
+                    Arg::new("require_nonzero_balance")
+                        .long("require-nonzero-balance")
+                        .takes_value(false)
+                        .help("In allow mode, also deny thaws of token accounts with a zero balance"),
+                )
+                .arg(
+                    Arg::new("allowed_window_start")
+                        .value_name("SECOND_OF_DAY")
+                        .takes_value(true)
+                        .long("allowed-window-start")
+                        .value_parser(clap::value_parser!(u32).range(..86400))
+                        .requires("allowed_window_end")
+                        .help("Only allow thaws from this second of the day, UTC [default: any time]"),
+                )
+                .arg(
+                    Arg::new("allowed_window_end")
+                        .value_name("SECOND_OF_DAY")
+                        .takes_value(true)
+                        .long("allowed-window-end")
+                        .value_parser(clap::value_parser!(u32).range(..86400))
+                        .requires("allowed_window_start")
+                        .help("Only allow thaws until this second of the day, UTC, wrapping past midnight when before the start"),
+                )
+                .arg(
+                    Arg::new("require_authority_cosign")
+                        .long("require-authority-cosign")
+                        .takes_value(false)
+                        .help("Also require the list authority to sign every thaw"),
+                )
+                .arg(
+                    Arg::new("allow_threshold")
+                        .value_name("COUNT")
+                        .takes_value(true)
+                        .long("allow-threshold")
+                        .value_parser(clap::value_parser!(u8))
+                        .help("In allow mode, require the owner on at least this many allow lists once every allow list sets a threshold [default: 0]"),
+                )
+                .arg(
+                    Arg::new("allow_freeze_authority_delegate")
+                        .long("allow-freeze-authority-delegate")
+                        .takes_value(false)
+                        .help("Allow thaws of token accounts delegated to the mint's freeze authority without checking the list"),
+                )
+                .arg(
+                    Arg::new("strict_block_mode")
+                        .long("strict-block-mode")
+                        .takes_value(false)
+                        .help("In block mode, check that the wallet entry passed for a thaw is the owner's entry even when it doesn't exist"),
+                )
+                .arg(
+                    Arg::new("allow_precedence")
+                        .long("allow-precedence")
+                        .takes_value(false)
+                        .help("In block mode, let owners with an entry on one of the mint's allow lists thaw even when blocked"),
+                )
+                .arg(
+                    Arg::new("sunset_at")
+                        .value_name("UNIX_TIMESTAMP")
+                        .takes_value(true)
+                        .long("sunset-at")
+                        .value_parser(clap::value_parser!(i64))
+                        .help("Allow all thaws from this time on, 0 never sunsets [default: 0]"),
+                )
+                .arg(
+                    Arg::new("max_wallets")
+                        .value_name("MAX_WALLETS")
+                        .takes_value(true)
+                        .long("max-wallets")
+                        .value_parser(clap::value_parser!(u32))
+                        .help("Maximum number of wallets on the list, 0 is unlimited [default: 0]"),
+                )
+                .arg(
+                    Arg::new("allow_multisig_owner")
+                        .long("allow-multisig-owner")
+                        .takes_value(false)
+                        .help("Let token accounts owned by a token-2022 multisig thaw without the immutable owner extension, when every list checked allows it"),
+                )
+                .arg(
+                    Arg::new("owner_extensions")
+                        .long("owner-extension")
+                        .value_name("EXTENSION")
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .possible_values(["immutable-owner", "non-transferable"])
+                        .help("Token account extension accepted to guard the owner, can be repeated [default: immutable-owner]"),
+                )
         )
         .subcommand(
             Command::new("delete-list")
@@ -280,6 +769,176 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .index(1)
                         .help("Specify the list address"),
         ))
+        .subcommand(
+            Command::new("update-mode")
+                .about("Switches the mode of a list, existing entries are reinterpreted by the new mode")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("mode")
+                        .value_name("MODE")
+                        .takes_value(true)
+                        .short('m')
+                        .long("mode")
+                        .possible_values(["allow", "allow-all-eoas", "allow-all-pdas", "block", "merkle-allow"])
+                        .required(true)
+                        .help("Specify the new mode"),
+                )
+                .arg(
+                    Arg::new("acknowledge")
+                        .long("acknowledge")
+                        .takes_value(false)
+                        .help("Acknowledge that moving from allow-all-eoas to allow denies every EOA without an entry"),
+                )
+                .arg(
+                    Arg::new("eoa_grace_period")
+                        .value_name("SECONDS")
+                        .takes_value(true)
+                        .long("eoa-grace-period")
+                        .value_parser(clap::value_parser!(u64))
+                        .help("When moving from allow-all-eoas to allow, keep allowing EOAs without an entry for this long [default: 0]"),
+                ),
+        )
+        .subcommand(
+            Command::new("set-max-wallets")
+                .about("Sets the maximum number of wallets on a list, 0 is unlimited")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("max_wallets")
+                        .value_name("MAX_WALLETS")
+                        .takes_value(true)
+                        .index(2)
+                        .value_parser(clap::value_parser!(u32))
+                        .required(true)
+                        .help("Specify the maximum number of wallets"),
+                ),
+        )
+        .subcommand(
+            Command::new("update-merkle-root")
+                .about("Sets the merkle root of the members of a merkle-allow list")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("merkle_root")
+                        .value_name("MERKLE_ROOT")
+                        .takes_value(true)
+                        .index(2)
+                        .value_parser(clap::value_parser!(solana_sdk::hash::Hash))
+                        .required(true)
+                        .help("Specify the merkle root, base58 encoded"),
+                )
+                .arg(
+                    Arg::new("expected_root_version")
+                        .value_name("ROOT_VERSION")
+                        .takes_value(true)
+                        .long("expected-root-version")
+                        .value_parser(clap::value_parser!(u64))
+                        .help("Only replace the root while the list is still at this root version"),
+                ),
+        )
+        .subcommand(
+            Command::new("set-paused")
+                .about("Pauses a list, denying every thaw checked against it, or resumes it")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("paused")
+                        .value_name("PAUSED")
+                        .takes_value(true)
+                        .index(2)
+                        .value_parser(clap::value_parser!(bool))
+                        .required(true)
+                        .help("Specify true to pause the list, false to resume it"),
+                ),
+        )
+        .subcommand(
+            Command::new("set-frozen")
+                .about("Freezes the entries and policy of a list, thaws are decided as usual, or unfreezes it")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("frozen")
+                        .value_name("FROZEN")
+                        .takes_value(true)
+                        .index(2)
+                        .value_parser(clap::value_parser!(bool))
+                        .required(true)
+                        .help("Specify true to freeze the list, false to unfreeze it"),
+                ),
+        )
+        .subcommand(
+            Command::new("close-merkle-proof")
+                .about("Closes the stored merkle proof of a member, refunding its rent to the signer")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("member")
+                        .value_name("MEMBER")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(2)
+                        .required(true)
+                        .help("Specify the member whose proof to close"),
+                ),
+        )
+        .subcommand(
+            Command::new("set-manager")
+                .about("Sets the key that can add and remove wallets alongside the authority")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("manager")
+                        .value_name("MANAGER")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(2)
+                        .required(true)
+                        .help("Specify the manager address, the default address removes the manager"),
+                ),
+        )
         .subcommand(
             Command::new("add-wallet")
                 .about("Adds a wallet to a list")
@@ -299,6 +958,28 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .index(2)
                         .help("Specify the wallet address to add"),
                 )
+                .arg(
+                    Arg::new("expires_at")
+                        .value_name("UNIX_TIMESTAMP")
+                        .takes_value(true)
+                        .long("expires-at")
+                        .value_parser(clap::value_parser!(i64).range(0..))
+                        .help("Stop counting the entry from this time on [default: never]"),
+                )
+                .arg(
+                    Arg::new("label")
+                        .value_name("LABEL")
+                        .takes_value(true)
+                        .long("label")
+                        .value_parser(parse_label)
+                        .help("Record why the wallet is added, up to 32 bytes of UTF-8"),
+                )
+                .arg(
+                    Arg::new("idempotent")
+                        .long("idempotent")
+                        .takes_value(false)
+                        .help("Succeed without changes when the wallet is already on the list"),
+                )
         )
         .subcommand(
             Command::new("remove-wallet")
@@ -340,6 +1021,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .index(2)
                         .help("Specify the list(s) address(es)"),
                 )
+                .arg(
+                    Arg::new("freeze")
+                        .long("freeze")
+                        .takes_value(false)
+                        .help("Also configure the extra metas for the mint permissionless freeze"),
+                )
                 ,
         )
         .get_matches();
@@ -396,48 +1083,285 @@ async fn main() -> Result<(), Box<dyn Error>> {
             let mode = match mode.as_str() {
                 "allow" => token_acl_gate_client::types::Mode::Allow,
                 "allow-all-eoas" => token_acl_gate_client::types::Mode::AllowAllEoas,
+                "allow-all-pdas" => token_acl_gate_client::types::Mode::AllowAllPdas,
                 "block" => token_acl_gate_client::types::Mode::Block,
+                "merkle-allow" => token_acl_gate_client::types::Mode::MerkleAllow,
                 _ => unreachable!(),
             };
-            let response = process_create_list(&rpc_client, &config.payer, mode)
+            let mut namespace = [0u8; 16];
+            if let Some(value) = arg_matches.get_one::<String>("namespace") {
+                namespace[..value.len()].copy_from_slice(value.as_bytes());
+            }
+            let enroll_window_secs = arg_matches
+                .get_one::<u64>("enroll_window_secs")
+                .copied()
+                .unwrap_or(0);
+            let max_enrolls_per_window = arg_matches
+                .get_one::<u32>("max_enrolls_per_window")
+                .copied()
+                .unwrap_or(0);
+            let warmup_secs = arg_matches
+                .get_one::<u64>("warmup_secs")
+                .copied()
+                .unwrap_or(0);
+            let token_program =
+                SignerSource::try_get_pubkey(arg_matches, "token_program", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap_or_default();
+            let transfer_hook_check = match arg_matches
+                .get_one::<String>("transfer_hook_check")
+                .map(String::as_str)
+            {
+                Some("warn") => 1,
+                Some("error") => 2,
+                _ => 0,
+            };
+            let appeal_period_secs = arg_matches
+                .get_one::<u64>("appeal_period_secs")
+                .copied()
+                .unwrap_or(0);
+            let allowed_window = (
+                arg_matches
+                    .get_one::<u32>("allowed_window_start")
+                    .copied()
+                    .unwrap_or(0),
+                arg_matches
+                    .get_one::<u32>("allowed_window_end")
+                    .copied()
+                    .unwrap_or(0),
+            );
+            let response = process_create_list(
+                &rpc_client,
+                &config.payer,
+                mode,
+                namespace,
+                enroll_window_secs,
+                max_enrolls_per_window,
+                arg_matches.contains_id("key_by_token_account"),
+                warmup_secs,
+                token_program,
+                transfer_hook_check,
+                appeal_period_secs,
+                arg_matches.contains_id("require_nonzero_balance"),
+                allowed_window,
+                arg_matches.contains_id("require_authority_cosign"),
+                arg_matches
+                    .get_one::<u8>("allow_threshold")
+                    .copied()
+                    .unwrap_or(0),
+                arg_matches.contains_id("allow_freeze_authority_delegate"),
+                arg_matches.contains_id("strict_block_mode"),
+                arg_matches.contains_id("allow_precedence"),
+                arg_matches
+                    .get_one::<i64>("sunset_at")
+                    .copied()
+                    .unwrap_or(0),
+                arg_matches
+                    .get_one::<u32>("max_wallets")
+                    .copied()
+                    .unwrap_or(0),
+                arg_matches.contains_id("allow_multisig_owner"),
+                arg_matches
+                    .get_many::<String>("owner_extensions")
+                    .into_iter()
+                    .flatten()
+                    .fold(0, |owner_extensions, extension| {
+                        owner_extensions
+                            | match extension.as_str() {
+                                "immutable-owner" => 1 << 0,
+                                "non-transferable" => 1 << 1,
+                                _ => unreachable!(),
+                            }
+                    }),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: create-list: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("delete-list", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_delete_list(&rpc_client, &config.payer, &list_address)
                 .await
                 .unwrap_or_else(|err| {
-                    eprintln!("error: create-list: {}", err);
+                    eprintln!("error: delete-list: {}", err);
                     exit(1);
                 });
             println!("{}", response);
         }
-        ("delete-list", arg_matches) => {
+        ("update-mode", arg_matches) => {
             let list_address =
                 SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
-            let response = process_delete_list(&rpc_client, &config.payer, &list_address)
+            let mode = arg_matches.get_one::<String>("mode").unwrap();
+            let mode = match mode.as_str() {
+                "allow" => token_acl_gate_client::types::Mode::Allow,
+                "allow-all-eoas" => token_acl_gate_client::types::Mode::AllowAllEoas,
+                "allow-all-pdas" => token_acl_gate_client::types::Mode::AllowAllPdas,
+                "block" => token_acl_gate_client::types::Mode::Block,
+                "merkle-allow" => token_acl_gate_client::types::Mode::MerkleAllow,
+                _ => unreachable!(),
+            };
+            let acknowledge = arg_matches.contains_id("acknowledge");
+            let eoa_grace_period_secs = arg_matches
+                .get_one::<u64>("eoa_grace_period")
+                .copied()
+                .unwrap_or(0);
+            let response = process_update_mode(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                mode,
+                acknowledge,
+                eoa_grace_period_secs,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: update-mode: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-max-wallets", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let max_wallets = *arg_matches.get_one::<u32>("max_wallets").unwrap();
+            let response =
+                process_set_max_wallets(&rpc_client, &config.payer, &list_address, max_wallets)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: set-max-wallets: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("update-merkle-root", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let merkle_root = arg_matches
+                .get_one::<solana_sdk::hash::Hash>("merkle_root")
+                .unwrap()
+                .to_bytes();
+            let expected_root_version =
+                arg_matches.get_one::<u64>("expected_root_version").copied();
+            let response = process_update_merkle_root(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                merkle_root,
+                expected_root_version,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: update-merkle-root: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-paused", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let paused = *arg_matches.get_one::<bool>("paused").unwrap();
+            let response = process_set_paused(&rpc_client, &config.payer, &list_address, paused)
                 .await
                 .unwrap_or_else(|err| {
-                    eprintln!("error: delete-list: {}", err);
+                    eprintln!("error: set-paused: {}", err);
                     exit(1);
                 });
             println!("{}", response);
         }
-        ("add-wallet", arg_matches) => {
-            let wallet_address =
-                SignerSource::try_get_pubkey(arg_matches, "wallet_address", &mut wallet_manager)
+        ("set-frozen", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
+            let frozen = *arg_matches.get_one::<bool>("frozen").unwrap();
+            let response = process_set_frozen(&rpc_client, &config.payer, &list_address, frozen)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: set-frozen: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("close-merkle-proof", arg_matches) => {
             let list_address =
                 SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
+            let member = SignerSource::try_get_pubkey(arg_matches, "member", &mut wallet_manager)
+                .unwrap()
+                .unwrap();
             let response =
-                process_add_wallet(&rpc_client, &config.payer, &wallet_address, &list_address)
+                process_close_merkle_proof(&rpc_client, &config.payer, &list_address, &member)
                     .await
                     .unwrap_or_else(|err| {
-                        eprintln!("error: add-wallet: {}", err);
+                        eprintln!("error: close-merkle-proof: {}", err);
                         exit(1);
                     });
             println!("{}", response);
         }
+        ("set-manager", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let manager = SignerSource::try_get_pubkey(arg_matches, "manager", &mut wallet_manager)
+                .unwrap()
+                .unwrap();
+            let response = process_set_manager(&rpc_client, &config.payer, &list_address, &manager)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: set-manager: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("add-wallet", arg_matches) => {
+            let wallet_address =
+                SignerSource::try_get_pubkey(arg_matches, "wallet_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let expires_at = arg_matches
+                .get_one::<i64>("expires_at")
+                .copied()
+                .unwrap_or(0);
+            let label = arg_matches
+                .get_one::<[u8; 32]>("label")
+                .copied()
+                .unwrap_or_default();
+            let response = process_add_wallet(
+                &rpc_client,
+                &config.payer,
+                &wallet_address,
+                &list_address,
+                expires_at,
+                label,
+                arg_matches.contains_id("idempotent"),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: add-wallet: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
         ("remove-wallet", arg_matches) => {
             let wallet_address =
                 SignerSource::try_get_pubkey(arg_matches, "wallet_address", &mut wallet_manager)
@@ -467,13 +1391,18 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 .unwrap()
                 .unwrap();
             println!("lists: {:?}", lists);
-            let response =
-                process_setup_extra_metas(&rpc_client, &config.payer, &mint_address, &lists)
-                    .await
-                    .unwrap_or_else(|err| {
-                        eprintln!("error: apply-lists-to-mint: {}", err);
-                        exit(1);
-                    });
+            let response = process_setup_extra_metas(
+                &rpc_client,
+                &config.payer,
+                &mint_address,
+                &lists,
+                arg_matches.contains_id("freeze"),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: apply-lists-to-mint: {}", err);
+                exit(1);
+            });
             println!("{}", response);
         }
         _ => unreachable!(),
@@ -19,6 +19,33 @@ pub enum ABLError {
     InvalidRemainingAccounts,
     InvalidWalletEntry,
     InvalidListConfig,
+    TokenAccountOwnerMismatch,
+    EnrollRateExceeded,
+    ReviewQueueFull,
+    InvalidReviewQueue,
+    TransferHookMismatch,
+    InvalidAccountOwner,
+    ListRegistryFull,
+    InvalidListRegistry,
+    AuthoritySetFull,
+    InvalidAuthoritySet,
+    ReturnDataTooLarge,
+    InvalidAuditLog,
+    ZeroBalance,
+    OutsideAllowedWindow,
+    AllowThresholdNotMet,
+    ArithmeticOverflow,
+    ArithmeticUnderflow,
+    BatchTooLarge,
+    ListFull,
+    InvalidPolicyArchive,
+    AccountNotBlocked,
+    InvalidMerkleProof,
+    RootVersionMismatch,
+    ProofTooLong,
+    ModeChangeNotAcknowledged,
+    ListFrozen,
+    EvalTooExpensive,
 }
 
 impl From<ABLError> for ProgramError {
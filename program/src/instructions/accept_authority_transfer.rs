@@ -0,0 +1,92 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, load_account_mut, parse_trailing_accounts, ABLError, AuditLog, ListConfig,
+    TrailingAccounts,
+};
+
+/// Second step of an authority transfer: the pending authority signs to
+/// become the list authority.
+pub struct AcceptAuthorityTransfer<'a> {
+    pub pending_authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for AcceptAuthorityTransfer<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [pending_authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !pending_authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        // only the pending authority accepts, so no authority set is taken
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set: None,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            pending_authority,
+            list_config,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> AcceptAuthorityTransfer<'a> {
+    pub const DISCRIMINATOR: u8 = 0x11;
+
+    pub fn process(&self) -> ProgramResult {
+        let list_config = unsafe { load_account_mut::<ListConfig>(self.list_config, true)? };
+
+        if !list_config.has_pending_authority()
+            || list_config
+                .pending_authority
+                .ne(self.pending_authority.key())
+        {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        list_config.authority = list_config.pending_authority;
+        list_config.pending_authority = Pubkey::default();
+        list_config.updated_at = Clock::get()?.unix_timestamp.to_le_bytes();
+        list_config.last_actor = *self.pending_authority.key();
+
+        if let Some(audit_log) = self.audit_log {
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_ACCEPT_AUTHORITY,
+                self.pending_authority.key(),
+                &[],
+            )?;
+        }
+
+        pinocchio_log::log!(
+            "Authority transfer of list {} accepted by {}",
+            self.list_config.key(),
+            self.pending_authority.key()
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,16 @@
 use pinocchio::{
     account_info::AccountInfo,
     instruction::Signer,
-    pubkey::find_program_address,
+    pubkey::{find_program_address, Pubkey},
     seeds,
-    sysvars::{rent::Rent, Sysvar},
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
     ProgramResult,
 };
 
 use crate::{
-    load_mut, load_mut_unchecked, ABLError, Discriminator, ListConfig, Transmutable, WalletEntry,
+    append_audit_record, check_authority, load, load_mut, load_mut_unchecked,
+    parse_trailing_accounts, parse_versioned_data, ABLError, AuditLog, AuthoritySet, Discriminator,
+    ListConfig, TrailingAccounts, Transmutable, WalletEntry,
 };
 
 pub struct AddWallet<'a> {
@@ -18,71 +20,175 @@ pub struct AddWallet<'a> {
     pub wallet: &'a AccountInfo,
     pub wallet_entry: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
     pub wallet_entry_bump: u8,
 }
 
 impl<'a> AddWallet<'a> {
     pub const DISCRIMINATOR: u8 = 0x02;
 
-    pub fn process(&self) -> ProgramResult {
-        let list_config =
-            unsafe { load_mut::<ListConfig>(self.list_config.borrow_mut_data_unchecked())? };
-
-        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
-            return Err(ABLError::InvalidAuthority.into());
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: version (1), v0 carries no fields, v1 adds expires_at (8),
+        // v2 adds label (32), v3 adds idempotent (1)
+        let (expires_at, label, idempotent) = match parse_versioned_data(remaining_data)? {
+            (0, _) => (0, [0; 32], false),
+            (1, data) => (
+                i64::from_le_bytes(data.try_into().map_err(|_| ABLError::InvalidData)?),
+                [0; 32],
+                false,
+            ),
+            (version, data) => {
+                let (expires_at, data) =
+                    data.split_first_chunk::<8>().ok_or(ABLError::InvalidData)?;
+                let (label, idempotent) = match (version, data.split_first_chunk::<32>()) {
+                    (2, Some((label, []))) => (label, false),
+                    (3, Some((label, [idempotent @ (0 | 1)]))) => (label, *idempotent == 1),
+                    _ => return Err(ABLError::InvalidData.into()),
+                };
+                (i64::from_le_bytes(*expires_at), *label, idempotent)
+            }
+        };
+        if expires_at < 0 {
+            return Err(ABLError::InvalidData.into());
+        }
+        if WalletEntry::label_text(&label).is_none() {
+            return Err(ABLError::InvalidData.into());
         }
 
-        let lamports = Rent::get()?.minimum_balance(WalletEntry::LEN);
+        let list_config =
+            unsafe { load_mut::<ListConfig>(self.list_config.borrow_mut_data_unchecked())? };
 
-        let bump_seed = [self.wallet_entry_bump];
-        let seeds = seeds!(
-            WalletEntry::SEED_PREFIX,
-            self.list_config.key(),
-            self.wallet.key(),
-            &bump_seed
-        );
-        let signer = [Signer::from(&seeds)];
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::ADD,
+        )?;
 
-        let current_lamports = self.wallet_entry.lamports();
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
 
-        if lamports > current_lamports {
-            pinocchio_system::instructions::Transfer {
-                from: self.payer,
-                to: self.wallet_entry,
-                lamports: lamports - current_lamports,
+        // a retried add finds the entry already created, it succeeds without
+        // counting or logging the wallet again
+        if idempotent && self.wallet_entry.is_owned_by(&crate::ID) {
+            let entry = unsafe { load::<WalletEntry>(self.wallet_entry.borrow_data_unchecked()) }
+                .map_err(|_| ABLError::InvalidWalletEntry)?;
+            if entry.list_config.ne(self.list_config.key()) {
+                return Err(ABLError::InvalidWalletEntry.into());
             }
-            .invoke()?;
+            return Ok(());
         }
 
-        pinocchio_system::instructions::Allocate {
-            account: self.wallet_entry,
-            space: WalletEntry::LEN as u64,
+        create_wallet_entry(
+            self.payer,
+            self.list_config,
+            list_config,
+            self.wallet.key(),
+            self.wallet_entry,
+            self.wallet_entry_bump,
+            expires_at,
+            &label,
+            self.authority.key(),
+        )?;
+
+        if let Some(audit_log) = self.audit_log {
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_ADD,
+                self.wallet.key(),
+                &[],
+            )?;
         }
-        .invoke_signed(&signer)?;
 
-        pinocchio_system::instructions::Assign {
-            account: self.wallet_entry,
-            owner: &crate::ID,
-        }
-        .invoke_signed(&signer)?;
+        Ok(())
+    }
+}
 
-        let mut data = self.wallet_entry.try_borrow_mut_data()?;
-        let wallet_entry = unsafe { load_mut_unchecked::<WalletEntry>(&mut data)? };
-        wallet_entry.discriminator = WalletEntry::DISCRIMINATOR;
-        wallet_entry.wallet_address = *self.wallet.key();
-        wallet_entry.list_config = *self.list_config.key();
+/// Creates and initializes the entry for `wallet` on a list, counting it as a
+/// new enrollment, stamped with the time and `added_by`. The caller is
+/// responsible for checking the authority, the entry address and that `label`
+/// is zero padded UTF-8.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn create_wallet_entry(
+    payer: &AccountInfo,
+    list_config_account: &AccountInfo,
+    list_config: &mut ListConfig,
+    wallet: &Pubkey,
+    wallet_entry: &AccountInfo,
+    wallet_entry_bump: u8,
+    expires_at: i64,
+    label: &[u8; 32],
+    added_by: &Pubkey,
+) -> ProgramResult {
+    if list_config.is_full() {
+        return Err(ABLError::ListFull.into());
+    }
 
-        list_config.increment_wallets_count()?;
+    let now = Clock::get()?.unix_timestamp;
+    list_config.record_enrollment(now)?;
+
+    let lamports = Rent::get()?.minimum_balance(WalletEntry::LEN);
+
+    let namespace = list_config.namespace;
+    let bump_seed = [wallet_entry_bump];
+    let seeds = seeds!(
+        WalletEntry::SEED_PREFIX,
+        &namespace,
+        list_config_account.key(),
+        wallet,
+        &bump_seed
+    );
+    let signer = [Signer::from(&seeds)];
+
+    let current_lamports = wallet_entry.lamports();
+
+    if lamports > current_lamports {
+        pinocchio_system::instructions::Transfer {
+            from: payer,
+            to: wallet_entry,
+            lamports: lamports - current_lamports,
+        }
+        .invoke()?;
+    }
 
-        Ok(())
+    pinocchio_system::instructions::Allocate {
+        account: wallet_entry,
+        space: WalletEntry::LEN as u64,
+    }
+    .invoke_signed(&signer)?;
+
+    pinocchio_system::instructions::Assign {
+        account: wallet_entry,
+        owner: &crate::ID,
     }
+    .invoke_signed(&signer)?;
+
+    let mut data = wallet_entry.try_borrow_mut_data()?;
+    let entry = unsafe { load_mut_unchecked::<WalletEntry>(&mut data)? };
+    entry.discriminator = WalletEntry::DISCRIMINATOR;
+    entry.wallet_address = *wallet;
+    entry.list_config = *list_config_account.key();
+    entry.namespace = namespace;
+    entry.appeal_deadline = list_config.appeal_deadline(now).to_le_bytes();
+    entry.expires_at = expires_at.to_le_bytes();
+    entry.label = *label;
+    entry.created_at = now.to_le_bytes();
+    entry.added_by = *added_by;
+
+    list_config.increment_wallets_count()
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for AddWallet<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, payer, list_config, wallet, wallet_entry, system_program] = accounts else {
+        let [authority, payer, list_config, wallet, wallet_entry, system_program, remaining_accounts @ ..] =
+            accounts
+        else {
             return Err(ABLError::NotEnoughAccounts);
         };
 
@@ -94,13 +200,21 @@ impl<'a> TryFrom<&'a [AccountInfo]> for AddWallet<'a> {
             return Err(ABLError::AccountNotWritable);
         }
 
+        // wallet entries are scoped to the namespace of their list
+        let namespace =
+            unsafe { load::<ListConfig>(list_config.borrow_data_unchecked())? }.namespace;
         let (wallet_entry_pk, wallet_entry_bump) = find_program_address(
-            &[WalletEntry::SEED_PREFIX, list_config.key(), wallet.key()],
+            &[
+                WalletEntry::SEED_PREFIX,
+                &namespace,
+                list_config.key(),
+                wallet.key(),
+            ],
             &crate::ID,
         );
 
         if wallet_entry_pk.ne(wallet_entry.key()) {
-            return Err(ABLError::InvalidWalletEntry.into());
+            return Err(ABLError::InvalidWalletEntry);
         }
 
         // check if system program is valid
@@ -108,6 +222,15 @@ impl<'a> TryFrom<&'a [AccountInfo]> for AddWallet<'a> {
             return Err(ABLError::InvalidSystemProgram);
         }
 
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
         Ok(Self {
             authority,
             payer,
@@ -115,6 +238,8 @@ impl<'a> TryFrom<&'a [AccountInfo]> for AddWallet<'a> {
             wallet,
             wallet_entry,
             system_program,
+            authority_set,
+            audit_log,
             wallet_entry_bump,
         })
     }
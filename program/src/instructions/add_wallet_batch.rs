@@ -0,0 +1,164 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, check_authority, create_wallet_entry, load_mut, parse_trailing_accounts,
+    ABLError, AuditLog, AuthoritySet, ListConfig, TrailingAccounts, WalletEntry,
+};
+
+/// Adds several wallets to a list in one instruction. Either every entry is
+/// created or none is.
+pub struct AddWalletBatch<'a> {
+    pub authority: &'a AccountInfo,
+    pub payer: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub remaining_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for AddWalletBatch<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, list_config, system_program, remaining_accounts @ ..] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        // at most one entry per wallet followed by the authority set and the
+        // audit log
+        if remaining_accounts.len() > Self::MAX_BATCH + 2 {
+            return Err(ABLError::BatchTooLarge);
+        }
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            list_config,
+            system_program,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> AddWalletBatch<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0F;
+
+    /// Maximum number of wallets added by one instruction. A full batch with
+    /// the trailing accounts fits a legacy transaction and the default
+    /// compute budget.
+    pub const MAX_BATCH: usize = 10;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: count (4) + wallet (32) per entry
+        let (count, wallets) = remaining_data
+            .split_first_chunk::<4>()
+            .ok_or(ABLError::InvalidData)?;
+        let count = u32::from_le_bytes(*count) as usize;
+        if count > Self::MAX_BATCH {
+            return Err(ABLError::BatchTooLarge.into());
+        }
+        if wallets.len() != count * 32 {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        // remaining accounts: one wallet entry per wallet, in data order,
+        // followed by the optional trailing accounts
+        if self.remaining_accounts.len() < count {
+            return Err(ABLError::NotEnoughAccounts.into());
+        }
+        let (wallet_entries, remaining_accounts) = self.remaining_accounts.split_at(count);
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts.into());
+        };
+
+        let list_config =
+            unsafe { load_mut::<ListConfig>(self.list_config.borrow_mut_data_unchecked())? };
+
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            authority_set,
+            AuthoritySet::ADD,
+        )?;
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        for (wallet, wallet_entry) in wallets.chunks_exact(32).zip(wallet_entries) {
+            let wallet: &Pubkey = wallet.try_into().unwrap();
+
+            let namespace = list_config.namespace;
+            let (wallet_entry_pk, wallet_entry_bump) = find_program_address(
+                &[
+                    WalletEntry::SEED_PREFIX,
+                    &namespace,
+                    self.list_config.key(),
+                    wallet,
+                ],
+                &crate::ID,
+            );
+
+            if !wallet_entry.is_writable() {
+                return Err(ABLError::AccountNotWritable.into());
+            }
+
+            if wallet_entry_pk.ne(wallet_entry.key()) {
+                return Err(ABLError::InvalidWalletEntry.into());
+            }
+
+            // an existing entry fails its creation, reverting the whole batch
+            create_wallet_entry(
+                self.payer,
+                self.list_config,
+                list_config,
+                wallet,
+                wallet_entry,
+                wallet_entry_bump,
+                0,
+                &[0; 32],
+                self.authority.key(),
+            )?;
+
+            if let Some(audit_log) = audit_log {
+                append_audit_record(
+                    audit_log,
+                    self.list_config,
+                    AuditLog::ACTION_ADD,
+                    wallet,
+                    &[],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    pubkey::{find_program_address, Pubkey},
+    seeds,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    check_authority, load, load_mut_unchecked, parse_trailing_accounts, ABLError, AuditLog,
+    AuthoritySet, Discriminator, ListConfig, PolicyArchive, TrailingAccounts, Transmutable,
+};
+
+/// Writes a copy of a list's policy into a fresh archive account, stamped with
+/// the current slot. The list's audit log, when passed, supplies the hash of
+/// its membership.
+pub struct ArchivePolicy<'a> {
+    pub authority: &'a AccountInfo,
+    pub payer: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub policy_archive: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ArchivePolicy<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, list_config, policy_archive, system_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !policy_archive.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // archives are only ever written once
+        if policy_archive.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidPolicyArchive);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            payer,
+            list_config,
+            policy_archive,
+            system_program,
+            authority_set,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> ArchivePolicy<'a> {
+    pub const DISCRIMINATOR: u8 = 0x18;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: seed (32)
+        let seed: &Pubkey = remaining_data
+            .try_into()
+            .map_err(|_| ABLError::InvalidData)?;
+
+        let list_data = self.list_config.try_borrow_data()?;
+        let list_config = unsafe { load::<ListConfig>(&list_data)? };
+
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::CONFIGURE,
+        )?;
+
+        let (membership_hash, membership_records) = match self.audit_log {
+            Some(audit_log) => {
+                let log = unsafe { load::<AuditLog>(audit_log.borrow_data_unchecked()) }
+                    .map_err(|_| ABLError::InvalidAuditLog)?;
+                if !audit_log.is_owned_by(&crate::ID) || log.list_config.ne(self.list_config.key())
+                {
+                    return Err(ABLError::InvalidAuditLog.into());
+                }
+                (log.head, log.count)
+            }
+            None => ([0; 32], [0; 8]),
+        };
+
+        let (archive_pk, archive_bump) = find_program_address(
+            &[PolicyArchive::SEED_PREFIX, self.list_config.key(), seed],
+            &crate::ID,
+        );
+
+        if archive_pk.ne(self.policy_archive.key()) {
+            return Err(ABLError::InvalidPolicyArchive.into());
+        }
+
+        let lamports = Rent::get()?.minimum_balance(PolicyArchive::LEN);
+
+        let bump_seed = [archive_bump];
+        let seeds = seeds!(
+            PolicyArchive::SEED_PREFIX,
+            self.list_config.key(),
+            seed,
+            &bump_seed
+        );
+        let signer = [Signer::from(&seeds)];
+
+        let current_lamports = self.policy_archive.lamports();
+        if lamports > current_lamports {
+            pinocchio_system::instructions::Transfer {
+                from: self.payer,
+                to: self.policy_archive,
+                lamports: lamports - current_lamports,
+            }
+            .invoke()?;
+        }
+
+        pinocchio_system::instructions::Allocate {
+            account: self.policy_archive,
+            space: PolicyArchive::LEN as u64,
+        }
+        .invoke_signed(&signer)?;
+
+        pinocchio_system::instructions::Assign {
+            account: self.policy_archive,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signer)?;
+
+        let clock = Clock::get()?;
+
+        let mut data = self.policy_archive.try_borrow_mut_data()?;
+        let archive = unsafe { load_mut_unchecked::<PolicyArchive>(&mut data)? };
+        archive.discriminator = PolicyArchive::DISCRIMINATOR;
+        archive.list_config = *self.list_config.key();
+        archive.seed = *seed;
+        archive.slot = clock.slot.to_le_bytes();
+        archive.archived_at = clock.unix_timestamp.to_le_bytes();
+        archive.membership_hash = membership_hash;
+        archive.membership_records = membership_records;
+        archive.policy = list_data[..ListConfig::LEN].try_into().unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::{
+    load, set_return_data_checked, ABLError, ListConfig, WalletEntry, MAX_RETURN_DATA_LEN,
+};
+
+/// Read-only consistency check of a list config and a batch of wallet
+/// entries, returning one health byte per entry.
+pub struct AuditEntries<'a> {
+    pub list_config: &'a AccountInfo,
+    pub wallet_entries: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for AuditEntries<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [list_config, wallet_entries @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if wallet_entries.len() > MAX_RETURN_DATA_LEN {
+            return Err(ABLError::ReturnDataTooLarge);
+        }
+
+        Ok(Self {
+            list_config,
+            wallet_entries,
+        })
+    }
+}
+
+impl<'a> AuditEntries<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0D;
+
+    /// The list config exists and is initialized.
+    pub const CONFIG_PRESENT: u8 = 1 << 0;
+    /// The entry is a wallet entry of the list, in the list's namespace when
+    /// the list config is present.
+    pub const BINDING_OK: u8 = 1 << 1;
+    /// The entry is bound to the list and the list config is present.
+    pub const NOT_ORPHANED: u8 = 1 << 2;
+
+    pub fn process(&self) -> ProgramResult {
+        let list_config = if self.list_config.is_owned_by(&crate::ID) {
+            unsafe { load::<ListConfig>(self.list_config.borrow_data_unchecked()) }.ok()
+        } else {
+            None
+        };
+
+        // return data: one health byte per entry, in account order
+        let mut return_data = [0u8; MAX_RETURN_DATA_LEN];
+        for (health, wallet_entry) in return_data.iter_mut().zip(self.wallet_entries) {
+            let entry = if wallet_entry.is_owned_by(&crate::ID) {
+                unsafe { load::<WalletEntry>(wallet_entry.borrow_data_unchecked()) }.ok()
+            } else {
+                None
+            };
+
+            let bound = entry.is_some_and(|entry| {
+                entry.list_config.eq(self.list_config.key())
+                    && list_config.is_none_or(|list| entry.namespace == list.namespace)
+            });
+
+            if list_config.is_some() {
+                *health |= Self::CONFIG_PRESENT;
+            }
+            if bound {
+                *health |= Self::BINDING_OK;
+            }
+            if bound && list_config.is_some() {
+                *health |= Self::NOT_ORPHANED;
+            }
+        }
+
+        set_return_data_checked(&return_data[..self.wallet_entries.len()])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use spl_discriminator::SplDiscriminate;
+use token_acl_interface::instruction::CanFreezePermissionlessInstruction;
+
+use crate::{
+    evaluate_thaw, is_entry_of_list, load, ABLError, CanThawPermissionless, ListConfig, WalletEntry,
+};
+
+/// Gate of permissionless freezes, the inverse of CanThawPermissionless on
+/// block lists: the freeze passes once any block list holds an active entry
+/// for the owner, or the token account on lists keyed by token account. Lists
+/// in the other modes don't gate freezes, and neither do lists warming up or
+/// sunset, as they don't enforce.
+///
+/// Called with the same accounts as CanThawPermissionless, see its security
+/// assumptions. Unlike a thaw, the entry has to be the owner's: a freeze must
+/// not be reachable with someone else's block entry.
+pub struct CanFreezePermissionless<'a> {
+    pub authority: &'a AccountInfo,
+    pub token_account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub owner: &'a AccountInfo,
+    pub mint_config: &'a AccountInfo,
+    pub extra_metas: &'a AccountInfo,
+    pub remaining_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CanFreezePermissionless<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let CanThawPermissionless {
+            authority,
+            token_account,
+            mint,
+            owner,
+            mint_config,
+            extra_metas,
+            remaining_accounts,
+        } = CanThawPermissionless::try_from(accounts)?;
+
+        Ok(Self {
+            authority,
+            token_account,
+            mint,
+            owner,
+            mint_config,
+            extra_metas,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> CanFreezePermissionless<'a> {
+    /// First byte of the token acl interface discriminator, like CanThaw.
+    pub const DISCRIMINATOR: u8 = CanFreezePermissionlessInstruction::SPL_DISCRIMINATOR_SLICE[0];
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: rest of the discriminator (7)
+        if !matches!(remaining_data.get(7..), None | Some([])) {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        // entries are keyed by the owner, so it has to own the token account
+        if crate::state::token_account_owner(self.token_account)?.ne(self.owner.key()) {
+            return Err(ABLError::TokenAccountOwnerMismatch.into());
+        }
+
+        // every list/wallet pair is resolved from a pair of extra metas
+        crate::state::validate_extra_metas(
+            self.extra_metas,
+            token_acl_interface::FREEZE_EXTRA_ACCOUNT_METAS_SEED,
+            self.mint,
+            self.remaining_accounts.len(),
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+
+        let mut remaining_accounts = self.remaining_accounts.iter();
+        while let Some(list) = remaining_accounts.next() {
+            let wallet_entry = remaining_accounts
+                .next()
+                .ok_or(ABLError::InvalidRemainingAccounts)?;
+
+            // lists not blocking the owner move on to the next one, anything
+            // else wrong with the pair fails the freeze
+            let verdict =
+                Self::validate_freeze_list(list, self.owner, self.token_account, wallet_entry, now);
+            if verdict != Err(ABLError::AccountNotBlocked.into()) {
+                return verdict;
+            }
+        }
+
+        Err(ABLError::AccountNotBlocked.into())
+    }
+
+    /// Validates the freeze against a single list, passing when it's a block
+    /// list holding an active entry for the owner. Fails with
+    /// `AccountNotBlocked` when the list doesn't block the owner.
+    pub(crate) fn validate_freeze_list(
+        list: &AccountInfo,
+        owner: &AccountInfo,
+        token_account: &AccountInfo,
+        wallet_entry: &AccountInfo,
+        now: i64,
+    ) -> ProgramResult {
+        if list.key() == wallet_entry.key() {
+            return Err(ABLError::InvalidRemainingAccounts.into());
+        }
+
+        if !list.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidListConfig.into());
+        }
+
+        let list_data: &[u8] = &list.try_borrow_data()?;
+        let list_config =
+            unsafe { load::<ListConfig>(list_data).map_err(|_| ABLError::InvalidListConfig)? };
+
+        if !list_config.gates_token_program(token_account.owner()) {
+            return Err(ABLError::InvalidGatingProgram.into());
+        }
+
+        if !matches!(list_config.get_mode(), crate::Mode::Block)
+            || list_config.is_warming_up(now)
+            || list_config.is_sunset(now)
+        {
+            return Err(ABLError::AccountNotBlocked.into());
+        }
+
+        // an absent entry blocks nothing
+        if wallet_entry.is_owned_by(&Pubkey::default()) {
+            return Err(ABLError::AccountNotBlocked.into());
+        }
+        if !wallet_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidWalletEntry.into());
+        }
+
+        let entry_data = wallet_entry.try_borrow_data()?;
+        let wallet = unsafe { load::<WalletEntry>(&entry_data) }
+            .map_err(|_| ABLError::InvalidWalletEntry)?;
+
+        let member = if list_config.is_keyed_by_token_account() {
+            token_account.key()
+        } else {
+            owner.key()
+        };
+        if !is_entry_of_list(wallet, list, list_config, token_account)
+            || wallet.wallet_address.ne(member)
+        {
+            return Err(ABLError::InvalidWalletEntry.into());
+        }
+
+        match evaluate_thaw(list_config, false, Some(wallet), now) {
+            Err(ABLError::AccountBlocked) => Ok(()),
+            _ => Err(ABLError::AccountNotBlocked.into()),
+        }
+    }
+}
@@ -1,6 +1,12 @@
-use pinocchio::{ProgramResult, account_info::AccountInfo, pubkey::Pubkey};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
 
-use crate::{load, ABLError, ListConfig, WalletEntry};
+use crate::{load, ABLError, ListConfig, MerkleProof, SetupExtraMetas, WalletEntry};
 
 use solana_curve25519::edwards::PodEdwardsPoint;
 
@@ -17,6 +23,7 @@ pub struct CanThawPermissionless<'a> {
     pub token_account: &'a AccountInfo,
     pub mint: &'a AccountInfo,
     pub owner: &'a AccountInfo,
+    pub mint_config: &'a AccountInfo,
     pub extra_metas: &'a AccountInfo,
     pub remaining_accounts: &'a [AccountInfo],
 }
@@ -24,99 +31,762 @@ pub struct CanThawPermissionless<'a> {
 impl<'a> CanThawPermissionless<'a> {
     pub const DISCRIMINATOR: u8 = 0x8;
 
-    pub fn process(&self) -> ProgramResult {
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: rest of the discriminator (7), Token ACL forwards nothing else
+        if remaining_data.len() > 7 {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        // the extra metas never resolve more lists than SetupExtraMetas writes,
+        // so more pairs can't match them
+        let pairs = self.remaining_accounts.len() / 2;
+        if pairs > SetupExtraMetas::MAX_LISTS {
+            return Err(ABLError::InvalidExtraMetasAccount.into());
+        }
+
+        // every list is decoded once, and the passes below read its summary
+        let mut lists = [None; SetupExtraMetas::MAX_LISTS];
+        for (summary, list) in lists
+            .iter_mut()
+            .zip(self.remaining_accounts.iter().step_by(2))
+        {
+            *summary = ListSummary::load(list);
+        }
+        let lists = &lists[..pairs];
+
+        // a list logging the units has them logged for the whole decision
+        let meter_start = lists
+            .iter()
+            .flatten()
+            .any(|summary| summary.metered)
+            .then(remaining_compute_units);
+
+        let verdict = self.validate(lists);
+
+        if let Some(start) = meter_start {
+            pinocchio_log::log!(
+                "METER: {} units, {} pairs, modes {}",
+                start.saturating_sub(remaining_compute_units()),
+                pairs,
+                list_modes(lists)
+            );
+        }
+
+        verdict
+    }
+
+    /// Validates the thaw, `lists` holding the summary of each pair's list.
+    fn validate(&self, lists: &[Option<ListSummary>]) -> ProgramResult {
+        CanThawPermissionless::validate_token_account(
+            self.token_account,
+            self.owner,
+            lists.iter().copied(),
+        )?;
+
+        // every list/wallet pair is resolved from a pair of extra metas
+        crate::state::validate_extra_metas(
+            self.extra_metas,
+            token_acl_interface::THAW_EXTRA_ACCOUNT_METAS_SEED,
+            self.mint,
+            self.remaining_accounts.len(),
+        )?;
+
+        // a paused list denies the thaw outright, before thresholds, allow
+        // precedence or any other list get a say
+        if let Some((list, _)) = self
+            .remaining_accounts
+            .iter()
+            .step_by(2)
+            .zip(lists)
+            .find(|(_, summary)| summary.is_some_and(|summary| summary.paused))
+        {
+            pinocchio_log::log!("PAUSED: list {} is paused, denying thaw", list.key());
+            return Err(ABLError::AccountBlocked.into());
+        }
+
+        if all_allow_any_list(lists) {
+            return self.process_any_list(lists);
+        }
+
+        // every list has to allow the thaw, evaluated in two passes: block lists
+        // first, then every other list, each pass in account order. A blocked
+        // wallet is always reported by its block list whatever the pair order,
+        // and the cheapest denial runs first
+        //
+        // once every allow list sets a threshold, allow lists are counted as a
+        // family instead: the owner has to pass at least the highest threshold
+        // of them, and failing allow lists don't deny the thaw on their own
+        //
+        // block lists giving precedence to allow lists only deny the thaw once
+        // no allow list holds an entry for the owner
+        let threshold = allow_family_threshold(lists);
+        let mut allowed_lists = 0u8;
+        let mut yielding_block_list = None;
+        let mut allowed_by_entry = false;
+        for block_lists in [true, false] {
+            // remaining accounts should be pairs of list and ab_wallet
+            for (pair, summary) in self.remaining_accounts.chunks(2).zip(lists) {
+                let [list, ab_wallet] = pair else {
+                    return Err(ABLError::InvalidRemainingAccounts.into());
+                };
+
+                // anything but a valid block list, including invalid lists, is
+                // left to the second pass to be validated and reported
+                if summary.is_some_and(|summary| summary.block) != block_lists {
+                    continue;
+                }
+
+                let verdict = CanThawPermissionless::validate_thaw_list(
+                    list,
+                    Some(self.authority),
+                    self.owner,
+                    self.token_account,
+                    self.mint,
+                    Some(self.mint_config),
+                    ab_wallet,
+                );
+                emit_decision(list, *summary, self.owner, &verdict);
+
+                if block_lists
+                    && verdict == Err(ABLError::AccountBlocked.into())
+                    && summary.is_some_and(|summary| summary.allow_precedence)
+                {
+                    yielding_block_list = Some(list.key());
+                    continue;
+                }
+
+                // warming up lists and delegate bypasses allow without an entry
+                let allow_threshold = summary.and_then(|summary| summary.allow_threshold);
+                if verdict.is_ok() && ab_wallet.is_owned_by(&crate::ID) && allow_threshold.is_some()
+                {
+                    allowed_by_entry = true;
+                }
+
+                if threshold > 0 && allow_threshold.is_some() {
+                    if verdict.is_ok() {
+                        allowed_lists = allowed_lists.saturating_add(1);
+                    } else {
+                        pinocchio_log::log!(
+                            "List {} doesn't count towards the threshold",
+                            list.key()
+                        );
+                    }
+                    continue;
+                }
+
+                verdict.inspect_err(|_| {
+                    pinocchio_log::log!("Failed to pass validation for list {}", list.key());
+                })?;
+            }
+        }
+
+        if let Some(list) = yielding_block_list.filter(|_| !allowed_by_entry) {
+            pinocchio_log::log!("Failed to pass validation for list {}", list);
+            return Err(ABLError::AccountBlocked.into());
+        }
+
+        if allowed_lists < threshold {
+            return Err(ABLError::AllowThresholdNotMet.into());
+        }
+
+        Ok(())
+    }
+
+    /// Passes once any list allows the thaw, in account order, returning the
+    /// error of the last list when none does. Allow thresholds and allow
+    /// precedence only apply when every list has to allow the thaw.
+    fn process_any_list(&self, lists: &[Option<ListSummary>]) -> ProgramResult {
+        let mut verdict = Err(ABLError::NotEnoughAccounts.into());
+
+        for (pair, summary) in self.remaining_accounts.chunks(2).zip(lists) {
+            let [list, ab_wallet] = pair else {
+                return Err(ABLError::InvalidRemainingAccounts.into());
+            };
+
+            verdict = CanThawPermissionless::validate_thaw_list(
+                list,
+                Some(self.authority),
+                self.owner,
+                self.token_account,
+                self.mint,
+                Some(self.mint_config),
+                ab_wallet,
+            );
+            emit_decision(list, *summary, self.owner, &verdict);
+
+            if verdict.is_ok() {
+                return Ok(());
+            }
+            pinocchio_log::log!("Failed to pass validation for list {}", list.key());
+        }
+
+        verdict
+    }
+
+    /// Validates the token account being thawed. `lists` are the summaries of
+    /// the pairs' lists, which can waive the immutable owner extension for
+    /// multisig owners.
+    pub(crate) fn validate_token_account(
+        token_account: &AccountInfo,
+        owner: &AccountInfo,
+        lists: impl Iterator<Item = Option<ListSummary>> + Clone,
+    ) -> ProgramResult {
+        // lists are evaluated against the owner account, so it has to be the
+        // actual owner of the token account being thawed
+        if crate::state::token_account_owner(token_account)?.ne(owner.key()) {
+            return Err(ABLError::TokenAccountOwnerMismatch.into());
+        }
+
+        // legacy spl token accounts can't carry extensions, so the immutable
+        // owner requirement only applies to token-2022; they have exactly the
+        // base layout
+        if crate::state::is_legacy_token_account(token_account) {
+            if token_account.data_len() != crate::state::TOKEN_ACCOUNT_LEN {
+                return Err(ABLError::InvalidAccountData.into());
+            }
+            return Ok(());
+        }
+
+        // every list has to accept the extension guarding the owner
+        let owner_extensions = accepted_owner_extensions(lists.clone());
+        let has_owner_extension = (owner_extensions & ListConfig::IMMUTABLE_OWNER != 0
+            && crate::state::has_immutable_owner_extension(token_account))
+            || (owner_extensions & ListConfig::NON_TRANSFERABLE != 0
+                && crate::state::has_non_transferable_account_extension(token_account));
+
         // SAFETY: token account is validated by the token-2022 program
         // after the current call finishes execution, the token acl program
         // calls into token-2022 to thaw the token account, which gets type checked
         // by the token-2022 program
-        if !crate::state::has_immutable_owner_extension(self.token_account) {
+        let is_allowed_multisig =
+            crate::state::is_token_2022_multisig(owner) && all_allow_multisig_owner(lists);
+        if !has_owner_extension && !is_allowed_multisig {
             return Err(ABLError::ImmutableOwnerExtensionMissing.into());
         }
 
-        // remaining accounts should be pairs of list and ab_wallet
-        let mut remaining_accounts = self.remaining_accounts.iter();
-        while let Some(list) = remaining_accounts.next() {
-            let ab_wallet = remaining_accounts.next().unwrap();
-
-            CanThawPermissionless::validate_thaw_list(list, self.owner, ab_wallet).inspect_err(
-                |_| {
-                    pinocchio_log::log!("Failed to pass validation for list {}", list.key());
-                },
-            )?;
-        }
-
         Ok(())
     }
 
-    fn validate_thaw_list(
+    /// Validates the thaw against a single list. `authority` is the signer of
+    /// the thaw, `None` skips the cosign requirement. `mint_config` is the token
+    /// acl mint config, `None` skips the freeze authority delegate bypass.
+    /// On merkle lists `wallet_entry` can also be the member's proof account.
+    /// Paused lists are left to the caller, which denies them up front.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn validate_thaw_list(
         list: &AccountInfo,
+        authority: Option<&AccountInfo>,
         owner: &AccountInfo,
+        token_account: &AccountInfo,
+        mint: &AccountInfo,
+        mint_config: Option<&AccountInfo>,
         wallet_entry: &AccountInfo,
     ) -> ProgramResult {
+        // a list can never be its own wallet entry
+        if list.key() == wallet_entry.key() {
+            return Err(ABLError::InvalidRemainingAccounts.into());
+        }
 
         if !list.is_owned_by(&crate::ID) {
             return Err(ABLError::InvalidListConfig.into());
         }
 
         let list_data: &[u8] = &list.try_borrow_data()?;
-        let list_config = unsafe { load::<ListConfig>(list_data)? };
+        // the discriminator check keeps other program owned accounts, like a
+        // wallet entry, from being passed as a list
+        let list_config =
+            unsafe { load::<ListConfig>(list_data).map_err(|_| ABLError::InvalidListConfig)? };
+
+        // lists bound to a token program only gate that program's accounts
+        if !list_config.gates_token_program(token_account.owner()) {
+            return Err(ABLError::InvalidGatingProgram.into());
+        }
 
-        // 3 operation modes
+        // opt-in cross check that the mint's transfer hook also points at this program
+        let transfer_hook_matches = || {
+            crate::state::mint_transfer_hook_program_id(mint).is_some_and(|id| id.eq(&crate::ID))
+        };
+        match list_config.get_transfer_hook_check() {
+            crate::TransferHookCheck::Off => {}
+            crate::TransferHookCheck::Warn => {
+                if !transfer_hook_matches() {
+                    pinocchio_log::log!("Mint transfer hook mismatch for list {}", list.key());
+                }
+            }
+            crate::TransferHookCheck::Error => {
+                if !transfer_hook_matches() {
+                    return Err(ABLError::TransferHookMismatch.into());
+                }
+            }
+        }
+
+        // fully permissioned lists also need the list authority to sign the thaw
+        if list_config.requires_authority_cosign()
+            && authority.is_some_and(|a| !a.is_signer() || list_config.authority.ne(a.key()))
+        {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        // lists fail open while warming up so operators can populate entries
+        let now = Clock::get()?.unix_timestamp;
+        if list_config.is_warming_up(now) {
+            pinocchio_log::log!("List {} is warming up, allowing thaw", list.key());
+            return Ok(());
+        }
+
+        // lists stop enforcing once their mandate ends
+        if list_config.is_sunset(now) {
+            pinocchio_log::log!(
+                "SUNSET: list {} no longer enforces, allowing thaw",
+                list.key()
+            );
+            return Ok(());
+        }
+
+        // lists with a daily window only allow thaws inside it
+        if !list_config.is_within_allowed_window(now) {
+            return Err(ABLError::OutsideAllowedWindow.into());
+        }
+
+        // opt-in: accounts delegated to the freeze authority are under the
+        // custodian's control already, so membership is not checked
+        if list_config.allows_freeze_authority_delegate()
+            && mint_config.is_some_and(|c| is_delegated_to_freeze_authority(token_account, mint, c))
+        {
+            pinocchio_log::log!(
+                "Token account {} is delegated to the freeze authority, allowing thaw",
+                token_account.key()
+            );
+            return Ok(());
+        }
+
+        // merkle lists admit the members of their tree by the proof stored in
+        // their proof account, checked against the current root; anything else
+        // is read as a wallet entry
+        if matches!(list_config.get_mode(), crate::Mode::MerkleAllow)
+            && wallet_entry.is_owned_by(&crate::ID)
+            && is_merkle_member(list, list_config, wallet_entry, owner, token_account)?
+        {
+            return Ok(());
+        }
+
+        // 4 operation modes
         // allow: only wallets that have been allowlisted can thaw, requires previously created ABWallet account
         // block: only wallets that have been blocklisted can't thaw, thawing requires ABWallet to not exist
         // allow with permissionless eoas: all wallets that can sign can thaw, otherwise requires previously created ABWallet account (for PDAs)
-        match list_config.get_mode() {
-            crate::Mode::Allow => {
-                let ab_wallet_data: &[u8] = &wallet_entry.try_borrow_data()?;
-                let wallet = unsafe {
-                    load::<WalletEntry>(ab_wallet_data).map_err(|_| ABLError::AccountBlocked)?
-                };
+        // allow with permissionless pdas: all off-curve wallets can thaw, otherwise requires previously created ABWallet account (for EOAs)
+        let mode = list_config.get_mode();
+        let owner_is_on_curve =
+            (matches!(mode, crate::Mode::AllowAllEoas | crate::Mode::AllowAllPdas)
+                || list_config.is_in_eoa_grace_period(now))
+                && solana_curve25519::edwards::validate_edwards(&PodEdwardsPoint(*owner.key()));
 
-                if !wallet_entry.is_owned_by(&crate::ID) || wallet.list_config.ne(list.key()) {
+        let ab_wallet_data = match mode {
+            crate::Mode::Block => {
+                // either the block exists and is owned by this program
+                // or it doest exist. We want to avoid PDA derivation to waste more CUs
+                let is_absent = wallet_entry.is_owned_by(&Pubkey::default());
+                if !is_absent && !wallet_entry.is_owned_by(&crate::ID) {
                     return Err(ABLError::InvalidWalletEntry.into());
                 }
 
-                Ok(())
-            }
-            crate::Mode::AllowAllEoas => {
-                let pt = PodEdwardsPoint(*owner.key());
-
-                if !solana_curve25519::edwards::validate_edwards(&pt) {
-                    let ab_wallet_data: &[u8] = &wallet_entry.try_borrow_data()?;
-                    let wallet = unsafe {
-                        load::<WalletEntry>(ab_wallet_data).map_err(|_| ABLError::AccountBlocked)?
-                    };
-                    
-                    if !wallet_entry.is_owned_by(&crate::ID) || wallet.list_config.ne(list.key()) {
-                        return Err(ABLError::InvalidWalletEntry.into());
-                    }
+                // opt-in: strict lists pay for the derivation so that an unrelated
+                // uninitialized account can't stand in for the owner's entry
+                if list_config.is_strict_block_mode()
+                    && !is_entry_address(wallet_entry, list, list_config, owner, token_account)
+                {
+                    return Err(ABLError::InvalidWalletEntry.into());
                 }
 
-                Ok(())
+                // the common not blocked case: a system account is never a block
+                // entry, whatever its data, so there is nothing to load
+                if is_absent {
+                    None
+                } else {
+                    Some(wallet_entry.try_borrow_data()?)
+                }
             }
-            crate::Mode::Block => {
-                let ab_wallet_data: &[u8] = &wallet_entry.try_borrow_data()?;
-                let res = unsafe { load::<WalletEntry>(ab_wallet_data) };
+            // owners let through by the mode only load their entry when an
+            // expired one would deny them
+            crate::Mode::AllowAllEoas
+                if owner_is_on_curve && !list_config.denies_expired_entries() =>
+            {
+                None
+            }
+            crate::Mode::AllowAllPdas
+                if !owner_is_on_curve && !list_config.denies_expired_entries() =>
+            {
+                None
+            }
+            _ => Some(wallet_entry.try_borrow_data()?),
+        };
 
-                // either the block exists and is owned by this program
-                // or it doest exist. We want to avoid PDA derivation to waste more CUs
-                if !wallet_entry.is_owned_by(&Pubkey::default()) && !wallet_entry.is_owned_by(&crate::ID) {
+        // anything but a wallet entry counts as no entry
+        let wallet = match ab_wallet_data
+            .as_deref()
+            .map(|data| unsafe { load::<WalletEntry>(data) })
+        {
+            Some(Ok(wallet)) => {
+                if !wallet_entry.is_owned_by(&crate::ID)
+                    || !is_entry_of_list(wallet, list, list_config, token_account)
+                {
                     return Err(ABLError::InvalidWalletEntry.into());
                 }
+                Some(wallet)
+            }
+            _ => None,
+        };
 
-                if let Ok(wallet) = res {
-                    if wallet.list_config.ne(list.key()) {
-                        return Err(ABLError::InvalidWalletEntry.into());
-                    }
-                    Err(ABLError::AccountBlocked.into())
-                } else {
-                    Ok(())
-                }
+        evaluate_thaw(list_config, owner_is_on_curve, wallet, now)?;
+
+        // opt-in: allowlisted owners also need to hold tokens
+        if matches!(mode, crate::Mode::Allow)
+            && list_config.requires_nonzero_balance()
+            && crate::state::token_account_amount(token_account)? == 0
+        {
+            return Err(ABLError::ZeroBalance.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Decides a single list from decoded state, so the decision can be replayed
+/// off-chain. `wallet_entry` is the owner's entry, already checked to belong
+/// to the list, `None` when there is none. `owner_is_on_curve` is only read by
+/// the modes letting one kind of owner through and by Allow lists in their
+/// EOA grace period. Checks needing the accounts, like the warmup, allowed
+/// window or balance, are left to the caller, as is the merkle proof of
+/// MerkleAllow lists, which only fall back to the entry.
+pub fn evaluate_thaw(
+    list_config: &ListConfig,
+    owner_is_on_curve: bool,
+    wallet_entry: Option<&WalletEntry>,
+    now: i64,
+) -> Result<(), ABLError> {
+    match list_config.get_mode() {
+        // the owners a mode lets through only need an entry to be denied,
+        // by an expired one on lists that deny expired entries
+        crate::Mode::AllowAllEoas if owner_is_on_curve => {
+            allow_unless_expired(list_config, wallet_entry, now)
+        }
+        crate::Mode::AllowAllPdas if !owner_is_on_curve => {
+            allow_unless_expired(list_config, wallet_entry, now)
+        }
+        // lists that left AllowAllEoas with a grace period still let EOAs
+        // through until it ends
+        crate::Mode::Allow if owner_is_on_curve && list_config.is_in_eoa_grace_period(now) => {
+            allow_unless_expired(list_config, wallet_entry, now)
+        }
+        crate::Mode::Allow
+        | crate::Mode::AllowAllEoas
+        | crate::Mode::AllowAllPdas
+        | crate::Mode::MerkleAllow => {
+            // an expired entry counts as absent
+            match wallet_entry {
+                Some(wallet) if !wallet.is_expired(now) => Ok(()),
+                _ => Err(ABLError::AccountBlocked),
             }
         }
+        crate::Mode::Block => match wallet_entry {
+            // blocks that expired or weren't reaffirmed before their appeal
+            // deadline lift
+            Some(wallet) if !wallet.is_expired(now) && !wallet.is_block_lifted(now) => {
+                Err(ABLError::AccountBlocked)
+            }
+            _ => Ok(()),
+        },
+    }
+}
+
+#[inline(always)]
+fn allow_unless_expired(
+    list_config: &ListConfig,
+    wallet_entry: Option<&WalletEntry>,
+    now: i64,
+) -> Result<(), ABLError> {
+    match wallet_entry {
+        Some(wallet) if list_config.denies_expired_entries() && wallet.is_expired(now) => {
+            Err(ABLError::AccountBlocked)
+        }
+        _ => Ok(()),
     }
 }
 
+/// Event discriminator of the decision events logged by CanThaw with the
+/// `decision-events` feature, one `sol_log_data` event per list checked:
+///
+/// | offset | size | field                                                    |
+/// |--------|------|----------------------------------------------------------|
+/// | 0      | 1    | `DECISION_EVENT`                                         |
+/// | 1      | 32   | list                                                     |
+/// | 33     | 32   | owner                                                    |
+/// | 65     | 1    | list mode, `0xFF` when the list can't be decoded         |
+/// | 66     | 8    | result code (u64 LE), `0` when the list allows the thaw  |
+///
+/// The result code is the program error the list fails with, as the runtime
+/// reports it, so custom errors are `ABLError` codes.
+pub const DECISION_EVENT: u8 = 0x01;
+
+/// Length of a decision event.
+pub const DECISION_EVENT_LEN: usize = 1 + 32 + 32 + 1 + 8;
+
+#[cfg(feature = "decision-events")]
+#[inline(always)]
+fn emit_decision(
+    list: &AccountInfo,
+    summary: Option<ListSummary>,
+    owner: &AccountInfo,
+    verdict: &ProgramResult,
+) {
+    let mode = summary.map_or(u8::MAX, |summary| summary.mode);
+
+    let mut event = [0u8; DECISION_EVENT_LEN];
+    event[0] = DECISION_EVENT;
+    event[1..33].copy_from_slice(list.key());
+    event[33..65].copy_from_slice(owner.key());
+    event[65] = mode;
+    event[66..].copy_from_slice(&crate::verdict_code(*verdict).to_le_bytes());
+
+    pinocchio::log::sol_log_data(&[&event]);
+}
+
+#[cfg(not(feature = "decision-events"))]
+#[inline(always)]
+fn emit_decision(
+    _list: &AccountInfo,
+    _summary: Option<ListSummary>,
+    _owner: &AccountInfo,
+    _verdict: &ProgramResult,
+) {
+}
+
+/// Compute units left in the transaction; `0` off-chain.
+#[inline(always)]
+fn remaining_compute_units() -> u64 {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        pinocchio::syscalls::sol_remaining_compute_units()
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    0
+}
+
+/// The settings of a pair's list that CanThaw reads besides validating the
+/// list, decoded once per thaw. Accounts that aren't a valid list config have
+/// no summary; `validate_thaw_list` reports them.
+#[derive(Clone, Copy)]
+pub(crate) struct ListSummary {
+    pub mode: u8,
+    pub block: bool,
+    pub paused: bool,
+    pub allow_precedence: bool,
+    pub multisig_owner: bool,
+    pub metered: bool,
+    pub any_list: bool,
+    pub owner_extensions: u8,
+    /// Threshold of a list in allow mode, `None` for any other list.
+    pub allow_threshold: Option<u8>,
+}
+
+impl ListSummary {
+    #[inline(always)]
+    pub(crate) fn load(list: &AccountInfo) -> Option<Self> {
+        if !list.is_owned_by(&crate::ID) {
+            return None;
+        }
+        let data = list.try_borrow_data().ok()?;
+        let list_config = unsafe { load::<ListConfig>(&data) }.ok()?;
+        let mode = list_config.get_mode();
+
+        Some(Self {
+            mode: list_config.mode,
+            block: matches!(mode, crate::Mode::Block),
+            paused: list_config.is_paused(),
+            allow_precedence: list_config.gives_allow_precedence(),
+            multisig_owner: list_config.allows_multisig_owner(),
+            metered: list_config.is_metered(),
+            any_list: list_config.allows_any_list(),
+            owner_extensions: list_config.get_owner_extensions(),
+            allow_threshold: matches!(mode, crate::Mode::Allow)
+                .then_some(list_config.allow_threshold),
+        })
+    }
+}
+
+/// Modes of the lists in the pairs as a bitmask, bit `n` set when a list in
+/// mode `n` is checked. Lists that can't be decoded aren't counted.
+#[inline(always)]
+fn list_modes(lists: &[Option<ListSummary>]) -> u8 {
+    lists
+        .iter()
+        .flatten()
+        .filter_map(|summary| 1u8.checked_shl(summary.mode as u32))
+        .fold(0, |modes, mode| modes | mode)
+}
+
+/// Whether there is at least one pair and every list is a valid list config
+/// waiving the immutable owner extension for multisig owners, so no single
+/// list can relax the others.
+#[inline(always)]
+fn all_allow_multisig_owner(lists: impl Iterator<Item = Option<ListSummary>>) -> bool {
+    let mut lists = lists.peekable();
+    lists.peek().is_some()
+        && lists.all(|summary| summary.is_some_and(|summary| summary.multisig_owner))
+}
+
+/// Whether there is at least one pair and every list is a valid list config
+/// letting the thaw pass on any list, so no single list can relax the others.
+#[inline(always)]
+fn all_allow_any_list(lists: &[Option<ListSummary>]) -> bool {
+    !lists.is_empty()
+        && lists
+            .iter()
+            .all(|summary| summary.is_some_and(|summary| summary.any_list))
+}
+
+/// Owner extensions accepted by every list of the pairs, only the immutable
+/// owner extension by lists that aren't valid list configs. Thaws without
+/// pairs only accept the immutable owner extension.
+#[inline(always)]
+fn accepted_owner_extensions(lists: impl Iterator<Item = Option<ListSummary>>) -> u8 {
+    let mut accepted = None;
+    for summary in lists {
+        let owner_extensions = summary.map_or(ListConfig::IMMUTABLE_OWNER, |summary| {
+            summary.owner_extensions
+        });
+        accepted = Some(accepted.unwrap_or(u8::MAX) & owner_extensions);
+    }
+    accepted.unwrap_or(ListConfig::IMMUTABLE_OWNER)
+}
+
+/// Highest threshold among the allow lists of the pairs, `0` unless every allow
+/// list sets one, so no single list can relax the others.
+fn allow_family_threshold(lists: &[Option<ListSummary>]) -> u8 {
+    let mut threshold = 0;
+    for summary in lists.iter().flatten() {
+        match summary.allow_threshold {
+            Some(0) => return 0,
+            Some(list_threshold) => threshold = threshold.max(list_threshold),
+            None => {}
+        }
+    }
+    threshold
+}
+
+/// Whether the token account's delegate is the freeze authority of the mint, as
+/// set in the token acl mint config. The signer of the thaw is not trusted here,
+/// anyone can call it, so an owner could otherwise delegate to themselves.
+#[inline(always)]
+fn is_delegated_to_freeze_authority(
+    token_account: &AccountInfo,
+    mint: &AccountInfo,
+    mint_config: &AccountInfo,
+) -> bool {
+    if !mint_config.is_owned_by(token_acl_interface::TOKEN_ACL_ID.as_array()) {
+        return false;
+    }
+    let Ok(mint_config_data) = mint_config.try_borrow_data() else {
+        return false;
+    };
+    let Ok(mint_config) = token_acl::state::load_mint_config(&mint_config_data) else {
+        return false;
+    };
+    if mint_config.mint.as_array() != mint.key() {
+        return false;
+    }
+
+    crate::state::token_account_delegate(token_account).is_ok_and(|delegate| {
+        delegate.is_some_and(|delegate| delegate.eq(mint_config.freeze_authority.as_array()))
+    })
+}
+
+/// Whether `merkle_proof` holds a proof, leading to the list's root, of the
+/// owner, or of the token account on lists keyed by token account. Proofs of
+/// another root version, or deeper than the list allows, aren't hashed, and
+/// proofs estimated to cost more than the list's ceiling fail with
+/// `EvalTooExpensive`. Any other account isn't a proof, so it's not a member
+/// by proof.
+#[inline(always)]
+fn is_merkle_member(
+    list: &AccountInfo,
+    list_config: &ListConfig,
+    merkle_proof: &AccountInfo,
+    owner: &AccountInfo,
+    token_account: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    let member = if list_config.is_keyed_by_token_account() {
+        token_account.key()
+    } else {
+        owner.key()
+    };
+
+    let data = merkle_proof.try_borrow_data()?;
+    let Ok(merkle_proof) = (unsafe { load::<MerkleProof>(&data) }) else {
+        return Ok(false);
+    };
+
+    if merkle_proof.list_config.ne(list.key())
+        || merkle_proof.member.ne(member)
+        || merkle_proof.root_version != list_config.root_version
+        || merkle_proof.get_proof().len() > list_config.get_max_proof_depth()
+    {
+        return Ok(false);
+    }
+
+    list_config.check_merkle_eval_cost(merkle_proof.get_proof().len())?;
+
+    Ok(crate::verify_merkle_proof(
+        &list_config.merkle_root,
+        &crate::merkle_leaf(member),
+        merkle_proof.get_proof(),
+    ))
+}
+
+/// Whether `wallet_entry` is at the entry address of the owner, or of the token
+/// account on lists keyed by token account.
+#[inline(always)]
+pub(crate) fn is_entry_address(
+    wallet_entry: &AccountInfo,
+    list: &AccountInfo,
+    list_config: &ListConfig,
+    owner: &AccountInfo,
+    token_account: &AccountInfo,
+) -> bool {
+    let wallet = if list_config.is_keyed_by_token_account() {
+        token_account.key()
+    } else {
+        owner.key()
+    };
+    let (address, _) = find_program_address(
+        &[
+            WalletEntry::SEED_PREFIX,
+            &list_config.namespace,
+            list.key(),
+            wallet,
+        ],
+        &crate::ID,
+    );
+    address.eq(wallet_entry.key())
+}
+
+/// A wallet entry only counts for the list it was created for, and only within
+/// that list's namespace. Lists keyed by token account also require the entry
+/// to be for the token account being thawed.
+#[inline(always)]
+pub(crate) fn is_entry_of_list(
+    wallet: &WalletEntry,
+    list: &AccountInfo,
+    list_config: &ListConfig,
+    token_account: &AccountInfo,
+) -> bool {
+    wallet.list_config.eq(list.key())
+        && wallet.namespace == list_config.namespace
+        && (!list_config.is_keyed_by_token_account()
+            || wallet.wallet_address.eq(token_account.key()))
+}
+
 impl<'a> TryFrom<&'a [AccountInfo]> for CanThawPermissionless<'a> {
     type Error = ABLError;
 
@@ -127,12 +797,12 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CanThawPermissionless<'a> {
          2- token account
          3- mint
          4- owner
-         5- flag account
+         5- flag account (token acl mint config)
          6- extra account metas
          (remaining accounts are pairs of list and wallet)
          */
 
-        let [authority, token_account, mint, owner, _flag_account, extra_metas, remaining_accounts @ ..] =
+        let [authority, token_account, mint, owner, mint_config, extra_metas, remaining_accounts @ ..] =
             accounts
         else {
             return Err(ABLError::NotEnoughAccounts);
@@ -147,6 +817,7 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CanThawPermissionless<'a> {
             token_account,
             mint,
             owner,
+            mint_config,
             extra_metas,
             remaining_accounts,
         })
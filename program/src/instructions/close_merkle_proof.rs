@@ -0,0 +1,108 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::{
+    append_audit_record, close_account, load, parse_trailing_accounts, ABLError, AuditLog,
+    ListConfig, MerkleProof, TrailingAccounts,
+};
+
+/// Closes a stored merkle proof, refunding its rent to the signer, which has
+/// to be the list authority or the proof's member. Its member no longer passes
+/// thaws until a proof is submitted again.
+///
+/// Proofs outliving their list can still be closed by their member.
+pub struct CloseMerkleProof<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub merkle_proof: &'a AccountInfo,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseMerkleProof<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, merkle_proof, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        if !authority.is_writable() || !merkle_proof.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        if !merkle_proof.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidMerkleProof);
+        }
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set: None,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            merkle_proof,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> CloseMerkleProof<'a> {
+    pub const DISCRIMINATOR: u8 = 0x22;
+
+    pub fn process(&self) -> ProgramResult {
+        let member = {
+            let proof_data = self.merkle_proof.try_borrow_data()?;
+            let merkle_proof = unsafe {
+                load::<MerkleProof>(&proof_data).map_err(|_| ABLError::InvalidMerkleProof)?
+            };
+
+            if merkle_proof.list_config.ne(self.list_config.key()) {
+                return Err(ABLError::InvalidMerkleProof.into());
+            }
+
+            let is_member = merkle_proof.member.eq(self.authority.key());
+
+            if self.list_config.is_owned_by(&crate::ID) {
+                let list_data = self.list_config.try_borrow_data()?;
+                let list_config = unsafe {
+                    load::<ListConfig>(&list_data).map_err(|_| ABLError::InvalidListConfig)?
+                };
+
+                if !is_member && list_config.authority.ne(self.authority.key()) {
+                    return Err(ABLError::InvalidAuthority.into());
+                }
+
+                if list_config.is_frozen() {
+                    return Err(ABLError::ListFrozen.into());
+                }
+            } else if !is_member {
+                return Err(ABLError::InvalidAuthority.into());
+            }
+
+            merkle_proof.member
+        };
+
+        if let Some(audit_log) = self.audit_log {
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_CLOSE_MERKLE_PROOF,
+                &member,
+                &[],
+            )?;
+        }
+
+        close_account(self.merkle_proof, self.authority)?;
+
+        Ok(())
+    }
+}
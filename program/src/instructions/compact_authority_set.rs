@@ -0,0 +1,69 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::{load, load_mut, set_return_data_checked, ABLError, AuthoritySet, ListConfig};
+
+/// Drops duplicate and default keys from the authority set of a list,
+/// returning the resulting delegate count. The list authority is held by the
+/// list config and isn't affected.
+pub struct CompactAuthoritySet<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub authority_set: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CompactAuthoritySet<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, authority_set] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !authority_set.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidAuthoritySet);
+        }
+
+        if !authority_set.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            authority_set,
+        })
+    }
+}
+
+impl<'a> CompactAuthoritySet<'a> {
+    pub const DISCRIMINATOR: u8 = 0x15;
+
+    pub fn process(&self) -> ProgramResult {
+        // only the list authority maintains the set
+        let list_config = unsafe { load::<ListConfig>(self.list_config.borrow_data_unchecked())? };
+        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        let mut data = self.authority_set.try_borrow_mut_data()?;
+        let set = unsafe { load_mut::<AuthoritySet>(&mut data) }
+            .map_err(|_| ABLError::InvalidAuthoritySet)?;
+        if set.list_config.ne(self.list_config.key()) {
+            return Err(ABLError::InvalidAuthoritySet.into());
+        }
+
+        let len = set.compact();
+
+        set_return_data_checked(&[len])?;
+
+        Ok(())
+    }
+}
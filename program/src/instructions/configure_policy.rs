@@ -0,0 +1,225 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, check_authority, load_account_mut, parse_trailing_accounts, ABLError,
+    AuditLog, AuthoritySet, ListConfig, Mode, TrailingAccounts,
+};
+
+/// Updates a selected set of list policy parameters in one go, stamping the
+/// list once with the time and signer of the change.
+pub struct ConfigurePolicy<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ConfigurePolicy<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            authority_set,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> ConfigurePolicy<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0E;
+
+    pub const ENROLL_RATE: u16 = 1 << 0;
+    pub const TRANSFER_HOOK_CHECK: u16 = 1 << 1;
+    pub const APPEAL_PERIOD: u16 = 1 << 2;
+    pub const REQUIRE_NONZERO_BALANCE: u16 = 1 << 3;
+    pub const ALLOWED_WINDOW: u16 = 1 << 4;
+    pub const REQUIRE_AUTHORITY_COSIGN: u16 = 1 << 5;
+    pub const ALLOW_THRESHOLD: u16 = 1 << 6;
+    pub const ANY_LIST: u16 = 1 << 7;
+    pub const METER: u16 = 1 << 8;
+    pub const MAX_PROOF_DEPTH: u16 = 1 << 9;
+    pub const DENY_EXPIRED_ENTRIES: u16 = 1 << 10;
+    pub const MAX_WALLETS: u16 = 1 << 11;
+    /// Only the list authority can set the manager, delegates holding the
+    /// configure permission can't.
+    pub const MANAGER: u16 = 1 << 12;
+    pub const MAX_CU_PER_EVAL: u16 = 1 << 13;
+
+    pub const ALL: u16 = Self::ENROLL_RATE
+        | Self::TRANSFER_HOOK_CHECK
+        | Self::APPEAL_PERIOD
+        | Self::REQUIRE_NONZERO_BALANCE
+        | Self::ALLOWED_WINDOW
+        | Self::REQUIRE_AUTHORITY_COSIGN
+        | Self::ALLOW_THRESHOLD
+        | Self::ANY_LIST
+        | Self::METER
+        | Self::MAX_PROOF_DEPTH
+        | Self::DENY_EXPIRED_ENTRIES
+        | Self::MAX_WALLETS
+        | Self::MANAGER
+        | Self::MAX_CU_PER_EVAL;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: mask (2) + enroll_window_secs (8) + max_enrolls_per_window (4)
+        //   + transfer_hook_check (1) + appeal_period_secs (8) + require_nonzero_balance (1)
+        //   + allowed_window_start (4) + allowed_window_end (4) + require_authority_cosign (1)
+        //   + allow_threshold (1) + any_list (1) + meter (1) + max_proof_depth (1)
+        //   + deny_expired_entries (1) + max_wallets (4) + manager (32) + max_cu_per_eval (4)
+        if remaining_data.len()
+            != 2 + 8 + 4 + 1 + 8 + 1 + 4 + 4 + 1 + 1 + 1 + 1 + 1 + 1 + 4 + 32 + 4
+        {
+            return Err(ABLError::InvalidData.into());
+        }
+        let (mask, data) = remaining_data.split_at(2);
+        let (enroll_window_secs, data) = data.split_at(8);
+        let (max_enrolls_per_window, data) = data.split_at(4);
+        let (transfer_hook_check, data) = data.split_at(1);
+        let (appeal_period_secs, data) = data.split_at(8);
+        let (require_nonzero_balance, data) = data.split_at(1);
+        let (allowed_window_start, data) = data.split_at(4);
+        let (allowed_window_end, data) = data.split_at(4);
+        let (require_authority_cosign, data) = data.split_at(1);
+        let (allow_threshold, data) = data.split_at(1);
+        let (any_list, data) = data.split_at(1);
+        let (meter, data) = data.split_at(1);
+        let (max_proof_depth, data) = data.split_at(1);
+        let (deny_expired_entries, data) = data.split_at(1);
+        let (max_wallets, data) = data.split_at(4);
+        let (manager, max_cu_per_eval) = data.split_at(32);
+        let mask = u16::from_le_bytes(mask.try_into().unwrap());
+        let transfer_hook_check = transfer_hook_check[0];
+        let require_nonzero_balance = require_nonzero_balance[0];
+        let require_authority_cosign = require_authority_cosign[0];
+        let allow_threshold = allow_threshold[0];
+        let any_list = any_list[0];
+        let meter = meter[0];
+        let max_proof_depth = max_proof_depth[0];
+        let deny_expired_entries = deny_expired_entries[0];
+
+        if mask & !Self::ALL != 0 {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        let list_config = unsafe { load_account_mut::<ListConfig>(self.list_config, true)? };
+
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::CONFIGURE,
+        )?;
+
+        if mask & Self::MANAGER != 0 && list_config.authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        // only the selected values are validated, the others are ignored
+        if (mask & Self::TRANSFER_HOOK_CHECK != 0 && transfer_hook_check > 2u8)
+            || (mask & Self::REQUIRE_NONZERO_BALANCE != 0 && require_nonzero_balance > 1u8)
+            || (mask & Self::REQUIRE_AUTHORITY_COSIGN != 0 && require_authority_cosign > 1u8)
+            || (mask & Self::ANY_LIST != 0 && any_list > 1u8)
+            || (mask & Self::METER != 0 && meter > 1u8)
+            || (mask & Self::MAX_PROOF_DEPTH != 0
+                && max_proof_depth as usize > crate::MAX_MERKLE_PROOF_LEN)
+            || (mask & Self::DENY_EXPIRED_ENTRIES != 0 && deny_expired_entries > 1u8)
+            || (mask & Self::ALLOW_THRESHOLD != 0
+                && allow_threshold > 0
+                && !matches!(list_config.get_mode(), Mode::Allow))
+            || (mask & Self::ALLOWED_WINDOW != 0
+                && (u32::from_le_bytes(allowed_window_start.try_into().unwrap())
+                    >= ListConfig::SECONDS_PER_DAY
+                    || u32::from_le_bytes(allowed_window_end.try_into().unwrap())
+                        >= ListConfig::SECONDS_PER_DAY))
+        {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        if mask & Self::ENROLL_RATE != 0 {
+            list_config.enroll_window_secs = enroll_window_secs.try_into().unwrap();
+            list_config.max_enrolls_per_window = max_enrolls_per_window.try_into().unwrap();
+        }
+        if mask & Self::TRANSFER_HOOK_CHECK != 0 {
+            list_config.transfer_hook_check = transfer_hook_check;
+        }
+        // existing block entries keep their deadlines, the new period applies
+        // from their next reaffirmation
+        if mask & Self::APPEAL_PERIOD != 0 {
+            list_config.appeal_period_secs = appeal_period_secs.try_into().unwrap();
+        }
+        if mask & Self::REQUIRE_NONZERO_BALANCE != 0 {
+            list_config.require_nonzero_balance = require_nonzero_balance;
+        }
+        if mask & Self::ALLOWED_WINDOW != 0 {
+            list_config.allowed_window_start = allowed_window_start.try_into().unwrap();
+            list_config.allowed_window_end = allowed_window_end.try_into().unwrap();
+        }
+        if mask & Self::REQUIRE_AUTHORITY_COSIGN != 0 {
+            list_config.require_authority_cosign = require_authority_cosign;
+        }
+        if mask & Self::ALLOW_THRESHOLD != 0 {
+            list_config.allow_threshold = allow_threshold;
+        }
+        if mask & Self::ANY_LIST != 0 {
+            list_config.any_list = any_list;
+        }
+        if mask & Self::METER != 0 {
+            list_config.meter = meter;
+        }
+        if mask & Self::MAX_PROOF_DEPTH != 0 {
+            list_config.max_proof_depth = max_proof_depth;
+        }
+        if mask & Self::DENY_EXPIRED_ENTRIES != 0 {
+            list_config.deny_expired_entries = deny_expired_entries;
+        }
+        // lowering the cap below the count keeps the entries but blocks new ones
+        if mask & Self::MAX_WALLETS != 0 {
+            list_config.max_wallets = max_wallets.try_into().unwrap();
+        }
+        if mask & Self::MANAGER != 0 {
+            list_config.manager = manager.try_into().unwrap();
+        }
+        if mask & Self::MAX_CU_PER_EVAL != 0 {
+            list_config.max_cu_per_eval = max_cu_per_eval.try_into().unwrap();
+        }
+
+        list_config.updated_at = Clock::get()?.unix_timestamp.to_le_bytes();
+        list_config.last_actor = *self.authority.key();
+
+        if let Some(audit_log) = self.audit_log {
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_CONFIGURE_POLICY,
+                self.authority.key(),
+                remaining_data,
+            )?;
+        }
+
+        Ok(())
+    }
+}
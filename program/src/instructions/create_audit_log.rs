@@ -0,0 +1,106 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    pubkey::find_program_address,
+    seeds,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    load, load_mut_unchecked, ABLError, AuditLog, Discriminator, ListConfig, Transmutable,
+};
+
+pub struct CreateAuditLog<'a> {
+    pub authority: &'a AccountInfo,
+    pub payer: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub audit_log: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub audit_log_bump: u8,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CreateAuditLog<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, list_config, audit_log, system_program] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !audit_log.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        let (audit_log_pk, audit_log_bump) =
+            find_program_address(&[AuditLog::SEED_PREFIX, list_config.key()], &crate::ID);
+
+        if audit_log_pk.ne(audit_log.key()) || audit_log.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidAuditLog);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            list_config,
+            audit_log,
+            system_program,
+            audit_log_bump,
+        })
+    }
+}
+
+impl<'a> CreateAuditLog<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0C;
+
+    pub fn process(&self) -> ProgramResult {
+        let list_config = unsafe { load::<ListConfig>(self.list_config.borrow_data_unchecked())? };
+        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        let lamports = Rent::get()?.minimum_balance(AuditLog::LEN);
+
+        let bump_seed = [self.audit_log_bump];
+        let seeds = seeds!(AuditLog::SEED_PREFIX, self.list_config.key(), &bump_seed);
+        let signer = [Signer::from(&seeds)];
+
+        let current_lamports = self.audit_log.lamports();
+        if lamports > current_lamports {
+            pinocchio_system::instructions::Transfer {
+                from: self.payer,
+                to: self.audit_log,
+                lamports: lamports - current_lamports,
+            }
+            .invoke()?;
+        }
+
+        pinocchio_system::instructions::Allocate {
+            account: self.audit_log,
+            space: AuditLog::LEN as u64,
+        }
+        .invoke_signed(&signer)?;
+
+        pinocchio_system::instructions::Assign {
+            account: self.audit_log,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signer)?;
+
+        let mut data = self.audit_log.try_borrow_mut_data()?;
+        let log = unsafe { load_mut_unchecked::<AuditLog>(&mut data)? };
+        log.discriminator = AuditLog::DISCRIMINATOR;
+        log.list_config = *self.list_config.key();
+
+        Ok(())
+    }
+}
@@ -3,24 +3,30 @@ use pinocchio::{
     instruction::Signer,
     pubkey::find_program_address,
     seeds,
-    sysvars::{rent::Rent, Sysvar},
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
     ProgramResult,
 };
 
-use crate::{load_mut_unchecked, ABLError, Discriminator, ListConfig, Transmutable};
+use crate::{
+    load_mut, load_mut_unchecked, parse_versioned_data, ABLError, Discriminator, ListConfig,
+    ListRegistry, Transmutable,
+};
 
 pub struct CreateList<'a> {
     pub authority: &'a AccountInfo,
     pub payer: &'a AccountInfo,
     pub list_config: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
+    /// Optional authority registry the new list is appended to.
+    pub list_registry: Option<(&'a AccountInfo, u8)>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for CreateList<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, payer, list_config, system_program] = accounts else {
+        let [authority, payer, list_config, system_program, remaining_accounts @ ..] = accounts
+        else {
             return Err(ABLError::NotEnoughAccounts);
         };
 
@@ -33,11 +39,31 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CreateList<'a> {
             return Err(ABLError::InvalidAuthority);
         }
 
+        let list_registry = match remaining_accounts {
+            [] => None,
+            [list_registry] => {
+                if !list_registry.is_writable() {
+                    return Err(ABLError::AccountNotWritable);
+                }
+
+                let (list_registry_pk, list_registry_bump) =
+                    find_program_address(&[ListRegistry::SEED_PREFIX, authority.key()], &crate::ID);
+
+                if list_registry_pk.ne(list_registry.key()) {
+                    return Err(ABLError::InvalidListRegistry);
+                }
+
+                Some((list_registry, list_registry_bump))
+            }
+            _ => return Err(ABLError::InvalidRemainingAccounts),
+        };
+
         Ok(Self {
             authority,
             payer,
             list_config,
             system_program,
+            list_registry,
         })
     }
 }
@@ -45,23 +71,89 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CreateList<'a> {
 impl<'a> CreateList<'a> {
     pub const DISCRIMINATOR: u8 = 0x01;
 
+    /// Length of the policy fields v1 data adds after the seed.
+    const POLICY_LEN: usize =
+        16 + 8 + 4 + 1 + 8 + 32 + 1 + 8 + 1 + 4 + 4 + 1 + 1 + 1 + 1 + 1 + 8 + 4 + 1 + 1;
+
     pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
-        let [mode, seed @ ..] = remaining_data else {
+        // data: version (1) + mode (1) + seed (32), v0 carries no other fields
+        // and leaves the policy at its defaults, v1 adds namespace (16)
+        //   + enroll_window_secs (8) + max_enrolls_per_window (4)
+        //   + key_by_token_account (1) + warmup_secs (8) + token_program (32)
+        //   + transfer_hook_check (1) + appeal_period_secs (8)
+        //   + require_nonzero_balance (1) + allowed_window_start (4)
+        //   + allowed_window_end (4) + require_authority_cosign (1) + allow_threshold (1)
+        //   + allow_freeze_authority_delegate (1) + strict_block_mode (1)
+        //   + allow_precedence (1) + sunset_at (8) + max_wallets (4)
+        //   + allow_multisig_owner (1) + owner_extensions (1)
+        let (version, data) = parse_versioned_data(remaining_data)?;
+        let [mode, data @ ..] = data else {
             return Err(ABLError::InvalidData.into());
         };
+        let (seed, data) = data
+            .split_first_chunk::<32>()
+            .ok_or(ABLError::InvalidData)?;
+        let data = match (version, data.len()) {
+            (0, 0) => &[0; Self::POLICY_LEN][..],
+            (1, Self::POLICY_LEN) => data,
+            _ => return Err(ABLError::InvalidData.into()),
+        };
+        let (namespace, data) = data.split_at(16);
+        let (enroll_window_secs, data) = data.split_at(8);
+        let (max_enrolls_per_window, data) = data.split_at(4);
+        let (key_by_token_account, data) = data.split_at(1);
+        let (warmup_secs, data) = data.split_at(8);
+        let (token_program, data) = data.split_at(32);
+        let (transfer_hook_check, data) = data.split_at(1);
+        let (appeal_period_secs, data) = data.split_at(8);
+        let (require_nonzero_balance, data) = data.split_at(1);
+        let (allowed_window_start, data) = data.split_at(4);
+        let (allowed_window_end, data) = data.split_at(4);
+        let (require_authority_cosign, data) = data.split_at(1);
+        let (allow_threshold, data) = data.split_at(1);
+        let (allow_freeze_authority_delegate, data) = data.split_at(1);
+        let (strict_block_mode, data) = data.split_at(1);
+        let (allow_precedence, data) = data.split_at(1);
+        let (sunset_at, data) = data.split_at(8);
+        let (max_wallets, data) = data.split_at(4);
+        let (allow_multisig_owner, owner_extensions) = data.split_at(1);
+        let key_by_token_account = key_by_token_account[0];
+        let transfer_hook_check = transfer_hook_check[0];
+        let require_nonzero_balance = require_nonzero_balance[0];
+        let require_authority_cosign = require_authority_cosign[0];
+        let allow_threshold = allow_threshold[0];
+        let allow_freeze_authority_delegate = allow_freeze_authority_delegate[0];
+        let strict_block_mode = strict_block_mode[0];
+        let allow_precedence = allow_precedence[0];
+        let allow_multisig_owner = allow_multisig_owner[0];
+        let owner_extensions = owner_extensions[0];
 
-        if seed.len() != 32 {
+        if *mode > 4u8
+            || key_by_token_account > 1u8
+            || transfer_hook_check > 2u8
+            || require_nonzero_balance > 1u8
+            || require_authority_cosign > 1u8
+            || (allow_threshold > 0 && *mode != 0)
+            || allow_freeze_authority_delegate > 1u8
+            || strict_block_mode > 1u8
+            || allow_precedence > 1u8
+            || allow_multisig_owner > 1u8
+            || owner_extensions & !(ListConfig::IMMUTABLE_OWNER | ListConfig::NON_TRANSFERABLE) != 0
+            || u32::from_le_bytes(allowed_window_start.try_into().unwrap())
+                >= ListConfig::SECONDS_PER_DAY
+            || u32::from_le_bytes(allowed_window_end.try_into().unwrap())
+                >= ListConfig::SECONDS_PER_DAY
+        {
             return Err(ABLError::InvalidData.into());
         }
 
-        if *mode > 2u8 {
-            return Err(ABLError::InvalidData.into());
-        }
+        let now = Clock::get()?.unix_timestamp;
+        let warmup_until =
+            now.saturating_add_unsigned(u64::from_le_bytes(warmup_secs.try_into().unwrap()));
 
         let lamports = Rent::get()?.minimum_balance(ListConfig::LEN);
 
         // find canonical bump to prepare signer seeds for cpi
-        let seed = TryInto::<&[u8; 32]>::try_into(seed).unwrap();
         let (config_pk, config_bump) = find_program_address(
             &[ListConfig::SEED_PREFIX, self.authority.key(), seed],
             &crate::ID,
@@ -109,6 +201,85 @@ impl<'a> CreateList<'a> {
         list.authority = *self.authority.key();
         list.seed = *seed;
         list.mode = *mode;
+        list.namespace = namespace.try_into().unwrap();
+        list.enroll_window_secs = enroll_window_secs.try_into().unwrap();
+        list.max_enrolls_per_window = max_enrolls_per_window.try_into().unwrap();
+        list.key_by_token_account = key_by_token_account;
+        list.warmup_until = warmup_until.to_le_bytes();
+        list.token_program = token_program.try_into().unwrap();
+        list.transfer_hook_check = transfer_hook_check;
+        list.appeal_period_secs = appeal_period_secs.try_into().unwrap();
+        list.require_nonzero_balance = require_nonzero_balance;
+        list.allowed_window_start = allowed_window_start.try_into().unwrap();
+        list.allowed_window_end = allowed_window_end.try_into().unwrap();
+        list.require_authority_cosign = require_authority_cosign;
+        list.allow_threshold = allow_threshold;
+        list.allow_freeze_authority_delegate = allow_freeze_authority_delegate;
+        list.strict_block_mode = strict_block_mode;
+        list.allow_precedence = allow_precedence;
+        list.sunset_at = sunset_at.try_into().unwrap();
+        list.max_wallets = max_wallets.try_into().unwrap();
+        list.allow_multisig_owner = allow_multisig_owner;
+        list.owner_extensions = owner_extensions;
+        list.updated_at = now.to_le_bytes();
+        list.last_actor = *self.authority.key();
+
+        if let Some((list_registry, list_registry_bump)) = self.list_registry {
+            self.register_list(list_registry, list_registry_bump)?;
+        }
+
+        Ok(())
+    }
+
+    fn register_list(&self, list_registry: &AccountInfo, list_registry_bump: u8) -> ProgramResult {
+        if !list_registry.is_owned_by(&crate::ID) {
+            self.create_list_registry(list_registry, list_registry_bump)?;
+        }
+
+        let mut data = list_registry.try_borrow_mut_data()?;
+        let registry = unsafe { load_mut::<ListRegistry>(&mut data)? };
+        registry.push(self.list_config.key())?;
+
+        Ok(())
+    }
+
+    fn create_list_registry(
+        &self,
+        list_registry: &AccountInfo,
+        list_registry_bump: u8,
+    ) -> ProgramResult {
+        let lamports = Rent::get()?.minimum_balance(ListRegistry::LEN);
+
+        let bump_seed = [list_registry_bump];
+        let seeds = seeds!(ListRegistry::SEED_PREFIX, self.authority.key(), &bump_seed);
+        let signer = [Signer::from(&seeds)];
+
+        let current_lamports = list_registry.lamports();
+        if lamports > current_lamports {
+            pinocchio_system::instructions::Transfer {
+                from: self.payer,
+                to: list_registry,
+                lamports: lamports - current_lamports,
+            }
+            .invoke()?;
+        }
+
+        pinocchio_system::instructions::Allocate {
+            account: list_registry,
+            space: ListRegistry::LEN as u64,
+        }
+        .invoke_signed(&signer)?;
+
+        pinocchio_system::instructions::Assign {
+            account: list_registry,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signer)?;
+
+        let mut data = list_registry.try_borrow_mut_data()?;
+        let registry = unsafe { load_mut_unchecked::<ListRegistry>(&mut data)? };
+        registry.discriminator = ListRegistry::DISCRIMINATOR;
+        registry.authority = *self.authority.key();
 
         Ok(())
     }
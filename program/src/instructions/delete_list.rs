@@ -1,17 +1,25 @@
 use pinocchio::{account_info::AccountInfo, ProgramResult};
 
-use crate::{load, ABLError, ListConfig};
+use crate::{
+    check_authority, close_account, load, load_account_mut, parse_trailing_accounts,
+    split_rent_destination, ABLError, AuthoritySet, ListConfig, ListRegistry, TrailingAccounts,
+};
 
 pub struct DeleteList<'a> {
     pub authority: &'a AccountInfo,
     pub list_config: &'a AccountInfo,
+    /// The list authority, which receives the rent of the list.
+    pub rent_destination: Option<&'a AccountInfo>,
+    /// Optional authority registry the list is removed from.
+    pub list_registry: Option<&'a AccountInfo>,
+    pub authority_set: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for DeleteList<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, list_config] = accounts else {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
             return Err(ABLError::NotEnoughAccounts);
         };
 
@@ -19,13 +27,30 @@ impl<'a> TryFrom<&'a [AccountInfo]> for DeleteList<'a> {
             return Err(ABLError::InvalidConfigAccount);
         }
 
-        if !authority.is_signer() || !authority.is_writable() {
+        if !authority.is_signer() {
             return Err(ABLError::InvalidAuthority);
         }
 
+        let list_authority =
+            unsafe { load::<ListConfig>(list_config.borrow_data_unchecked())? }.authority;
+        let (rent_destination, remaining_accounts) =
+            split_rent_destination(authority, &list_authority, remaining_accounts)?;
+
+        let TrailingAccounts {
+            list_registry,
+            authority_set,
+            audit_log: None,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
         Ok(Self {
             authority,
             list_config,
+            rent_destination,
+            list_registry,
+            authority_set,
         })
     }
 }
@@ -34,26 +59,42 @@ impl<'a> DeleteList<'a> {
     pub const DISCRIMINATOR: u8 = 0x05;
 
     pub fn process(&self) -> ProgramResult {
-        {
+        let list_authority = {
             let list_config =
                 unsafe { load::<ListConfig>(self.list_config.borrow_data_unchecked())? };
 
-            if list_config.authority.ne(self.authority.key()) {
-                return Err(ABLError::InvalidAuthority.into());
+            check_authority(
+                self.list_config,
+                list_config,
+                self.authority,
+                self.authority_set,
+                AuthoritySet::CLOSE,
+            )?;
+
+            if list_config.is_frozen() {
+                return Err(ABLError::ListFrozen.into());
             }
 
             if list_config.get_wallets_count() > 0 {
                 return Err(ABLError::ListNotEmpty.into());
             }
-        }
 
-        let list_config_lamports = unsafe { self.list_config.borrow_mut_lamports_unchecked() };
-        let authority_lamports = unsafe { self.authority.borrow_mut_lamports_unchecked() };
+            list_config.authority
+        };
+
+        let rent_destination = self.rent_destination.ok_or(ABLError::NotEnoughAccounts)?;
 
-        *authority_lamports += *list_config_lamports;
+        if let Some(list_registry) = self.list_registry {
+            let registry = unsafe { load_account_mut::<ListRegistry>(list_registry, true)? };
+            if registry.authority.ne(&list_authority) {
+                return Err(ABLError::InvalidListRegistry.into());
+            }
+
+            // lists created without the registry are simply not in it
+            registry.remove(self.list_config.key());
+        }
 
-        // close will set lamports to 0
-        self.list_config.close()?;
+        close_account(self.list_config, rent_destination)?;
 
         Ok(())
     }
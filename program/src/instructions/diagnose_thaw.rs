@@ -0,0 +1,103 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::{
+    set_return_data_checked, verdict_code, ABLError, CanThawPermissionless, ListSummary,
+    MAX_RETURN_DATA_LEN,
+};
+
+/// Read-only diagnostic that runs the thaw validation for a token account and
+/// arbitrary (list, wallet_entry) pairs, reporting every verdict instead of
+/// stopping at the first denial.
+pub struct DiagnoseThaw<'a> {
+    pub token_account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub owner: &'a AccountInfo,
+    pub remaining_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DiagnoseThaw<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [token_account, mint, owner, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        // remaining accounts should be pairs of list and wallet entry
+        if remaining_accounts.len() % 2 != 0 {
+            return Err(ABLError::InvalidRemainingAccounts);
+        }
+
+        Ok(Self {
+            token_account,
+            mint,
+            owner,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> DiagnoseThaw<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0B;
+
+    pub fn process(&self) -> ProgramResult {
+        // return data: token account verdict (8) + one verdict (8) per pair, in
+        // account order. A verdict is the validation result as a u64, `0` when
+        // it passes
+        let mut return_data = [0u8; MAX_RETURN_DATA_LEN];
+        let len = 8 + 8 * (self.remaining_accounts.len() / 2);
+        if len > MAX_RETURN_DATA_LEN {
+            return Err(ABLError::ReturnDataTooLarge.into());
+        }
+
+        let verdict = CanThawPermissionless::validate_token_account(
+            self.token_account,
+            self.owner,
+            self.remaining_accounts
+                .iter()
+                .step_by(2)
+                .map(ListSummary::load),
+        );
+        let code = verdict_code(verdict);
+        pinocchio_log::log!(
+            "Token account {} verdict: {}",
+            self.token_account.key(),
+            code
+        );
+        return_data[..8].copy_from_slice(&code.to_le_bytes());
+
+        let mut offset = 8;
+        let mut remaining_accounts = self.remaining_accounts.iter();
+        while let Some(list) = remaining_accounts.next() {
+            let wallet_entry = remaining_accounts
+                .next()
+                .ok_or(ABLError::InvalidRemainingAccounts)?;
+
+            // there is no thaw signer or mint config to diagnose, so list cosign
+            // requirements and freeze authority delegates are not evaluated;
+            // merkle memberships are, from the pair's proof account
+            let verdict = if ListSummary::load(list).is_some_and(|summary| summary.paused) {
+                pinocchio_log::log!("PAUSED: list {} is paused, denying thaw", list.key());
+                Err(ABLError::AccountBlocked.into())
+            } else {
+                CanThawPermissionless::validate_thaw_list(
+                    list,
+                    None,
+                    self.owner,
+                    self.token_account,
+                    self.mint,
+                    None,
+                    wallet_entry,
+                )
+            };
+            let code = verdict_code(verdict);
+            pinocchio_log::log!("List {} verdict: {}", list.key(), code);
+            return_data[offset..offset + 8].copy_from_slice(&code.to_le_bytes());
+            offset += 8;
+        }
+
+        set_return_data_checked(&return_data[..len])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use pinocchio::{account_info::AccountInfo, pubkey::find_program_address, ProgramResult};
+
+use crate::{
+    append_audit_record, create_wallet_entry, load_mut, parse_trailing_accounts, ABLError,
+    AuditLog, ListConfig, ReviewQueue, TrailingAccounts, WalletEntry,
+};
+
+pub struct DrainQueue<'a> {
+    pub authority: &'a AccountInfo,
+    pub payer: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub review_queue: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub remaining_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DrainQueue<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, list_config, review_queue, system_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !review_queue.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidReviewQueue);
+        }
+
+        if !list_config.is_writable() || !review_queue.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            list_config,
+            review_queue,
+            system_program,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> DrainQueue<'a> {
+    pub const DISCRIMINATOR: u8 = 0x07;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: confirmed (2), bit i set confirms the i-th queued report
+        let confirmed = u16::from_le_bytes(
+            remaining_data
+                .try_into()
+                .map_err(|_| ABLError::InvalidData)?,
+        );
+
+        let list_config =
+            unsafe { load_mut::<ListConfig>(self.list_config.borrow_mut_data_unchecked())? };
+
+        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        let queue =
+            unsafe { load_mut::<ReviewQueue>(self.review_queue.borrow_mut_data_unchecked())? };
+
+        if queue.list_config.ne(self.list_config.key()) {
+            return Err(ABLError::InvalidReviewQueue.into());
+        }
+
+        // confirmed bits past the queue length mean the caller's view of the
+        // queue is stale
+        if confirmed.checked_shr(queue.len as u32).unwrap_or(0) != 0 {
+            return Err(ABLError::InvalidRemainingAccounts.into());
+        }
+
+        // remaining accounts are the wallet entries of the confirmed reports, in
+        // queue order, followed by the optional audit log
+        let confirmed_count = confirmed.count_ones() as usize;
+        if self.remaining_accounts.len() < confirmed_count {
+            return Err(ABLError::InvalidRemainingAccounts.into());
+        }
+        let (wallet_entries, remaining_accounts) =
+            self.remaining_accounts.split_at(confirmed_count);
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set: None,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts.into());
+        };
+
+        let mut wallet_entries = wallet_entries.iter();
+        for index in 0..queue.len as usize {
+            if confirmed & (1 << index) == 0 {
+                continue;
+            }
+
+            let wallet = queue.get(index).ok_or(ABLError::InvalidData)?;
+            let wallet_entry = wallet_entries
+                .next()
+                .ok_or(ABLError::InvalidRemainingAccounts)?;
+
+            let namespace = list_config.namespace;
+            let (wallet_entry_pk, wallet_entry_bump) = find_program_address(
+                &[
+                    WalletEntry::SEED_PREFIX,
+                    &namespace,
+                    self.list_config.key(),
+                    wallet,
+                ],
+                &crate::ID,
+            );
+
+            if wallet_entry_pk.ne(wallet_entry.key()) {
+                return Err(ABLError::InvalidWalletEntry.into());
+            }
+
+            // the wallet may have been blocked since it was reported
+            if wallet_entry.is_owned_by(&crate::ID) {
+                continue;
+            }
+
+            create_wallet_entry(
+                self.payer,
+                self.list_config,
+                list_config,
+                wallet,
+                wallet_entry,
+                wallet_entry_bump,
+                0,
+                &[0; 32],
+                self.authority.key(),
+            )?;
+
+            if let Some(audit_log) = audit_log {
+                append_audit_record(
+                    audit_log,
+                    self.list_config,
+                    AuditLog::ACTION_ADD,
+                    wallet,
+                    &[],
+                )?;
+            }
+        }
+
+        queue.clear();
+
+        Ok(())
+    }
+}
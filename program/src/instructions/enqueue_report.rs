@@ -0,0 +1,133 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    pubkey::{find_program_address, Pubkey},
+    seeds,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    load, load_mut, load_mut_unchecked, ABLError, Discriminator, ListConfig, Mode, ReviewQueue,
+    Transmutable,
+};
+
+pub struct EnqueueReport<'a> {
+    pub reporter: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub review_queue: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub review_queue_bump: u8,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for EnqueueReport<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [reporter, list_config, review_queue, system_program] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !reporter.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        // reports only make sense for lists where an entry blocks the wallet
+        let list = unsafe { load::<ListConfig>(list_config.borrow_data_unchecked())? };
+        if !matches!(list.get_mode(), Mode::Block) {
+            return Err(ABLError::InvalidListConfig);
+        }
+
+        if !review_queue.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        let (review_queue_pk, review_queue_bump) =
+            find_program_address(&[ReviewQueue::SEED_PREFIX, list_config.key()], &crate::ID);
+
+        if review_queue_pk.ne(review_queue.key()) {
+            return Err(ABLError::InvalidReviewQueue);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            reporter,
+            list_config,
+            review_queue,
+            system_program,
+            review_queue_bump,
+        })
+    }
+}
+
+impl<'a> EnqueueReport<'a> {
+    pub const DISCRIMINATOR: u8 = 0x06;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: reported wallet (32)
+        let wallet: &Pubkey = remaining_data
+            .try_into()
+            .map_err(|_| ABLError::InvalidData)?;
+
+        if !self.review_queue.is_owned_by(&crate::ID) {
+            self.create_review_queue()?;
+        }
+
+        let mut data = self.review_queue.try_borrow_mut_data()?;
+        let queue = unsafe { load_mut::<ReviewQueue>(&mut data)? };
+
+        // reporting an already queued wallet is a no-op
+        if queue.contains(wallet) {
+            return Ok(());
+        }
+
+        queue.push(wallet)?;
+
+        Ok(())
+    }
+
+    fn create_review_queue(&self) -> ProgramResult {
+        let lamports = Rent::get()?.minimum_balance(ReviewQueue::LEN);
+
+        let bump_seed = [self.review_queue_bump];
+        let seeds = seeds!(ReviewQueue::SEED_PREFIX, self.list_config.key(), &bump_seed);
+        let signer = [Signer::from(&seeds)];
+
+        let current_lamports = self.review_queue.lamports();
+        if lamports > current_lamports {
+            pinocchio_system::instructions::Transfer {
+                from: self.reporter,
+                to: self.review_queue,
+                lamports: lamports - current_lamports,
+            }
+            .invoke()?;
+        }
+
+        pinocchio_system::instructions::Allocate {
+            account: self.review_queue,
+            space: ReviewQueue::LEN as u64,
+        }
+        .invoke_signed(&signer)?;
+
+        pinocchio_system::instructions::Assign {
+            account: self.review_queue,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signer)?;
+
+        let mut data = self.review_queue.try_borrow_mut_data()?;
+        let queue = unsafe { load_mut_unchecked::<ReviewQueue>(&mut data)? };
+        queue.discriminator = ReviewQueue::DISCRIMINATOR;
+        queue.list_config = *self.list_config.key();
+
+        Ok(())
+    }
+}
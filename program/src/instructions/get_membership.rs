@@ -0,0 +1,126 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use solana_curve25519::edwards::PodEdwardsPoint;
+
+use crate::{
+    evaluate_thaw, is_entry_address, is_entry_of_list, load, set_return_data_checked, ABLError,
+    ListConfig, WalletEntry,
+};
+
+/// Read-only membership check of a wallet on a list, returning a one byte
+/// status so other programs can gate on it through CPI. The decision is the
+/// one of `evaluate_thaw`, denied while the list is paused: warmups, sunsets,
+/// allowed windows and merkle proofs aren't taken into account.
+///
+/// `owner` is the wallet the list keys its entries by, the token account on
+/// lists keyed by token account, and `wallet_entry` has to be its entry
+/// address, whether the entry exists or not.
+pub struct GetMembership<'a> {
+    pub list_config: &'a AccountInfo,
+    pub owner: &'a AccountInfo,
+    pub wallet_entry: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for GetMembership<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [list_config, owner, wallet_entry] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidListConfig);
+        }
+
+        Ok(Self {
+            list_config,
+            owner,
+            wallet_entry,
+        })
+    }
+}
+
+impl<'a> GetMembership<'a> {
+    pub const DISCRIMINATOR: u8 = 0x17;
+
+    /// The list denies the wallet.
+    pub const DENIED: u8 = 0;
+    /// The list allows the wallet.
+    pub const ALLOWED: u8 = 1;
+
+    pub fn process(&self) -> ProgramResult {
+        let list_data = self.list_config.try_borrow_data()?;
+        let list_config =
+            unsafe { load::<ListConfig>(&list_data).map_err(|_| ABLError::InvalidListConfig)? };
+
+        let status = if Self::is_member(
+            self.list_config,
+            list_config,
+            self.owner,
+            self.wallet_entry,
+            Clock::get()?.unix_timestamp,
+        )? {
+            Self::ALLOWED
+        } else {
+            Self::DENIED
+        };
+
+        set_return_data_checked(&[status])?;
+
+        Ok(())
+    }
+
+    /// Whether `list_config` allows `owner`, given its entry address
+    /// `wallet_entry`. Fails when `wallet_entry` isn't the owner's entry
+    /// address or the list can't be evaluated.
+    pub(crate) fn is_member(
+        list: &AccountInfo,
+        list_config: &ListConfig,
+        owner: &AccountInfo,
+        wallet_entry: &AccountInfo,
+        now: i64,
+    ) -> Result<bool, ProgramError> {
+        // the entry has to be the owner's, so another wallet's entry can't
+        // stand in for it
+        if !is_entry_address(wallet_entry, list, list_config, owner, owner) {
+            return Err(ABLError::InvalidWalletEntry.into());
+        }
+
+        // paused lists deny every wallet, as they deny every thaw
+        if list_config.is_paused() {
+            return Ok(false);
+        }
+
+        let entry_data = if wallet_entry.is_owned_by(&crate::ID) {
+            Some(wallet_entry.try_borrow_data()?)
+        } else if wallet_entry.is_owned_by(&Pubkey::default()) {
+            None
+        } else {
+            return Err(ABLError::InvalidWalletEntry.into());
+        };
+
+        // anything but a wallet entry of the list counts as no entry
+        let wallet = entry_data
+            .as_deref()
+            .and_then(|data| unsafe { load::<WalletEntry>(data) }.ok())
+            .filter(|wallet| is_entry_of_list(wallet, list, list_config, owner));
+
+        let mode = list_config.get_mode();
+        let owner_is_on_curve =
+            (matches!(mode, crate::Mode::AllowAllEoas | crate::Mode::AllowAllPdas)
+                || list_config.is_in_eoa_grace_period(now))
+                && solana_curve25519::edwards::validate_edwards(&PodEdwardsPoint(*owner.key()));
+
+        match evaluate_thaw(list_config, owner_is_on_curve, wallet, now) {
+            Ok(()) => Ok(true),
+            Err(ABLError::AccountBlocked) => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
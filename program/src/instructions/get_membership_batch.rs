@@ -0,0 +1,68 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    load, set_return_data_checked, ABLError, GetMembership, ListConfig, MAX_TX_ACCOUNT_LOCKS,
+};
+
+/// Read-only membership check of many owners on one list, with the decision
+/// of `GetMembership` for each. Owners are passed as `(wallet_entry, owner)`
+/// pairs of remaining accounts, and the return data packs one bit per pair,
+/// set when the list allows the owner: pair `i` is bit `i % 8` of byte `i / 8`.
+pub struct GetMembershipBatch<'a> {
+    pub list_config: &'a AccountInfo,
+    pub pairs: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for GetMembershipBatch<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [list_config, pairs @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidListConfig);
+        }
+
+        if pairs.len() % 2 != 0 || pairs.len() / 2 > Self::MAX_OWNERS {
+            return Err(ABLError::InvalidRemainingAccounts);
+        }
+
+        Ok(Self { list_config, pairs })
+    }
+}
+
+impl<'a> GetMembershipBatch<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1A;
+
+    /// Most owners checked at once: the distinct pairs a transaction can lock
+    /// next to its fee payer, this program and the list config. Their bits
+    /// fit well within the return data limit.
+    pub const MAX_OWNERS: usize = (MAX_TX_ACCOUNT_LOCKS - 3) / 2;
+
+    pub fn process(&self) -> ProgramResult {
+        let list_data = self.list_config.try_borrow_data()?;
+        let list_config =
+            unsafe { load::<ListConfig>(&list_data).map_err(|_| ABLError::InvalidListConfig)? };
+
+        let now = Clock::get()?.unix_timestamp;
+
+        // return data: one bit per pair, in account order
+        let mut bitmask = [0u8; Self::MAX_OWNERS.div_ceil(8)];
+        for (i, pair) in self.pairs.chunks_exact(2).enumerate() {
+            let (wallet_entry, owner) = (&pair[0], &pair[1]);
+            if GetMembership::is_member(self.list_config, list_config, owner, wallet_entry, now)? {
+                bitmask[i / 8] |= 1 << (i % 8);
+            }
+        }
+
+        set_return_data_checked(&bitmask[..(self.pairs.len() / 2).div_ceil(8)])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::{grow_account, ABLError, Discriminator, ListConfig, Transmutable};
+
+/// Grows a list config written by an earlier version of the program to the
+/// current layout. Fields were only ever appended, so the new ones start
+/// zeroed, which leaves every setting added since off and the list unpaused.
+///
+/// Anyone can migrate a list, the payer funds the extra rent.
+pub struct MigrateListConfig<'a> {
+    pub payer: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for MigrateListConfig<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [payer, list_config, system_program] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        // only earlier layouts, current lists have nothing to migrate
+        let data = unsafe { list_config.borrow_data_unchecked() };
+        if !(ListConfig::LEGACY_LEN..ListConfig::LEN).contains(&data.len())
+            || data[0] != ListConfig::DISCRIMINATOR
+        {
+            return Err(ABLError::InvalidAccountData);
+        }
+
+        Ok(Self { payer, list_config })
+    }
+}
+
+impl<'a> MigrateListConfig<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1B;
+
+    pub fn process(&self) -> ProgramResult {
+        grow_account(self.payer, self.list_config, ListConfig::LEN)
+    }
+}
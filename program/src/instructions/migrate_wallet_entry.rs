@@ -0,0 +1,171 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    pubkey::{find_program_address, Pubkey},
+    seeds,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    close_account, grow_account, load, load_mut_unchecked, ABLError, Discriminator, ListConfig,
+    Transmutable, WalletEntry,
+};
+
+/// Brings a wallet entry written by an earlier version of the program to the
+/// current layout, once its list is migrated. Fields were only ever appended,
+/// so the new ones start zeroed: the entry never expires or lifts, and has no
+/// label, creation time or recorded creator.
+///
+/// Entries of the first layout live at the address derived without the
+/// namespace. They are moved to their current address, passed as
+/// `wallet_entry`, and the old account is closed into the payer. Later entries
+/// already live at their current address and are grown in place, passing the
+/// same account as `legacy_wallet_entry` and `wallet_entry`.
+///
+/// Anyone can migrate an entry, the payer funds the extra rent.
+pub struct MigrateWalletEntry<'a> {
+    pub payer: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub legacy_wallet_entry: &'a AccountInfo,
+    pub wallet_entry: &'a AccountInfo,
+    pub namespace: [u8; 16],
+    pub wallet: Pubkey,
+    pub wallet_entry_bump: u8,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for MigrateWalletEntry<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [payer, list_config, legacy_wallet_entry, wallet_entry, system_program] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        // the list has to be migrated first, the new address needs its namespace
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+        let namespace =
+            unsafe { load::<ListConfig>(list_config.borrow_data_unchecked())? }.namespace;
+
+        if !legacy_wallet_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidAccountOwner);
+        }
+
+        if !legacy_wallet_entry.is_writable() || !wallet_entry.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        // only earlier layouts, current entries have nothing to migrate
+        let data = unsafe { legacy_wallet_entry.borrow_data_unchecked() };
+        if !(WalletEntry::LEGACY_LEN..WalletEntry::LEN).contains(&data.len())
+            || data[0] != WalletEntry::DISCRIMINATOR
+        {
+            return Err(ABLError::InvalidAccountData);
+        }
+
+        // wallet_address (32) + list_config (32), in every layout
+        let wallet: Pubkey = data[1..33].try_into().unwrap();
+        if data[33..65].ne(list_config.key()) {
+            return Err(ABLError::InvalidWalletEntry);
+        }
+
+        let (wallet_entry_pk, wallet_entry_bump) = find_program_address(
+            &[
+                WalletEntry::SEED_PREFIX,
+                &namespace,
+                list_config.key(),
+                &wallet,
+            ],
+            &crate::ID,
+        );
+        if wallet_entry_pk.ne(wallet_entry.key()) {
+            return Err(ABLError::InvalidWalletEntry);
+        }
+
+        // entries of the first layout are moved from the address without the namespace
+        if legacy_wallet_entry.key().ne(wallet_entry.key()) {
+            let (legacy_pk, _) = find_program_address(
+                &[WalletEntry::SEED_PREFIX, list_config.key(), &wallet],
+                &crate::ID,
+            );
+            if data.len() != WalletEntry::LEGACY_LEN || legacy_pk.ne(legacy_wallet_entry.key()) {
+                return Err(ABLError::InvalidWalletEntry);
+            }
+        }
+
+        Ok(Self {
+            payer,
+            list_config,
+            legacy_wallet_entry,
+            wallet_entry,
+            namespace,
+            wallet,
+            wallet_entry_bump,
+        })
+    }
+}
+
+impl<'a> MigrateWalletEntry<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1C;
+
+    pub fn process(&self) -> ProgramResult {
+        if self.legacy_wallet_entry.key().eq(self.wallet_entry.key()) {
+            return grow_account(self.payer, self.wallet_entry, WalletEntry::LEN);
+        }
+
+        let lamports = Rent::get()?.minimum_balance(WalletEntry::LEN);
+
+        let bump_seed = [self.wallet_entry_bump];
+        let seeds = seeds!(
+            WalletEntry::SEED_PREFIX,
+            &self.namespace,
+            self.list_config.key(),
+            &self.wallet,
+            &bump_seed
+        );
+        let signer = [Signer::from(&seeds)];
+
+        let current_lamports = self.wallet_entry.lamports();
+
+        if lamports > current_lamports {
+            pinocchio_system::instructions::Transfer {
+                from: self.payer,
+                to: self.wallet_entry,
+                lamports: lamports - current_lamports,
+            }
+            .invoke()?;
+        }
+
+        pinocchio_system::instructions::Allocate {
+            account: self.wallet_entry,
+            space: WalletEntry::LEN as u64,
+        }
+        .invoke_signed(&signer)?;
+
+        pinocchio_system::instructions::Assign {
+            account: self.wallet_entry,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signer)?;
+
+        {
+            let mut data = self.wallet_entry.try_borrow_mut_data()?;
+            let entry = unsafe { load_mut_unchecked::<WalletEntry>(&mut data)? };
+            entry.discriminator = WalletEntry::DISCRIMINATOR;
+            entry.wallet_address = self.wallet;
+            entry.list_config = *self.list_config.key();
+            entry.namespace = self.namespace;
+        }
+
+        close_account(self.legacy_wallet_entry, self.payer)?;
+
+        Ok(())
+    }
+}
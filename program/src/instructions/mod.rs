@@ -1,13 +1,311 @@
+pub mod accept_authority_transfer;
 pub mod add_wallet;
+pub mod add_wallet_batch;
+pub mod archive_policy;
+pub mod audit_entries;
+pub mod create_audit_log;
+pub mod can_freeze_permissionless;
 pub mod can_thaw_permissionless;
+pub mod close_merkle_proof;
+pub mod compact_authority_set;
+pub mod configure_policy;
 pub mod create_list;
 pub mod delete_list;
+pub mod diagnose_thaw;
+pub mod drain_queue;
+pub mod enqueue_report;
+pub mod get_membership;
+pub mod get_membership_batch;
+pub mod migrate_list_config;
+pub mod migrate_wallet_entry;
+pub mod promote_to_allow_all_eoas;
+pub mod propose_authority_transfer;
+pub mod reaffirm_block;
 pub mod remove_wallet;
+pub mod set_delegate;
+pub mod set_frozen;
+pub mod set_manager;
+pub mod set_paused;
 pub mod setup_extra_metas;
+pub mod setup_freeze_extra_metas;
+pub mod submit_merkle_proof;
+pub mod update_merkle_root;
+pub mod update_mode;
+pub mod verify_merkle_membership;
 
+pub use accept_authority_transfer::*;
 pub use add_wallet::*;
+pub use add_wallet_batch::*;
+pub use archive_policy::*;
+pub use audit_entries::*;
+pub use create_audit_log::*;
+pub use can_freeze_permissionless::*;
 pub use can_thaw_permissionless::*;
+pub use close_merkle_proof::*;
+pub use compact_authority_set::*;
+pub use configure_policy::*;
 pub use create_list::*;
 pub use delete_list::*;
+pub use diagnose_thaw::*;
+pub use drain_queue::*;
+pub use enqueue_report::*;
+pub use get_membership::*;
+pub use get_membership_batch::*;
+pub use migrate_list_config::*;
+pub use migrate_wallet_entry::*;
+pub use promote_to_allow_all_eoas::*;
+pub use propose_authority_transfer::*;
+pub use reaffirm_block::*;
 pub use remove_wallet::*;
+pub use set_delegate::*;
+pub use set_frozen::*;
+pub use set_manager::*;
+pub use set_paused::*;
 pub use setup_extra_metas::*;
+pub use setup_freeze_extra_metas::*;
+pub use submit_merkle_proof::*;
+pub use update_merkle_root::*;
+pub use update_mode::*;
+pub use verify_merkle_membership::*;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    add_lamports, load, load_account_mut, ABLError, AuditLog, AuthoritySet, Discriminator,
+    ListConfig, ListRegistry,
+};
+
+/// Latest instruction data version understood by the program.
+pub const IX_DATA_VERSION: u8 = 3;
+
+/// Splits the version header (first byte after the discriminator) off
+/// versioned instruction data, rejecting versions newer than this program.
+/// Empty data comes from clients predating the header and reads as v0.
+#[inline(always)]
+pub fn parse_versioned_data(data: &[u8]) -> Result<(u8, &[u8]), ABLError> {
+    let [version, data @ ..] = data else {
+        return Ok((0, data));
+    };
+
+    if *version > IX_DATA_VERSION {
+        return Err(ABLError::InvalidInstruction);
+    }
+
+    Ok((*version, data))
+}
+
+/// Validation result as a u64, `0` when it passes, otherwise the program error
+/// as the runtime reports it.
+#[inline(always)]
+pub fn verdict_code(verdict: ProgramResult) -> u64 {
+    verdict.map_or_else(u64::from, |_| 0)
+}
+
+/// Maximum return data size accepted by the runtime.
+pub const MAX_RETURN_DATA_LEN: usize = 1024;
+
+/// Maximum number of accounts a transaction can lock, fee payer and programs
+/// included, which bounds the accounts any instruction can be passed.
+pub const MAX_TX_ACCOUNT_LOCKS: usize = 128;
+
+/// Sets the instruction return data, failing with `ReturnDataTooLarge` when
+/// `data` is over the runtime limit so callers get a clear error.
+#[inline(always)]
+pub fn set_return_data_checked(data: &[u8]) -> Result<(), ABLError> {
+    if data.len() > MAX_RETURN_DATA_LEN {
+        return Err(ABLError::ReturnDataTooLarge);
+    }
+
+    set_return_data(data);
+    Ok(())
+}
+
+/// Closes `account` into `destination` in one sequence, returning the lamports
+/// moved. The data is zeroed before closing, clearing the discriminator, so an
+/// account reopened at the same address can't be read as initialized.
+///
+/// Every lamport of `account` ends up in `destination`: the balance is moved
+/// before `close`, which only clears what is left, and the two balances are
+/// checked afterwards.
+#[inline(always)]
+pub fn close_account(
+    account: &AccountInfo,
+    destination: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    // closing an account into itself would burn its balance
+    if account.key() == destination.key() {
+        return Err(ABLError::InvalidAccountData.into());
+    }
+
+    let reclaimed_lamports = account.lamports();
+    let destination_lamports = add_lamports(destination.lamports(), reclaimed_lamports)?;
+
+    *destination.try_borrow_mut_lamports()? = destination_lamports;
+    *account.try_borrow_mut_lamports()? = 0;
+
+    account.try_borrow_mut_data()?.fill(0);
+    account.close()?;
+
+    if account.lamports() != 0 || destination.lamports() != destination_lamports {
+        return Err(ABLError::InvalidAccountData.into());
+    }
+
+    Ok(reclaimed_lamports)
+}
+
+/// Splits off the account receiving the rent of the accounts an instruction
+/// closes, which is always the list authority: the signer when it is the
+/// authority, otherwise the authority's account, passed by delegates and the
+/// manager in front of `accounts`. `None` when it wasn't passed, which the
+/// instruction rejects once the signer is authorized.
+#[inline(always)]
+pub fn split_rent_destination<'a>(
+    signer: &'a AccountInfo,
+    list_authority: &Pubkey,
+    accounts: &'a [AccountInfo],
+) -> Result<(Option<&'a AccountInfo>, &'a [AccountInfo]), ABLError> {
+    let (destination, accounts) = if signer.key().eq(list_authority) {
+        (signer, accounts)
+    } else {
+        match accounts.split_first() {
+            Some((destination, accounts)) if destination.key().eq(list_authority) => {
+                (destination, accounts)
+            }
+            _ => return Ok((None, accounts)),
+        }
+    };
+
+    if !destination.is_writable() {
+        return Err(ABLError::AccountNotWritable);
+    }
+
+    Ok((Some(destination), accounts))
+}
+
+/// Grows `account` to `len` bytes, zero filling the new bytes, with `payer`
+/// topping up its balance to the rent exemption of the new length.
+#[inline(always)]
+pub fn grow_account(payer: &AccountInfo, account: &AccountInfo, len: usize) -> ProgramResult {
+    let lamports = Rent::get()?.minimum_balance(len);
+    let current_lamports = account.lamports();
+
+    if lamports > current_lamports {
+        pinocchio_system::instructions::Transfer {
+            from: payer,
+            to: account,
+            lamports: lamports - current_lamports,
+        }
+        .invoke()?;
+    }
+
+    account.resize(len)
+}
+
+/// Checks that `authority` signed and is either the list authority, which
+/// holds every permission, the list manager, which can only add and remove
+/// wallets, or a delegate in `authority_set` holding `permission`.
+#[inline(always)]
+pub fn check_authority(
+    list_config_account: &AccountInfo,
+    list_config: &ListConfig,
+    authority: &AccountInfo,
+    authority_set: Option<&AccountInfo>,
+    permission: u16,
+) -> Result<(), ABLError> {
+    if !authority.is_signer() {
+        return Err(ABLError::InvalidAuthority);
+    }
+
+    if list_config.authority.eq(authority.key()) {
+        return Ok(());
+    }
+
+    if list_config.has_manager()
+        && list_config.manager.eq(authority.key())
+        && permission & !(AuthoritySet::ADD | AuthoritySet::REMOVE) == 0
+    {
+        return Ok(());
+    }
+
+    let Some(authority_set) = authority_set else {
+        return Err(ABLError::InvalidAuthority);
+    };
+
+    if !authority_set.is_owned_by(&crate::ID) {
+        return Err(ABLError::InvalidAuthoritySet);
+    }
+
+    let set = unsafe { load::<AuthoritySet>(authority_set.borrow_data_unchecked()) }
+        .map_err(|_| ABLError::InvalidAuthoritySet)?;
+    if set.list_config.ne(list_config_account.key()) {
+        return Err(ABLError::InvalidAuthoritySet);
+    }
+
+    if set.get_permissions(authority.key()) & permission != permission {
+        return Err(ABLError::InvalidAuthority);
+    }
+
+    Ok(())
+}
+
+/// Optional trailing accounts of the list management instructions.
+pub struct TrailingAccounts<'a> {
+    pub list_registry: Option<&'a AccountInfo>,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+/// Sorts the optional trailing accounts by their discriminator, so they can
+/// be passed in any order. Each kind can be passed once.
+#[inline(always)]
+pub fn parse_trailing_accounts(
+    accounts: &[AccountInfo],
+) -> Result<TrailingAccounts<'_>, ABLError> {
+    let mut trailing = TrailingAccounts {
+        list_registry: None,
+        authority_set: None,
+        audit_log: None,
+    };
+
+    for account in accounts {
+        let slot = match unsafe { account.borrow_data_unchecked() }.first() {
+            Some(&ListRegistry::DISCRIMINATOR) => &mut trailing.list_registry,
+            Some(&AuthoritySet::DISCRIMINATOR) => &mut trailing.authority_set,
+            Some(&AuditLog::DISCRIMINATOR) => &mut trailing.audit_log,
+            _ => return Err(ABLError::InvalidRemainingAccounts),
+        };
+
+        if slot.replace(account).is_some() {
+            return Err(ABLError::InvalidRemainingAccounts);
+        }
+    }
+
+    Ok(trailing)
+}
+
+/// Appends a record of `action` on `key` to the audit log of `list_config`,
+/// with `payload` holding the value the action set.
+#[inline(always)]
+pub fn append_audit_record(
+    audit_log: &AccountInfo,
+    list_config: &AccountInfo,
+    action: u8,
+    key: &Pubkey,
+    payload: &[u8],
+) -> ProgramResult {
+    let log = unsafe { load_account_mut::<AuditLog>(audit_log, true)? };
+    if log.list_config.ne(list_config.key()) {
+        return Err(ABLError::InvalidAuditLog.into());
+    }
+
+    log.append(action, key, Clock::get()?.slot, payload);
+
+    Ok(())
+}
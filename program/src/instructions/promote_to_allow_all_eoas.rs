@@ -0,0 +1,201 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, check_authority, close_account, load, load_account_mut,
+    parse_trailing_accounts, split_rent_destination, ABLError, AuditLog, AuthoritySet,
+    Discriminator, ListConfig, Mode, TrailingAccounts, WalletEntry,
+};
+
+use solana_curve25519::edwards::PodEdwardsPoint;
+
+/// Switches an allow list to allow all eoas and reclaims the rent of the
+/// entries that became redundant. EOAs are now allowed without an entry, so
+/// the on-curve entries passed are closed, while off-curve entries are still
+/// needed and left as they are. Entries can be passed over several calls,
+/// once the list allows all eoas.
+///
+/// A delegate needs both the update mode and the remove permission. Lists
+/// with an allow threshold can't be promoted, as thresholds only apply to
+/// allow lists.
+pub struct PromoteToAllowAllEoas<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    /// The list authority, which receives the rent of the closed entries.
+    pub rent_destination: Option<&'a AccountInfo>,
+    pub wallet_entries: &'a [AccountInfo],
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PromoteToAllowAllEoas<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        let list_authority =
+            unsafe { load::<ListConfig>(list_config.borrow_data_unchecked()) }?.authority;
+        let (rent_destination, remaining_accounts) =
+            split_rent_destination(authority, &list_authority, remaining_accounts)?;
+
+        // wallet entries, followed by the optional trailing accounts
+        let entries_len = remaining_accounts
+            .iter()
+            .position(|account| {
+                unsafe { account.borrow_data_unchecked() }.first()
+                    != Some(&WalletEntry::DISCRIMINATOR)
+            })
+            .unwrap_or(remaining_accounts.len());
+        let (wallet_entries, remaining_accounts) = remaining_accounts.split_at(entries_len);
+
+        if wallet_entries.len() > Self::MAX_BATCH {
+            return Err(ABLError::BatchTooLarge);
+        }
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            rent_destination,
+            wallet_entries,
+            authority_set,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> PromoteToAllowAllEoas<'a> {
+    pub const DISCRIMINATOR: u8 = 0x12;
+
+    /// Maximum number of entries reclaimed by one instruction, larger sets
+    /// are passed over several calls.
+    pub const MAX_BATCH: usize = 20;
+
+    pub fn process(&self) -> ProgramResult {
+        let list_config = unsafe { load_account_mut::<ListConfig>(self.list_config, true)? };
+
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::UPDATE_MODE | AuthoritySet::REMOVE,
+        )?;
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        let rent_destination = self.rent_destination.ok_or(ABLError::NotEnoughAccounts)?;
+
+        // entries of lists keyed by token account aren't keyed by owner, so
+        // whether they are redundant can't be told from their address
+        if list_config.is_keyed_by_token_account() {
+            return Err(ABLError::InvalidListConfig.into());
+        }
+
+        let promoted = match list_config.get_mode() {
+            Mode::Allow => {
+                // thresholds only apply to allow lists
+                if list_config.allow_threshold > 0 {
+                    return Err(ABLError::InvalidData.into());
+                }
+
+                list_config.set_mode(Mode::AllowAllEoas as u8)?;
+                pinocchio_log::log!("List {} now allows all eoas", self.list_config.key());
+                true
+            }
+            Mode::AllowAllEoas => false,
+            _ => return Err(ABLError::InvalidListConfig.into()),
+        };
+
+        if promoted {
+            if let Some(audit_log) = self.audit_log {
+                // payload: mode (1) + eoa_grace_until (8), as for UpdateMode
+                let mut payload = [list_config.mode; 9];
+                payload[1..].copy_from_slice(&list_config.eoa_grace_until);
+                append_audit_record(
+                    audit_log,
+                    self.list_config,
+                    AuditLog::ACTION_UPDATE_MODE,
+                    self.authority.key(),
+                    &payload,
+                )?;
+            }
+        }
+
+        let mut reclaimed = 0u64;
+        for wallet_entry in self.wallet_entries {
+            let (is_eoa, wallet) = {
+                if !wallet_entry.is_owned_by(&crate::ID) || !wallet_entry.is_writable() {
+                    return Err(ABLError::InvalidWalletEntry.into());
+                }
+                let entry = unsafe { load::<WalletEntry>(wallet_entry.borrow_data_unchecked()) }
+                    .map_err(|_| ABLError::InvalidWalletEntry)?;
+                if entry.list_config.ne(self.list_config.key())
+                    || entry.namespace != list_config.namespace
+                {
+                    return Err(ABLError::InvalidWalletEntry.into());
+                }
+
+                (
+                    solana_curve25519::edwards::validate_edwards(&PodEdwardsPoint(
+                        entry.wallet_address,
+                    )),
+                    entry.wallet_address,
+                )
+            };
+
+            if !is_eoa {
+                continue;
+            }
+
+            if let Some(audit_log) = self.audit_log {
+                append_audit_record(
+                    audit_log,
+                    self.list_config,
+                    AuditLog::ACTION_REMOVE,
+                    &wallet,
+                    &[],
+                )?;
+            }
+
+            close_account(wallet_entry, rent_destination)?;
+            list_config.decrement_wallets_count()?;
+            reclaimed += 1;
+        }
+
+        if promoted || reclaimed > 0 {
+            list_config.updated_at = Clock::get()?.unix_timestamp.to_le_bytes();
+            list_config.last_actor = *self.authority.key();
+        }
+
+        if reclaimed > 0 {
+            pinocchio_log::log!(
+                "Reclaimed {} eoa entries of list {}",
+                reclaimed,
+                self.list_config.key()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, check_authority, load_account_mut, parse_trailing_accounts, ABLError,
+    AuditLog, AuthoritySet, ListConfig, TrailingAccounts,
+};
+
+/// First step of an authority transfer: records the new authority as pending
+/// until it accepts with [`crate::AcceptAuthorityTransfer`]. Proposing again
+/// replaces the pending authority.
+pub struct ProposeAuthorityTransfer<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ProposeAuthorityTransfer<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            authority_set,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> ProposeAuthorityTransfer<'a> {
+    pub const DISCRIMINATOR: u8 = 0x10;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: new_authority (32)
+        let new_authority: &Pubkey = remaining_data
+            .try_into()
+            .map_err(|_| ABLError::InvalidData)?;
+
+        if new_authority.eq(&Pubkey::default()) {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        let list_config = unsafe { load_account_mut::<ListConfig>(self.list_config, true)? };
+
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::TRANSFER,
+        )?;
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        list_config.pending_authority = *new_authority;
+        list_config.updated_at = Clock::get()?.unix_timestamp.to_le_bytes();
+        list_config.last_actor = *self.authority.key();
+
+        if let Some(audit_log) = self.audit_log {
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_PROPOSE_AUTHORITY,
+                self.authority.key(),
+                new_authority,
+            )?;
+        }
+
+        pinocchio_log::log!(
+            "Authority transfer of list {} proposed to {}",
+            self.list_config.key(),
+            new_authority
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, load, load_account_mut, parse_trailing_accounts, ABLError, AuditLog,
+    ListConfig, Mode, TrailingAccounts, WalletEntry,
+};
+
+pub struct ReaffirmBlock<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub wallet_entry: &'a AccountInfo,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReaffirmBlock<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, wallet_entry, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set: None,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            wallet_entry,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> ReaffirmBlock<'a> {
+    pub const DISCRIMINATOR: u8 = 0x09;
+
+    pub fn process(&self) -> ProgramResult {
+        let list_config = unsafe { load::<ListConfig>(self.list_config.borrow_data_unchecked())? };
+
+        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        // only block lists with an appeal period have deadlines to extend
+        if !matches!(list_config.get_mode(), Mode::Block)
+            || list_config.get_appeal_period_secs() == 0
+        {
+            return Err(ABLError::InvalidListConfig.into());
+        }
+
+        let entry = unsafe { load_account_mut::<WalletEntry>(self.wallet_entry, true)? };
+        if entry.list_config.ne(self.list_config.key()) || entry.namespace != list_config.namespace
+        {
+            return Err(ABLError::InvalidWalletEntry.into());
+        }
+
+        // the new deadline runs a full appeal period from now, so a lifted
+        // block is put back in force as well
+        entry.appeal_deadline = list_config
+            .appeal_deadline(Clock::get()?.unix_timestamp)
+            .to_le_bytes();
+
+        if let Some(audit_log) = self.audit_log {
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_REAFFIRM_BLOCK,
+                &entry.wallet_address,
+                &entry.appeal_deadline,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,19 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
 
-use crate::{load, load_mut_unchecked, ABLError, ListConfig, WalletEntry};
+use crate::{
+    append_audit_record, check_authority, close_account, load, load_account_mut,
+    load_mut_unchecked, parse_trailing_accounts, set_return_data_checked, split_rent_destination,
+    ABLError, AuditLog, AuthoritySet, ListConfig, TrailingAccounts, WalletEntry,
+};
 
 pub struct RemoveWallet<'a> {
     pub authority: &'a AccountInfo,
     pub list_config: &'a AccountInfo,
     pub wallet_entry: &'a AccountInfo,
+    /// The list authority, which receives the rent of the entry.
+    pub rent_destination: Option<&'a AccountInfo>,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
 }
 
 impl<'a> RemoveWallet<'a> {
@@ -16,21 +24,43 @@ impl<'a> RemoveWallet<'a> {
             load_mut_unchecked::<ListConfig>(self.list_config.borrow_mut_data_unchecked())?
         };
 
-        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
-            return Err(ABLError::InvalidAuthority.into());
-        }
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::REMOVE,
+        )?;
 
-        let destination_lamports = self.authority.lamports();
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
 
-        unsafe {
-            *self.authority.borrow_mut_lamports_unchecked() = destination_lamports
-                .checked_add(self.wallet_entry.lamports())
-                .ok_or(ProgramError::ArithmeticOverflow)?;
+        let rent_destination = self.rent_destination.ok_or(ABLError::NotEnoughAccounts)?;
+
+        if let Some(audit_log) = self.audit_log {
+            let wallet = unsafe { load::<WalletEntry>(self.wallet_entry.borrow_data_unchecked())? }
+                .wallet_address;
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_REMOVE,
+                &wallet,
+                &[],
+            )?;
         }
-        self.wallet_entry.close()?;
+
+        let reclaimed_lamports = close_account(self.wallet_entry, rent_destination)?;
 
         list_config.decrement_wallets_count()?;
 
+        // return data: reclaimed lamports (8) + recipient (32) + wallets_count (8)
+        let mut return_data = [0u8; 8 + 32 + 8];
+        return_data[..8].copy_from_slice(&reclaimed_lamports.to_le_bytes());
+        return_data[8..40].copy_from_slice(rent_destination.key());
+        return_data[40..].copy_from_slice(&list_config.wallets_count);
+        set_return_data_checked(&return_data)?;
+
         Ok(())
     }
 }
@@ -39,31 +69,35 @@ impl<'a> TryFrom<&'a [AccountInfo]> for RemoveWallet<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, list_config, wallet_entry] = accounts else {
+        let [authority, list_config, wallet_entry, remaining_accounts @ ..] = accounts else {
             return Err(ABLError::NotEnoughAccounts);
         };
 
-        if !list_config.is_owned_by(&crate::ID) {
-            return Err(ABLError::InvalidConfigAccount);
-        }
-
-        if !list_config.is_writable() || !wallet_entry.is_writable() {
-            return Err(ABLError::AccountNotWritable);
-        }
-
-        if !wallet_entry.is_owned_by(&crate::ID) {
+        let list = unsafe { load_account_mut::<ListConfig>(list_config, true)? };
+        let we = unsafe { load_account_mut::<WalletEntry>(wallet_entry, true)? };
+        if !we.list_config.eq(list_config.key()) || we.namespace != list.namespace {
             return Err(ABLError::InvalidWalletEntry);
         }
 
-        let we = unsafe { load::<WalletEntry>(wallet_entry.borrow_data_unchecked())? };
-        if !we.list_config.eq(list_config.key()) {
-            return Err(ABLError::InvalidWalletEntry);
-        }
+        let (rent_destination, remaining_accounts) =
+            split_rent_destination(authority, &list.authority, remaining_accounts)?;
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
 
         Ok(Self {
             authority,
             list_config,
             wallet_entry,
+            rent_destination,
+            authority_set,
+            audit_log,
         })
     }
 }
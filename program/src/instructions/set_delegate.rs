@@ -0,0 +1,140 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    pubkey::{find_program_address, Pubkey},
+    seeds,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    load, load_mut, load_mut_unchecked, ABLError, AuthoritySet, Discriminator, ListConfig,
+    Transmutable,
+};
+
+pub struct SetDelegate<'a> {
+    pub authority: &'a AccountInfo,
+    pub payer: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub authority_set: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub authority_set_bump: u8,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetDelegate<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, payer, list_config, authority_set, system_program] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !authority_set.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        let (authority_set_pk, authority_set_bump) =
+            find_program_address(&[AuthoritySet::SEED_PREFIX, list_config.key()], &crate::ID);
+
+        if authority_set_pk.ne(authority_set.key()) {
+            return Err(ABLError::InvalidAuthoritySet);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            payer,
+            list_config,
+            authority_set,
+            system_program,
+            authority_set_bump,
+        })
+    }
+}
+
+impl<'a> SetDelegate<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0A;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: delegate (32) + permissions (2)
+        if remaining_data.len() != 32 + 2 {
+            return Err(ABLError::InvalidData.into());
+        }
+        let (delegate, permissions) = remaining_data.split_at(32);
+        let delegate: &Pubkey = delegate.try_into().unwrap();
+        let permissions = u16::from_le_bytes(permissions.try_into().unwrap());
+
+        if permissions & !AuthoritySet::ALL != 0 {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        // only the list authority manages delegates
+        let list_config = unsafe { load::<ListConfig>(self.list_config.borrow_data_unchecked())? };
+        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        if !self.authority_set.is_owned_by(&crate::ID) {
+            self.create_authority_set()?;
+        }
+
+        let mut data = self.authority_set.try_borrow_mut_data()?;
+        let set = unsafe { load_mut::<AuthoritySet>(&mut data)? };
+        set.set_permissions(delegate, permissions)?;
+
+        Ok(())
+    }
+
+    fn create_authority_set(&self) -> ProgramResult {
+        let lamports = Rent::get()?.minimum_balance(AuthoritySet::LEN);
+
+        let bump_seed = [self.authority_set_bump];
+        let seeds = seeds!(
+            AuthoritySet::SEED_PREFIX,
+            self.list_config.key(),
+            &bump_seed
+        );
+        let signer = [Signer::from(&seeds)];
+
+        let current_lamports = self.authority_set.lamports();
+        if lamports > current_lamports {
+            pinocchio_system::instructions::Transfer {
+                from: self.payer,
+                to: self.authority_set,
+                lamports: lamports - current_lamports,
+            }
+            .invoke()?;
+        }
+
+        pinocchio_system::instructions::Allocate {
+            account: self.authority_set,
+            space: AuthoritySet::LEN as u64,
+        }
+        .invoke_signed(&signer)?;
+
+        pinocchio_system::instructions::Assign {
+            account: self.authority_set,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signer)?;
+
+        let mut data = self.authority_set.try_borrow_mut_data()?;
+        let set = unsafe { load_mut_unchecked::<AuthoritySet>(&mut data)? };
+        set.discriminator = AuthoritySet::DISCRIMINATOR;
+        set.list_config = *self.list_config.key();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, check_authority, load_account_mut, parse_trailing_accounts, ABLError,
+    AuditLog, AuthoritySet, ListConfig, TrailingAccounts,
+};
+
+/// Freezes or unfreezes a list. While frozen, the list's entries, policy,
+/// delegates, authority and merkle proofs can't be changed and those
+/// instructions fail with `ListFrozen`; thaws are decided as usual. Pausing
+/// and resuming stay available, and a paused list denies thaws whether or not
+/// it is frozen.
+pub struct SetFrozen<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetFrozen<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            authority_set,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> SetFrozen<'a> {
+    pub const DISCRIMINATOR: u8 = 0x20;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: frozen (1)
+        let frozen = match remaining_data {
+            [frozen @ (0 | 1)] => *frozen,
+            _ => return Err(ABLError::InvalidData.into()),
+        };
+
+        let list_config = unsafe { load_account_mut::<ListConfig>(self.list_config, true)? };
+
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::CONFIGURE,
+        )?;
+
+        list_config.frozen = frozen;
+        list_config.updated_at = Clock::get()?.unix_timestamp.to_le_bytes();
+        list_config.last_actor = *self.authority.key();
+
+        if let Some(audit_log) = self.audit_log {
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_SET_FROZEN,
+                self.authority.key(),
+                &[frozen],
+            )?;
+        }
+
+        Ok(())
+    }
+}
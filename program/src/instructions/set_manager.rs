@@ -0,0 +1,88 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, load_account_mut, parse_trailing_accounts, ABLError, AuditLog, ListConfig,
+    TrailingAccounts,
+};
+
+/// Sets the manager of a list, the key that can add and remove wallets
+/// alongside the authority without being able to change the policy. The
+/// default key removes the manager.
+pub struct SetManager<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetManager<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        // delegates can't appoint a manager, so no authority set is taken
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set: None,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> SetManager<'a> {
+    pub const DISCRIMINATOR: u8 = 0x21;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: manager (32)
+        let manager: &Pubkey = remaining_data
+            .try_into()
+            .map_err(|_| ABLError::InvalidData)?;
+
+        let list_config = unsafe { load_account_mut::<ListConfig>(self.list_config, true)? };
+
+        // only the list authority appoints the manager
+        if list_config.authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        list_config.manager = *manager;
+        list_config.updated_at = Clock::get()?.unix_timestamp.to_le_bytes();
+        list_config.last_actor = *self.authority.key();
+
+        if let Some(audit_log) = self.audit_log {
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_SET_MANAGER,
+                self.authority.key(),
+                manager,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -7,13 +7,25 @@ use pinocchio::{
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
+use spl_discriminator::SplDiscriminate;
 use spl_tlv_account_resolution::{
     account::ExtraAccountMeta, seeds::Seed, solana_pubkey::Pubkey as SolanaPubkey,
     state::ExtraAccountMetaList,
 };
+use token_acl_interface::{
+    instruction::CanThawPermissionlessInstruction, THAW_EXTRA_ACCOUNT_METAS_SEED,
+};
 
-use crate::{load, ABLError, ListConfig, WalletEntry};
+use crate::{add_lamports, load, ABLError, ListConfig, MerkleProof, WalletEntry};
 
+/// Writes the CanThaw extra metas of a mint, resolving a (list, wallet entry)
+/// pair for every list passed. The CanFreeze extra metas are written by
+/// [`crate::SetupFreezeExtraMetas`], so mints that only gate thaws don't pay
+/// rent for them.
+///
+/// Lists in MerkleAllow mode resolve the owner's merkle proof account in place
+/// of the wallet entry, which is why UpdateMode keeps lists from switching
+/// into or out of that mode.
 pub struct SetupExtraMetas<'a> {
     pub authority: &'a AccountInfo,
     pub payer: &'a AccountInfo,
@@ -29,6 +41,19 @@ impl<'a> TryFrom<&'a [AccountInfo]> for SetupExtraMetas<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Self::parse(accounts, THAW_EXTRA_ACCOUNT_METAS_SEED)
+    }
+}
+
+impl<'a> SetupExtraMetas<'a> {
+    pub const DISCRIMINATOR: u8 = 0x04;
+
+    /// Most lists a mint's extra metas resolve.
+    pub const MAX_LISTS: usize = 5;
+
+    /// Parses the accounts shared with [`crate::SetupFreezeExtraMetas`],
+    /// with `extra_metas` the PDA of `seed` and the mint.
+    pub(crate) fn parse(accounts: &'a [AccountInfo], seed: &[u8]) -> Result<Self, ABLError> {
         let [authority, payer, token_acl_mint_config, mint, extra_metas, system_program, remaining_accounts @ ..] =
             accounts
         else {
@@ -40,13 +65,8 @@ impl<'a> TryFrom<&'a [AccountInfo]> for SetupExtraMetas<'a> {
         }
 
         // derive extra_metas account
-        let (extra_metas_address, extra_metas_bump) = find_program_address(
-            &[
-                token_acl_interface::THAW_EXTRA_ACCOUNT_METAS_SEED,
-                mint.key(),
-            ],
-            &crate::ID,
-        );
+        let (extra_metas_address, extra_metas_bump) =
+            find_program_address(&[seed, mint.key()], &crate::ID);
         // need to check because we cannot rely on system program create instruction
         // as the account may already be initialized
         if extra_metas_address.ne(extra_metas.key()) {
@@ -73,12 +93,15 @@ impl<'a> TryFrom<&'a [AccountInfo]> for SetupExtraMetas<'a> {
             extra_metas_bump,
         })
     }
-}
-
-impl<'a> SetupExtraMetas<'a> {
-    pub const DISCRIMINATOR: u8 = 0x04;
 
     pub fn process(&self) -> ProgramResult {
+        self.write_extra_metas::<CanThawPermissionlessInstruction>(THAW_EXTRA_ACCOUNT_METAS_SEED)
+    }
+
+    /// Checks the mint's Token ACL config and writes the metas of the lists
+    /// passed into `extra_metas`, the PDA of `seed` and the mint, for the
+    /// instruction `T`.
+    pub(crate) fn write_extra_metas<T: SplDiscriminate>(&self, seed: &[u8]) -> ProgramResult {
         let mint_config_data = self.token_acl_mint_config.try_borrow_data()?;
         let mint_config = token_acl::state::load_mint_config(&mint_config_data)
             .map_err(|_| ABLError::InvalidTokenAclMintConfig)?;
@@ -94,18 +117,23 @@ impl<'a> SetupExtraMetas<'a> {
             return Err(ABLError::InvalidGatingProgram.into());
         }
 
-        if self.remaining_accounts.len() > 5 {
+        if self.remaining_accounts.len() > Self::MAX_LISTS {
             return Err(ABLError::InvalidData.into());
         }
 
-        let mut lists = [Option::<&Pubkey>::None; 5];
+        let mut lists = [Option::<(&Pubkey, bool, bool)>::None; Self::MAX_LISTS];
         let mut i = 0;
         for account in self.remaining_accounts {
             if !account.is_owned_by(&crate::ID) {
                 return Err(ABLError::InvalidConfigAccount.into());
             }
-            let _ = unsafe { load::<ListConfig>(&account.try_borrow_data()?)? };
-            lists[i] = Some(account.key());
+            let data = account.try_borrow_data()?;
+            let list = unsafe { load::<ListConfig>(&data)? };
+            lists[i] = Some((
+                account.key(),
+                list.is_keyed_by_token_account(),
+                matches!(list.get_mode(), crate::Mode::MerkleAllow),
+            ));
             i += 1;
         }
 
@@ -140,20 +168,16 @@ impl<'a> SetupExtraMetas<'a> {
             } else if current_lamports > min_lamports {
                 // transfer from extra
                 let diff = current_lamports - min_lamports;
+                let payer_lamports = add_lamports(payer_lamports, diff)?;
                 unsafe {
                     *self.extra_metas.borrow_mut_lamports_unchecked() = min_lamports;
-                    *self.payer.borrow_mut_lamports_unchecked() =
-                        payer_lamports.checked_add(diff).unwrap();
+                    *self.payer.borrow_mut_lamports_unchecked() = payer_lamports;
                 }
             }
         } else {
             // create new account
             let bump_seed = [self.extra_metas_bump];
-            let seeds = seeds!(
-                token_acl_interface::THAW_EXTRA_ACCOUNT_METAS_SEED,
-                self.mint.key(),
-                &bump_seed
-            );
+            let seeds = seeds!(seed, self.mint.key(), &bump_seed);
             let signer = [Signer::from(&seeds)];
 
             let current_lamports = self.extra_metas.lamports();
@@ -185,42 +209,66 @@ impl<'a> SetupExtraMetas<'a> {
         let mut extra_metas_data = self.extra_metas.try_borrow_mut_data()?;
         let (metas, len) = get_extra_metas(lists_slice);
 
-        ExtraAccountMetaList::init::<
-            token_acl_interface::instruction::CanThawPermissionlessInstruction,
-        >(&mut extra_metas_data, &metas[..len])
-        .unwrap();
+        ExtraAccountMetaList::init::<T>(&mut extra_metas_data, &metas[..len]).unwrap();
         Ok(())
     }
 }
 
-fn get_extra_metas(lists: &[Option<&Pubkey>]) -> ([ExtraAccountMeta; 10], usize) {
+fn get_extra_metas(lists: &[Option<(&Pubkey, bool, bool)>]) -> ([ExtraAccountMeta; 10], usize) {
     let mut metas = [ExtraAccountMeta::default(); 10];
 
     let mut index: usize = 0;
     for list in lists {
-        metas[index] = ExtraAccountMeta::new_with_pubkey(
-            &SolanaPubkey::new_from_array(*list.unwrap()),
-            false,
-            false,
-        )
-        .unwrap();
-        metas[index + 1] = ExtraAccountMeta::new_with_seeds(
-            &[
-                Seed::Literal {
-                    bytes: WalletEntry::SEED_PREFIX.to_vec(),
-                },
-                Seed::AccountKey {
-                    index: index as u8 + 6,
-                },
-                Seed::AccountData {
-                    account_index: 1, // token account
-                    data_index: 32,   // ta owner
-                    length: 32,
-                },
-            ],
-            false,
-            false,
-        )
+        let (list, key_by_token_account, merkle_allow) = list.unwrap();
+        metas[index] =
+            ExtraAccountMeta::new_with_pubkey(&SolanaPubkey::new_from_array(*list), false, false)
+                .unwrap();
+        let wallet_seed = if key_by_token_account {
+            Seed::AccountKey {
+                index: 1, // token account
+            }
+        } else {
+            Seed::AccountData {
+                account_index: 1, // token account
+                data_index: 32,   // ta owner
+                length: 32,
+            }
+        };
+        metas[index + 1] = if merkle_allow {
+            // the member's proof account, in place of its wallet entry
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: MerkleProof::SEED_PREFIX.to_vec(),
+                    },
+                    Seed::AccountKey {
+                        index: index as u8 + 6, // list
+                    },
+                    wallet_seed,
+                ],
+                false,
+                false,
+            )
+        } else {
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal {
+                        bytes: WalletEntry::SEED_PREFIX.to_vec(),
+                    },
+                    Seed::AccountData {
+                        account_index: index as u8 + 6,                 // list
+                        data_index: ListConfig::NAMESPACE_OFFSET as u8, // list namespace
+                        length: 16,
+                    },
+                    Seed::AccountKey {
+                        index: index as u8 + 6,
+                    },
+                    wallet_seed,
+                ],
+                false,
+                false,
+            )
+        }
         .unwrap();
         index += 2;
     }
@@ -228,6 +276,6 @@ fn get_extra_metas(lists: &[Option<&Pubkey>]) -> ([ExtraAccountMeta; 10], usize)
     (metas, index)
 }
 
-fn get_extra_metas_size(lists: &[Option<&Pubkey>]) -> usize {
+fn get_extra_metas_size(lists: &[Option<(&Pubkey, bool, bool)>]) -> usize {
     ExtraAccountMetaList::size_of(2 * lists.len()).unwrap()
 }
@@ -0,0 +1,35 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+use token_acl_interface::{
+    instruction::CanFreezePermissionlessInstruction, FREEZE_EXTRA_ACCOUNT_METAS_SEED,
+};
+
+use crate::{ABLError, SetupExtraMetas};
+
+/// Writes the CanFreeze extra metas of a mint into their own PDA, taking the
+/// same accounts as [`crate::SetupExtraMetas`] with the freeze extra metas in
+/// place of the thaw ones. Pass the same lists as for the thaw metas, as
+/// freezes resolve the same (list, wallet entry) pairs.
+pub struct SetupFreezeExtraMetas<'a> {
+    pub setup: SetupExtraMetas<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetupFreezeExtraMetas<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            setup: SetupExtraMetas::parse(accounts, FREEZE_EXTRA_ACCOUNT_METAS_SEED)?,
+        })
+    }
+}
+
+impl<'a> SetupFreezeExtraMetas<'a> {
+    pub const DISCRIMINATOR: u8 = 0x23;
+
+    pub fn process(&self) -> ProgramResult {
+        self.setup
+            .write_extra_metas::<CanFreezePermissionlessInstruction>(
+                FREEZE_EXTRA_ACCOUNT_METAS_SEED,
+            )
+    }
+}
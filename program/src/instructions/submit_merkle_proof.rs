@@ -0,0 +1,158 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    pubkey::find_program_address,
+    seeds,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    load, load_mut, load_mut_unchecked, merkle_leaf, parse_merkle_proof, verify_merkle_proof,
+    ABLError, Discriminator, ListConfig, MerkleProof, Transmutable, MAX_MERKLE_PROOF_LEN,
+};
+
+/// Stores the merkle proof of a member of a MerkleAllow list in the member's
+/// proof account, where the thaw extra metas resolve it. Token ACL only
+/// forwards its discriminator to CanThaw, so the proof can't travel with the
+/// thaw itself.
+///
+/// The proof has to lead to the list's current root, and is stored with its
+/// root version. Anyone can submit it, the payer funds the account; a later
+/// submission overwrites the stored proof, for instance once the root changes.
+pub struct SubmitMerkleProof<'a> {
+    pub payer: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub member: &'a AccountInfo,
+    pub merkle_proof: &'a AccountInfo,
+    pub merkle_proof_bump: u8,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SubmitMerkleProof<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [payer, list_config, member, merkle_proof, system_program] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidListConfig);
+        }
+
+        if !merkle_proof.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        let (merkle_proof_pk, merkle_proof_bump) = find_program_address(
+            &[MerkleProof::SEED_PREFIX, list_config.key(), member.key()],
+            &crate::ID,
+        );
+        if merkle_proof_pk.ne(merkle_proof.key()) {
+            return Err(ABLError::InvalidMerkleProof);
+        }
+
+        Ok(Self {
+            payer,
+            list_config,
+            member,
+            merkle_proof,
+            merkle_proof_bump,
+        })
+    }
+}
+
+impl<'a> SubmitMerkleProof<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1D;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let (proof, root_version) = {
+            let list_data = self.list_config.try_borrow_data()?;
+            let list_config =
+                unsafe { load::<ListConfig>(&list_data).map_err(|_| ABLError::InvalidListConfig)? };
+
+            if !matches!(list_config.get_mode(), crate::Mode::MerkleAllow) {
+                return Err(ABLError::InvalidListConfig.into());
+            }
+
+            if list_config.is_frozen() {
+                return Err(ABLError::ListFrozen.into());
+            }
+
+            // data: proof
+            let proof = parse_merkle_proof(remaining_data, list_config.get_max_proof_depth())?;
+            list_config.check_merkle_eval_cost(proof.len())?;
+
+            if !verify_merkle_proof(
+                &list_config.merkle_root,
+                &merkle_leaf(self.member.key()),
+                proof,
+            ) {
+                return Err(ABLError::InvalidMerkleProof.into());
+            }
+
+            (proof, list_config.root_version)
+        };
+
+        if !self.merkle_proof.is_owned_by(&crate::ID) {
+            self.create_merkle_proof()?;
+        }
+
+        let mut data = self.merkle_proof.try_borrow_mut_data()?;
+        let merkle_proof = unsafe { load_mut::<MerkleProof>(&mut data)? };
+        merkle_proof.proof = [[0; 32]; MAX_MERKLE_PROOF_LEN];
+        merkle_proof.proof[..proof.len()].copy_from_slice(proof);
+        merkle_proof.proof_len = proof.len() as u8;
+        merkle_proof.root_version = root_version;
+
+        Ok(())
+    }
+
+    fn create_merkle_proof(&self) -> ProgramResult {
+        let lamports = Rent::get()?.minimum_balance(MerkleProof::LEN);
+
+        let bump_seed = [self.merkle_proof_bump];
+        let seeds = seeds!(
+            MerkleProof::SEED_PREFIX,
+            self.list_config.key(),
+            self.member.key(),
+            &bump_seed
+        );
+        let signer = [Signer::from(&seeds)];
+
+        let current_lamports = self.merkle_proof.lamports();
+
+        if lamports > current_lamports {
+            pinocchio_system::instructions::Transfer {
+                from: self.payer,
+                to: self.merkle_proof,
+                lamports: lamports - current_lamports,
+            }
+            .invoke()?;
+        }
+
+        pinocchio_system::instructions::Allocate {
+            account: self.merkle_proof,
+            space: MerkleProof::LEN as u64,
+        }
+        .invoke_signed(&signer)?;
+
+        pinocchio_system::instructions::Assign {
+            account: self.merkle_proof,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&signer)?;
+
+        let mut data = self.merkle_proof.try_borrow_mut_data()?;
+        let merkle_proof = unsafe { load_mut_unchecked::<MerkleProof>(&mut data)? };
+        merkle_proof.discriminator = MerkleProof::DISCRIMINATOR;
+        merkle_proof.list_config = *self.list_config.key();
+        merkle_proof.member = *self.member.key();
+
+        Ok(())
+    }
+}
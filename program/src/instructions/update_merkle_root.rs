@@ -0,0 +1,112 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, check_authority, load_account_mut, parse_trailing_accounts, ABLError,
+    AuditLog, AuthoritySet, ListConfig, Mode, TrailingAccounts,
+};
+
+/// Sets the merkle root of a MerkleAllow list, replacing the members admitted
+/// by proof, and bumps its root version. Wallet entries of the list are
+/// unaffected.
+///
+/// The data can name the root version the new root was computed from, such
+/// as for a nightly recomputed list. The root is then only replaced while the
+/// list is still at that version, so a recomputation racing another one fails
+/// with `RootVersionMismatch` instead of overwriting it.
+pub struct UpdateMerkleRoot<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UpdateMerkleRoot<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            authority_set,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> UpdateMerkleRoot<'a> {
+    pub const DISCRIMINATOR: u8 = 0x16;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: merkle_root (32), optionally followed by expected_root_version (8)
+        let (merkle_root, expected_root_version) = remaining_data
+            .split_first_chunk::<32>()
+            .ok_or(ABLError::InvalidData)?;
+        let expected_root_version: Option<[u8; 8]> = match expected_root_version {
+            [] => None,
+            version => Some(version.try_into().map_err(|_| ABLError::InvalidData)?),
+        };
+
+        let list_config = unsafe { load_account_mut::<ListConfig>(self.list_config, true)? };
+
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::CONFIGURE,
+        )?;
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        if !matches!(list_config.get_mode(), Mode::MerkleAllow) {
+            return Err(ABLError::InvalidListConfig.into());
+        }
+
+        if expected_root_version.is_some_and(|version| version != list_config.root_version) {
+            return Err(ABLError::RootVersionMismatch.into());
+        }
+
+        list_config.set_merkle_root(*merkle_root)?;
+        list_config.updated_at = Clock::get()?.unix_timestamp.to_le_bytes();
+        list_config.last_actor = *self.authority.key();
+
+        if let Some(audit_log) = self.audit_log {
+            // payload: merkle_root (32) + root_version (8)
+            let mut payload = [0; 40];
+            payload[..32].copy_from_slice(&list_config.merkle_root);
+            payload[32..].copy_from_slice(&list_config.root_version);
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_UPDATE_MERKLE_ROOT,
+                self.authority.key(),
+                &payload,
+            )?;
+        }
+
+        Ok(())
+    }
+}
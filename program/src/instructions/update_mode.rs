@@ -0,0 +1,168 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    append_audit_record, check_authority, load_account_mut, parse_trailing_accounts, ABLError,
+    AuditLog, AuthoritySet, ListConfig, Mode, TrailingAccounts,
+};
+
+/// Switches the mode of a list in place.
+///
+/// Existing entries are reinterpreted by the new mode rather than migrated:
+/// switching between allow and block turns every allowed wallet into a
+/// blocked one and the other way around.
+///
+/// Moving a list from AllowAllEoas to Allow denies every EOA without an entry,
+/// so it has to be acknowledged, and can keep letting EOAs through for a grace
+/// period while their entries are added. Any other update ends a running
+/// grace period.
+///
+/// Lists can't move into or out of MerkleAllow, as their thaw extra metas
+/// resolve merkle proofs in place of wallet entries.
+pub struct UpdateMode<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub authority_set: Option<&'a AccountInfo>,
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UpdateMode<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        let TrailingAccounts {
+            list_registry: None,
+            authority_set,
+            audit_log,
+        } = parse_trailing_accounts(remaining_accounts)?
+        else {
+            return Err(ABLError::InvalidRemainingAccounts);
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            authority_set,
+            audit_log,
+        })
+    }
+}
+
+impl<'a> UpdateMode<'a> {
+    pub const DISCRIMINATOR: u8 = 0x13;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: mode (1), optionally followed by acknowledge (1)
+        //   + eoa_grace_period_secs (8)
+        let (mode, acknowledge, eoa_grace_period_secs) = match remaining_data {
+            [mode] => (*mode, 0, 0),
+            [mode, acknowledge, eoa_grace_period_secs @ ..] => {
+                let eoa_grace_period_secs = eoa_grace_period_secs
+                    .try_into()
+                    .map_err(|_| ABLError::InvalidData)?;
+                (
+                    *mode,
+                    *acknowledge,
+                    u64::from_le_bytes(eoa_grace_period_secs),
+                )
+            }
+            _ => return Err(ABLError::InvalidData.into()),
+        };
+
+        if acknowledge > 1 {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        let list_config = unsafe { load_account_mut::<ListConfig>(self.list_config, true)? };
+
+        check_authority(
+            self.list_config,
+            list_config,
+            self.authority,
+            self.authority_set,
+            AuthoritySet::UPDATE_MODE,
+        )?;
+
+        if list_config.is_frozen() {
+            return Err(ABLError::ListFrozen.into());
+        }
+
+        let previous_mode = list_config.get_mode();
+        list_config.set_mode(mode)?;
+
+        // the thaw extra metas record whether the list resolves merkle proofs
+        // or wallet entries, so a merkle list stays one
+        if matches!(previous_mode, Mode::MerkleAllow)
+            != matches!(list_config.get_mode(), Mode::MerkleAllow)
+        {
+            return Err(ABLError::InvalidListConfig.into());
+        }
+
+        // thresholds only apply to allow lists
+        if list_config.allow_threshold > 0 && !matches!(list_config.get_mode(), Mode::Allow) {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        let drops_all_eoas = matches!(previous_mode, Mode::AllowAllEoas)
+            && matches!(list_config.get_mode(), Mode::Allow);
+        if drops_all_eoas && acknowledge == 0 {
+            return Err(ABLError::ModeChangeNotAcknowledged.into());
+        }
+
+        // only the acknowledged move to Allow has a grace period
+        if !drops_all_eoas && eoa_grace_period_secs > 0 {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let eoa_grace_until = match eoa_grace_period_secs {
+            0 => 0,
+            secs => now.saturating_add_unsigned(secs),
+        };
+        list_config.eoa_grace_until = eoa_grace_until.to_le_bytes();
+
+        if drops_all_eoas {
+            pinocchio_log::log!(
+                "Warning: list {} no longer allows all EOAs, those without an entry are denied from {}",
+                self.list_config.key(),
+                eoa_grace_until.max(now)
+            );
+        }
+
+        if matches!(previous_mode, Mode::Block) != matches!(list_config.get_mode(), Mode::Block) {
+            pinocchio_log::log!(
+                "Warning: list {} switched between allowing and blocking, existing entries are reinterpreted",
+                self.list_config.key()
+            );
+        }
+
+        list_config.updated_at = now.to_le_bytes();
+        list_config.last_actor = *self.authority.key();
+
+        if let Some(audit_log) = self.audit_log {
+            // payload: mode (1) + eoa_grace_until (8)
+            let mut payload = [list_config.mode; 9];
+            payload[1..].copy_from_slice(&list_config.eoa_grace_until);
+            append_audit_record(
+                audit_log,
+                self.list_config,
+                AuditLog::ACTION_UPDATE_MODE,
+                self.authority.key(),
+                &payload,
+            )?;
+        }
+
+        Ok(())
+    }
+}
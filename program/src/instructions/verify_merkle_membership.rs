@@ -0,0 +1,56 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::{
+    load, merkle_leaf, parse_merkle_proof, set_return_data_checked, verify_merkle_proof, ABLError,
+    ListConfig,
+};
+
+/// Read-only check of a merkle proof against a list's current root, with the
+/// verification of the thaw, so clients can show membership before building
+/// a thaw or submitting the proof. Returns one byte, `1` when the proof leads
+/// from the member to the root and `0` when it doesn't.
+pub struct VerifyMerkleMembership<'a> {
+    pub list_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for VerifyMerkleMembership<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [list_config] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidListConfig);
+        }
+
+        Ok(Self { list_config })
+    }
+}
+
+impl<'a> VerifyMerkleMembership<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1F;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // data: member (32) + proof
+        let (member, proof) = remaining_data
+            .split_first_chunk::<32>()
+            .ok_or(ABLError::InvalidData)?;
+        let list_data = self.list_config.try_borrow_data()?;
+        let list_config =
+            unsafe { load::<ListConfig>(&list_data).map_err(|_| ABLError::InvalidListConfig)? };
+
+        if !matches!(list_config.get_mode(), crate::Mode::MerkleAllow) {
+            return Err(ABLError::InvalidListConfig.into());
+        }
+
+        let proof = parse_merkle_proof(proof, list_config.get_max_proof_depth())?;
+        list_config.check_merkle_eval_cost(proof.len())?;
+        let is_member = verify_merkle_proof(&list_config.merkle_root, &merkle_leaf(member), proof);
+
+        set_return_data_checked(&[is_member as u8])?;
+
+        Ok(())
+    }
+}
@@ -16,6 +16,10 @@ pub mod instructions;
 pub use instructions::*;
 pub mod error;
 pub use error::*;
+pub mod math;
+pub use math::*;
+pub mod merkle;
+pub use merkle::*;
 pub mod state;
 pub use state::*;
 
@@ -33,13 +37,61 @@ fn process_instruction(
 
     match *disc {
         CanThawPermissionless::DISCRIMINATOR => {
-            CanThawPermissionless::try_from(accounts)?.process()
+            CanThawPermissionless::try_from(accounts)?.process(remaining_data)
+        }
+        CanFreezePermissionless::DISCRIMINATOR => {
+            CanFreezePermissionless::try_from(accounts)?.process(remaining_data)
         }
         CreateList::DISCRIMINATOR => CreateList::try_from(accounts)?.process(remaining_data),
         DeleteList::DISCRIMINATOR => DeleteList::try_from(accounts)?.process(),
-        AddWallet::DISCRIMINATOR => AddWallet::try_from(accounts)?.process(),
+        AddWallet::DISCRIMINATOR => AddWallet::try_from(accounts)?.process(remaining_data),
         RemoveWallet::DISCRIMINATOR => RemoveWallet::try_from(accounts)?.process(),
         SetupExtraMetas::DISCRIMINATOR => SetupExtraMetas::try_from(accounts)?.process(),
+        EnqueueReport::DISCRIMINATOR => EnqueueReport::try_from(accounts)?.process(remaining_data),
+        DrainQueue::DISCRIMINATOR => DrainQueue::try_from(accounts)?.process(remaining_data),
+        ReaffirmBlock::DISCRIMINATOR => ReaffirmBlock::try_from(accounts)?.process(),
+        SetDelegate::DISCRIMINATOR => SetDelegate::try_from(accounts)?.process(remaining_data),
+        DiagnoseThaw::DISCRIMINATOR => DiagnoseThaw::try_from(accounts)?.process(),
+        CreateAuditLog::DISCRIMINATOR => CreateAuditLog::try_from(accounts)?.process(),
+        AuditEntries::DISCRIMINATOR => AuditEntries::try_from(accounts)?.process(),
+        ConfigurePolicy::DISCRIMINATOR => {
+            ConfigurePolicy::try_from(accounts)?.process(remaining_data)
+        }
+        AddWalletBatch::DISCRIMINATOR => {
+            AddWalletBatch::try_from(accounts)?.process(remaining_data)
+        }
+        ProposeAuthorityTransfer::DISCRIMINATOR => {
+            ProposeAuthorityTransfer::try_from(accounts)?.process(remaining_data)
+        }
+        AcceptAuthorityTransfer::DISCRIMINATOR => {
+            AcceptAuthorityTransfer::try_from(accounts)?.process()
+        }
+        PromoteToAllowAllEoas::DISCRIMINATOR => {
+            PromoteToAllowAllEoas::try_from(accounts)?.process()
+        }
+        UpdateMode::DISCRIMINATOR => UpdateMode::try_from(accounts)?.process(remaining_data),
+        CompactAuthoritySet::DISCRIMINATOR => CompactAuthoritySet::try_from(accounts)?.process(),
+        UpdateMerkleRoot::DISCRIMINATOR => {
+            UpdateMerkleRoot::try_from(accounts)?.process(remaining_data)
+        }
+        GetMembership::DISCRIMINATOR => GetMembership::try_from(accounts)?.process(),
+        ArchivePolicy::DISCRIMINATOR => ArchivePolicy::try_from(accounts)?.process(remaining_data),
+        SetPaused::DISCRIMINATOR => SetPaused::try_from(accounts)?.process(remaining_data),
+        SetFrozen::DISCRIMINATOR => SetFrozen::try_from(accounts)?.process(remaining_data),
+        SetManager::DISCRIMINATOR => SetManager::try_from(accounts)?.process(remaining_data),
+        GetMembershipBatch::DISCRIMINATOR => GetMembershipBatch::try_from(accounts)?.process(),
+        MigrateListConfig::DISCRIMINATOR => MigrateListConfig::try_from(accounts)?.process(),
+        MigrateWalletEntry::DISCRIMINATOR => MigrateWalletEntry::try_from(accounts)?.process(),
+        SubmitMerkleProof::DISCRIMINATOR => {
+            SubmitMerkleProof::try_from(accounts)?.process(remaining_data)
+        }
+        VerifyMerkleMembership::DISCRIMINATOR => {
+            VerifyMerkleMembership::try_from(accounts)?.process(remaining_data)
+        }
+        CloseMerkleProof::DISCRIMINATOR => CloseMerkleProof::try_from(accounts)?.process(),
+        SetupFreezeExtraMetas::DISCRIMINATOR => {
+            SetupFreezeExtraMetas::try_from(accounts)?.process()
+        }
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -0,0 +1,21 @@
+use crate::ABLError;
+
+/// Adds `amount` to a lamport balance.
+#[inline(always)]
+pub fn add_lamports(lamports: u64, amount: u64) -> Result<u64, ABLError> {
+    lamports
+        .checked_add(amount)
+        .ok_or(ABLError::ArithmeticOverflow)
+}
+
+/// Increments a count by one.
+#[inline(always)]
+pub fn inc_count(count: u64) -> Result<u64, ABLError> {
+    count.checked_add(1).ok_or(ABLError::ArithmeticOverflow)
+}
+
+/// Decrements a count by one.
+#[inline(always)]
+pub fn dec_count(count: u64) -> Result<u64, ABLError> {
+    count.checked_sub(1).ok_or(ABLError::ArithmeticUnderflow)
+}
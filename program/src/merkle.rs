@@ -0,0 +1,77 @@
+use pinocchio::{pubkey::Pubkey, syscalls::sol_sha256};
+
+use crate::ABLError;
+
+/// Prefix of the hashed leaves, keeping a leaf from passing as an inner node.
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+
+/// Prefix of the hashed inner nodes.
+pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+/// Longest proof a MerkleProof account holds, and the default proof depth of
+/// a list, enough for a tree of 2^24 (about 16.7 million) members.
+pub const MAX_MERKLE_PROOF_LEN: usize = 24;
+
+/// Estimated compute units of checking a proof besides its levels, mostly
+/// hashing the leaf.
+pub const MERKLE_PROOF_BASE_CU: u64 = 150;
+
+/// Estimated compute units of each level of a proof, one `sol_sha256` of the
+/// node prefix and both children plus their ordering.
+pub const MERKLE_PROOF_LEVEL_CU: u64 = 150;
+
+/// Estimated compute units of checking a proof of `depth` levels, compared
+/// with the list's `max_cu_per_eval` before any hashing.
+pub fn estimate_merkle_proof_cu(depth: usize) -> u64 {
+    MERKLE_PROOF_BASE_CU + MERKLE_PROOF_LEVEL_CU * depth as u64
+}
+
+/// Leaf of `key` in a merkle allowlist: `sha256(0x00 || key)`.
+pub fn merkle_leaf(key: &Pubkey) -> [u8; 32] {
+    hashv(&[&[MERKLE_LEAF_PREFIX], key])
+}
+
+/// Reads a proof from instruction data: count (4) + sibling (32) per level.
+/// Proofs of more than `max_depth` levels fail with `ProofTooLong` before
+/// their siblings are read.
+pub fn parse_merkle_proof(data: &[u8], max_depth: usize) -> Result<&[[u8; 32]], ABLError> {
+    let (count, proof) = data.split_first_chunk::<4>().ok_or(ABLError::InvalidData)?;
+    let count = u32::from_le_bytes(*count) as usize;
+    if count > max_depth.min(MAX_MERKLE_PROOF_LEN) {
+        return Err(ABLError::ProofTooLong);
+    }
+    if proof.len() != count * 32 {
+        return Err(ABLError::InvalidData);
+    }
+
+    Ok(bytemuck::cast_slice(proof))
+}
+
+/// Whether `proof` leads from `leaf` to `root`. Inner nodes hash their two
+/// children in sorted order, `sha256(0x01 || min || max)`, so the proof is
+/// just the sibling of each level, from the leaf up.
+pub fn verify_merkle_proof(root: &[u8; 32], leaf: &[u8; 32], proof: &[[u8; 32]]) -> bool {
+    let node = proof.iter().fold(*leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (&node, sibling)
+        } else {
+            (sibling, &node)
+        };
+        hashv(&[&[MERKLE_NODE_PREFIX], left, right])
+    });
+
+    node.eq(root)
+}
+
+#[inline(always)]
+fn hashv(vals: &[&[u8]]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    unsafe {
+        sol_sha256(
+            vals.as_ptr() as *const u8,
+            vals.len() as u64,
+            hash.as_mut_ptr(),
+        );
+    }
+    hash
+}
@@ -0,0 +1,68 @@
+use pinocchio::{pubkey::Pubkey, syscalls::sol_sha256};
+
+use super::{Discriminator, Transmutable};
+
+/// Tamper-evident history of a list's mutations. Every record is folded into
+/// `head` as `sha256(head || action || key || slot || payload)`, so the chain
+/// can be recomputed off-chain from the records. The payload is the new value
+/// of a list-level change and empty for wallet records.
+#[repr(C)]
+pub struct AuditLog {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    pub head: [u8; 32],
+    pub count: [u8; 8],
+}
+
+impl AuditLog {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_log";
+
+    pub const ACTION_ADD: u8 = 1;
+    pub const ACTION_REMOVE: u8 = 2;
+    /// List-level changes, recorded with the key of the signing authority.
+    pub const ACTION_UPDATE_MODE: u8 = 3;
+    pub const ACTION_SET_PAUSED: u8 = 4;
+    pub const ACTION_CONFIGURE_POLICY: u8 = 5;
+    pub const ACTION_SET_FROZEN: u8 = 6;
+    pub const ACTION_SET_MANAGER: u8 = 7;
+    pub const ACTION_UPDATE_MERKLE_ROOT: u8 = 8;
+    pub const ACTION_PROPOSE_AUTHORITY: u8 = 9;
+    pub const ACTION_ACCEPT_AUTHORITY: u8 = 10;
+    /// Wallet changes, recorded with the key of the wallet.
+    pub const ACTION_REAFFIRM_BLOCK: u8 = 11;
+    pub const ACTION_CLOSE_MERKLE_PROOF: u8 = 12;
+
+    pub fn get_count(&self) -> u64 {
+        u64::from_le_bytes(self.count)
+    }
+
+    pub fn append(&mut self, action: u8, key: &Pubkey, slot: u64, payload: &[u8]) {
+        let action = [action];
+        let slot = slot.to_le_bytes();
+        let vals: [&[u8]; 5] = [&self.head, &action, key, &slot, payload];
+
+        let mut head = [0u8; 32];
+        unsafe {
+            sol_sha256(
+                vals.as_ptr() as *const u8,
+                vals.len() as u64,
+                head.as_mut_ptr(),
+            );
+        }
+
+        self.head = head;
+        self.count = self.get_count().saturating_add(1).to_le_bytes();
+    }
+}
+
+impl Transmutable for AuditLog {
+    const LEN: usize = 1 + 32 + 32 + 8;
+}
+
+impl Discriminator for AuditLog {
+    const DISCRIMINATOR: u8 = 0x06;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
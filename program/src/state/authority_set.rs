@@ -0,0 +1,115 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::ABLError;
+
+use super::{Discriminator, Transmutable};
+
+/// Delegate keys of a list, each holding a subset of the permissions of the
+/// list authority. `permissions[i]` is the bitmask of `delegates[i]`.
+#[repr(C)]
+pub struct AuthoritySet {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    pub len: u8,
+    pub delegates: [Pubkey; AuthoritySet::CAPACITY],
+    pub permissions: [[u8; 2]; AuthoritySet::CAPACITY],
+}
+
+impl AuthoritySet {
+    pub const SEED_PREFIX: &'static [u8] = b"authority_set";
+
+    pub const CAPACITY: usize = 8;
+
+    pub const ADD: u16 = 1 << 0;
+    pub const REMOVE: u16 = 1 << 1;
+    pub const UPDATE_MODE: u16 = 1 << 2;
+    pub const TRANSFER: u16 = 1 << 3;
+    pub const CLOSE: u16 = 1 << 4;
+    pub const CONFIGURE: u16 = 1 << 5;
+
+    pub const ALL: u16 = Self::ADD
+        | Self::REMOVE
+        | Self::UPDATE_MODE
+        | Self::TRANSFER
+        | Self::CLOSE
+        | Self::CONFIGURE;
+
+    pub fn delegates(&self) -> &[Pubkey] {
+        &self.delegates[..self.len as usize]
+    }
+
+    pub fn get_permissions(&self, key: &Pubkey) -> u16 {
+        self.delegates()
+            .iter()
+            .position(|d| d.eq(key))
+            .map_or(0, |i| u16::from_le_bytes(self.permissions[i]))
+    }
+
+    /// Sets the permissions of `key`, adding it as a delegate if needed;
+    /// setting no permissions removes the delegate.
+    pub fn set_permissions(&mut self, key: &Pubkey, permissions: u16) -> Result<(), ABLError> {
+        let len = self.len as usize;
+
+        match self.delegates().iter().position(|d| d.eq(key)) {
+            Some(index) if permissions == 0 => {
+                self.delegates[index] = self.delegates[len - 1];
+                self.permissions[index] = self.permissions[len - 1];
+                self.delegates[len - 1] = Pubkey::default();
+                self.permissions[len - 1] = [0; 2];
+                self.len -= 1;
+            }
+            Some(index) => self.permissions[index] = permissions.to_le_bytes(),
+            None if permissions == 0 => {}
+            None => {
+                if len == Self::CAPACITY {
+                    return Err(ABLError::AuthoritySetFull);
+                }
+
+                self.delegates[len] = *key;
+                self.permissions[len] = permissions.to_le_bytes();
+                self.len += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Drops duplicate and default keys from the delegates, keeping the
+    /// first entry of each key in order, and clears the unused slots.
+    /// Returns the resulting count.
+    pub fn compact(&mut self) -> u8 {
+        let len = (self.len as usize).min(Self::CAPACITY);
+        let mut kept = 0;
+
+        for i in 0..len {
+            let key = self.delegates[i];
+            if key == Pubkey::default() || self.delegates[..kept].contains(&key) {
+                continue;
+            }
+
+            self.delegates[kept] = key;
+            self.permissions[kept] = self.permissions[i];
+            kept += 1;
+        }
+
+        for i in kept..Self::CAPACITY {
+            self.delegates[i] = Pubkey::default();
+            self.permissions[i] = [0; 2];
+        }
+        self.len = kept as u8;
+
+        self.len
+    }
+}
+
+impl Transmutable for AuthoritySet {
+    const LEN: usize = 1 + 32 + 1 + 32 * Self::CAPACITY + 2 * Self::CAPACITY;
+}
+
+impl Discriminator for AuthoritySet {
+    const DISCRIMINATOR: u8 = 0x05;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
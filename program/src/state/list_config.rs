@@ -1,4 +1,6 @@
-use pinocchio::{program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+use pinocchio::{pubkey::Pubkey, ProgramResult};
+
+use crate::{dec_count, inc_count, ABLError};
 
 use super::{Discriminator, Transmutable};
 
@@ -9,44 +11,396 @@ pub struct ListConfig {
     pub seed: Pubkey,
     pub mode: u8,
     pub wallets_count: [u8; 8],
+    pub namespace: [u8; 16],
+    /// Length of the enrollment rate window, `0` disables the limit.
+    pub enroll_window_secs: [u8; 8],
+    pub max_enrolls_per_window: [u8; 4],
+    pub window_start: [u8; 8],
+    pub window_enrolls: [u8; 4],
+    /// When set, wallet entries are keyed by token account address instead of owner.
+    pub key_by_token_account: u8,
+    /// Until this timestamp CanThaw allows every thaw, so entries can be populated first.
+    pub warmup_until: [u8; 8],
+    /// Token program the gated token accounts must belong to, the default key leaves it unbound.
+    pub token_program: Pubkey,
+    /// How CanThaw reacts to a mint whose transfer hook isn't this program.
+    pub transfer_hook_check: u8,
+    /// Time a block entry stays in force before it needs reaffirming, `0` disables appeals.
+    pub appeal_period_secs: [u8; 8],
+    /// When set, Allow mode also denies thaws of token accounts with a zero balance.
+    pub require_nonzero_balance: u8,
+    /// Second of the day (UTC) thaws are allowed from, equal bounds disable the window.
+    pub allowed_window_start: [u8; 4],
+    /// Second of the day (UTC) thaws are allowed until, before `allowed_window_start`
+    /// when the window wraps past midnight.
+    pub allowed_window_end: [u8; 4],
+    /// When set, CanThaw also requires the list authority to sign the thaw.
+    pub require_authority_cosign: u8,
+    /// Allow mode only: minimum number of allow lists the owner has to be on,
+    /// `0` requires this list itself. Takes effect once every allow list of a
+    /// thaw sets one.
+    pub allow_threshold: u8,
+    /// When set, token accounts delegated to the mint's freeze authority skip
+    /// the membership checks.
+    pub allow_freeze_authority_delegate: u8,
+    /// When set, block lists check that the wallet entry passed for a thaw is
+    /// the owner's entry, even when it doesn't exist, at the cost of deriving
+    /// its address.
+    pub strict_block_mode: u8,
+    /// Block mode only: when set, an owner blocked by this list can still thaw
+    /// with an entry on one of the thaw's allow lists. Unset, the block wins.
+    pub allow_precedence: u8,
+    /// From this timestamp on CanThaw allows every thaw, `0` never sunsets.
+    pub sunset_at: [u8; 8],
+    /// Maximum number of entries the list holds, `0` is unlimited.
+    pub max_wallets: [u8; 4],
+    /// When set, token accounts owned by a token-2022 multisig can thaw
+    /// without the immutable owner extension, as long as every list checked
+    /// sets it too.
+    pub allow_multisig_owner: u8,
+    /// Token account extensions that guard the owner, any of which satisfies
+    /// the owner check: `IMMUTABLE_OWNER` and `NON_TRANSFERABLE`. `0` accepts
+    /// the immutable owner extension only.
+    pub owner_extensions: u8,
+    /// MerkleAllow mode only: root of the tree whose leaves are the members
+    /// of the list, see `merkle_leaf`.
+    pub merkle_root: [u8; 32],
+    /// Authority proposed by the current one, default while no transfer is
+    /// pending. It becomes the authority once it accepts.
+    pub pending_authority: Pubkey,
+    /// Timestamp of the list's creation or last policy change.
+    pub updated_at: [u8; 8],
+    /// Signer of the list's creation or last policy change.
+    pub last_actor: Pubkey,
+    /// When set, every thaw checked against the list is denied, whatever the
+    /// other lists passed with it and whether or not the list is `frozen`.
+    pub paused: u8,
+    /// When set, a thaw passes once any of its lists allows it instead of
+    /// needing all of them. Takes effect once every list of a thaw sets it,
+    /// so no single list can relax the others.
+    pub any_list: u8,
+    /// When set, CanThaw logs a `METER` line with the compute units its
+    /// decision consumed. Off by default, as reading the remaining units
+    /// costs compute too.
+    pub meter: u8,
+    /// MerkleAllow mode only: bumped on every change of `merkle_root`. Stored
+    /// proofs record the version they were checked against, and only count
+    /// while it is current.
+    pub root_version: [u8; 8],
+    /// MerkleAllow mode only: most levels a proof may have, rejected with
+    /// `ProofTooLong` before any hashing. `0` allows `MAX_MERKLE_PROOF_LEN`.
+    pub max_proof_depth: u8,
+    /// Allow modes only: when set, an expired entry denies the owner even
+    /// where the mode would let it through without an entry. Unset, an
+    /// expired entry counts as absent and the mode decides.
+    pub deny_expired_entries: u8,
+    /// Allow mode only: until this timestamp owners on the curve are still let
+    /// through without an entry, as they were before the list left
+    /// AllowAllEoas. `0` grants no grace period.
+    pub eoa_grace_until: [u8; 8],
+    /// When set, the entries and policy of the list can't be changed. Unlike
+    /// `paused`, thaws are still decided as usual.
+    pub frozen: u8,
+    /// Key that curates the entries of the list besides the authority, it
+    /// can add and remove wallets but not change the policy. The default key
+    /// when the list has no manager.
+    pub manager: Pubkey,
+    /// MerkleAllow mode only: most compute units a proof check may be
+    /// estimated to take, proofs above it are rejected with `EvalTooExpensive`
+    /// before any hashing. `0` sets no ceiling.
+    pub max_cu_per_eval: [u8; 4],
+    /// Zeroed space later fields are carved out of, so adding one doesn't
+    /// take another `MigrateListConfig`.
+    pub reserved: [u8; 28],
 }
 
 impl ListConfig {
     pub const SEED_PREFIX: &'static [u8] = b"list_config";
 
+    /// Length of the first deployed layout, up to `wallets_count`. Every later
+    /// field was appended after it, see `MigrateListConfig`.
+    pub const LEGACY_LEN: usize = 1 + 32 + 32 + 1 + 8;
+
+    /// Byte offset of `namespace`, used to resolve wallet entry seeds from the list data.
+    pub const NAMESPACE_OFFSET: usize = 1 + 32 + 32 + 1 + 8;
+
+    pub const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+    /// Owner extension flag for the immutable owner account extension.
+    pub const IMMUTABLE_OWNER: u8 = 1 << 0;
+    /// Owner extension flag for the non-transferable account extension.
+    pub const NON_TRANSFERABLE: u8 = 1 << 1;
+
     pub fn get_mode(&self) -> Mode {
         match self.mode {
             0 => Mode::Allow,
             1 => Mode::AllowAllEoas,
+            3 => Mode::AllowAllPdas,
+            4 => Mode::MerkleAllow,
             _ => Mode::Block,
         }
     }
 
+    /// Sets the mode from its discriminant, rejecting unknown modes.
+    pub fn set_mode(&mut self, mode: u8) -> Result<(), ABLError> {
+        if mode > Mode::MerkleAllow as u8 {
+            return Err(ABLError::InvalidData);
+        }
+
+        self.mode = mode;
+        Ok(())
+    }
+
+    pub fn get_transfer_hook_check(&self) -> TransferHookCheck {
+        match self.transfer_hook_check {
+            0 => TransferHookCheck::Off,
+            1 => TransferHookCheck::Warn,
+            _ => TransferHookCheck::Error,
+        }
+    }
+
+    pub fn is_keyed_by_token_account(&self) -> bool {
+        self.key_by_token_account != 0
+    }
+
+    pub fn get_warmup_until(&self) -> i64 {
+        i64::from_le_bytes(self.warmup_until)
+    }
+
+    pub fn is_warming_up(&self, now: i64) -> bool {
+        now < self.get_warmup_until()
+    }
+
+    /// Whether this list gates accounts of `token_program`; unbound lists gate any.
+    pub fn gates_token_program(&self, token_program: &Pubkey) -> bool {
+        self.token_program == Pubkey::default() || self.token_program.eq(token_program)
+    }
+
+    pub fn get_appeal_period_secs(&self) -> u64 {
+        u64::from_le_bytes(self.appeal_period_secs)
+    }
+
+    /// Deadline for a block entry created or reaffirmed at `now`, `0` when the
+    /// list has no appeal period.
+    pub fn appeal_deadline(&self, now: i64) -> i64 {
+        match (self.get_mode(), self.get_appeal_period_secs()) {
+            (Mode::Block, period) if period > 0 => now.saturating_add_unsigned(period),
+            _ => 0,
+        }
+    }
+
+    pub fn requires_nonzero_balance(&self) -> bool {
+        self.require_nonzero_balance != 0
+    }
+
+    pub fn requires_authority_cosign(&self) -> bool {
+        self.require_authority_cosign != 0
+    }
+
+    pub fn allows_freeze_authority_delegate(&self) -> bool {
+        self.allow_freeze_authority_delegate != 0
+    }
+
+    pub fn get_allowed_window_start(&self) -> u32 {
+        u32::from_le_bytes(self.allowed_window_start)
+    }
+
+    pub fn get_allowed_window_end(&self) -> u32 {
+        u32::from_le_bytes(self.allowed_window_end)
+    }
+
+    /// Whether `now` falls inside the daily allowed window, lists without a
+    /// window allow thaws at any time.
+    pub fn is_within_allowed_window(&self, now: i64) -> bool {
+        let start = self.get_allowed_window_start();
+        let end = self.get_allowed_window_end();
+        if start == end {
+            return true;
+        }
+
+        let second_of_day = now.rem_euclid(Self::SECONDS_PER_DAY as i64) as u32;
+        if start < end {
+            start <= second_of_day && second_of_day < end
+        } else {
+            // the window wraps past midnight
+            second_of_day >= start || second_of_day < end
+        }
+    }
+
+    pub fn get_updated_at(&self) -> i64 {
+        i64::from_le_bytes(self.updated_at)
+    }
+
+    pub fn is_strict_block_mode(&self) -> bool {
+        self.strict_block_mode != 0
+    }
+
+    pub fn gives_allow_precedence(&self) -> bool {
+        self.allow_precedence != 0
+    }
+
+    pub fn get_sunset_at(&self) -> i64 {
+        i64::from_le_bytes(self.sunset_at)
+    }
+
+    pub fn is_sunset(&self, now: i64) -> bool {
+        let sunset_at = self.get_sunset_at();
+        sunset_at != 0 && now >= sunset_at
+    }
+
+    pub fn get_max_wallets(&self) -> u32 {
+        u32::from_le_bytes(self.max_wallets)
+    }
+
+    /// Whether the list holds as many entries as its cap allows.
+    pub fn is_full(&self) -> bool {
+        let max_wallets = self.get_max_wallets();
+        max_wallets != 0 && self.get_wallets_count() >= max_wallets as u64
+    }
+
+    pub fn allows_multisig_owner(&self) -> bool {
+        self.allow_multisig_owner != 0
+    }
+
+    /// Owner extensions accepted by the list, the immutable owner extension
+    /// when unset.
+    pub fn get_owner_extensions(&self) -> u8 {
+        match self.owner_extensions {
+            0 => Self::IMMUTABLE_OWNER,
+            owner_extensions => owner_extensions,
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen != 0
+    }
+
+    pub fn allows_any_list(&self) -> bool {
+        self.any_list != 0
+    }
+
+    pub fn is_metered(&self) -> bool {
+        self.meter != 0
+    }
+
+    pub fn get_root_version(&self) -> u64 {
+        u64::from_le_bytes(self.root_version)
+    }
+
+    pub fn get_max_proof_depth(&self) -> usize {
+        match self.max_proof_depth {
+            0 => crate::MAX_MERKLE_PROOF_LEN,
+            max_proof_depth => max_proof_depth as usize,
+        }
+    }
+
+    pub fn denies_expired_entries(&self) -> bool {
+        self.deny_expired_entries != 0
+    }
+
+    pub fn get_eoa_grace_until(&self) -> i64 {
+        i64::from_le_bytes(self.eoa_grace_until)
+    }
+
+    pub fn is_in_eoa_grace_period(&self, now: i64) -> bool {
+        now < self.get_eoa_grace_until()
+    }
+
+    /// Replaces the merkle root, moving on to the next root version.
+    pub fn set_merkle_root(&mut self, merkle_root: [u8; 32]) -> ProgramResult {
+        self.merkle_root = merkle_root;
+        self.root_version = inc_count(self.get_root_version())?.to_le_bytes();
+        Ok(())
+    }
+
+    pub fn has_manager(&self) -> bool {
+        self.manager.ne(&Pubkey::default())
+    }
+
+    pub fn get_max_cu_per_eval(&self) -> u32 {
+        u32::from_le_bytes(self.max_cu_per_eval)
+    }
+
+    /// Rejects a proof of `depth` levels with `EvalTooExpensive` when its
+    /// estimated cost exceeds the list's ceiling.
+    pub fn check_merkle_eval_cost(&self, depth: usize) -> Result<(), ABLError> {
+        match self.get_max_cu_per_eval() {
+            0 => Ok(()),
+            max_cu => match crate::estimate_merkle_proof_cu(depth) > max_cu as u64 {
+                true => Err(ABLError::EvalTooExpensive),
+                false => Ok(()),
+            },
+        }
+    }
+
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority.ne(&Pubkey::default())
+    }
+
     pub fn get_wallets_count(&self) -> u64 {
         u64::from_le_bytes(self.wallets_count)
     }
 
     pub fn increment_wallets_count(&mut self) -> ProgramResult {
-        self.wallets_count = self
-            .get_wallets_count()
-            .checked_add(1)
-            .ok_or(ProgramError::ArithmeticOverflow)?
-            .to_le_bytes();
+        self.wallets_count = inc_count(self.get_wallets_count())?.to_le_bytes();
+        Ok(())
+    }
+
+    pub fn get_enroll_window_secs(&self) -> u64 {
+        u64::from_le_bytes(self.enroll_window_secs)
+    }
+
+    pub fn get_max_enrolls_per_window(&self) -> u32 {
+        u32::from_le_bytes(self.max_enrolls_per_window)
+    }
+
+    pub fn get_window_start(&self) -> i64 {
+        i64::from_le_bytes(self.window_start)
+    }
+
+    pub fn get_window_enrolls(&self) -> u32 {
+        u32::from_le_bytes(self.window_enrolls)
+    }
+
+    /// Counts a new enrollment against the current window, starting a new
+    /// window once the previous one has elapsed.
+    pub fn record_enrollment(&mut self, now: i64) -> ProgramResult {
+        let window_secs = self.get_enroll_window_secs();
+        if window_secs == 0 {
+            return Ok(());
+        }
+
+        let window_end = self.get_window_start().saturating_add_unsigned(window_secs);
+        let enrolls = if now >= window_end {
+            self.window_start = now.to_le_bytes();
+            0
+        } else {
+            self.get_window_enrolls()
+        };
+
+        if enrolls >= self.get_max_enrolls_per_window() {
+            return Err(ABLError::EnrollRateExceeded.into());
+        }
+
+        self.window_enrolls = (enrolls + 1).to_le_bytes();
         Ok(())
     }
 
     pub fn decrement_wallets_count(&mut self) -> ProgramResult {
-        self.wallets_count = self
-            .get_wallets_count()
-            .checked_sub(1)
-            .ok_or(ProgramError::ArithmeticOverflow)?
-            .to_le_bytes();
+        self.wallets_count = dec_count(self.get_wallets_count())?.to_le_bytes();
         Ok(())
     }
 }
 
 impl Transmutable for ListConfig {
-    const LEN: usize = 1 + 32 + 32 + 8 + 1;
+    const LEN: usize = (1 + 32 + 32 + 1 + 8)
+        + (16 + 8 + 4 + 8 + 4 + 1 + 8 + 32 + 1 + 8 + 1 + 4 + 4 + 1 + 1 + 1 + 1 + 1 + 8 + 4 + 1 + 1)
+        + (32 + 32 + 8 + 32 + 1 + 1 + 1 + 8 + 1 + 1 + 8 + 1 + 32 + 4)
+        + 28;
 }
 
 impl Discriminator for ListConfig {
@@ -62,4 +416,13 @@ pub enum Mode {
     Allow,
     AllowAllEoas,
     Block,
+    AllowAllPdas,
+    MerkleAllow,
+}
+
+#[repr(u8)]
+pub enum TransferHookCheck {
+    Off,
+    Warn,
+    Error,
 }
@@ -0,0 +1,61 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::ABLError;
+
+use super::{Discriminator, Transmutable};
+
+/// Per-authority index of the lists it created, so they can be enumerated
+/// on-chain.
+#[repr(C)]
+pub struct ListRegistry {
+    pub discriminator: u8,
+    pub authority: Pubkey,
+    pub len: u8,
+    pub lists: [Pubkey; ListRegistry::CAPACITY],
+}
+
+impl ListRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"lists";
+
+    pub const CAPACITY: usize = 32;
+
+    pub fn lists(&self) -> &[Pubkey] {
+        &self.lists[..self.len as usize]
+    }
+
+    pub fn push(&mut self, list: &Pubkey) -> Result<(), ABLError> {
+        if self.len as usize == Self::CAPACITY {
+            return Err(ABLError::ListRegistryFull);
+        }
+
+        self.lists[self.len as usize] = *list;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes `list` by moving the last entry into its slot, returning
+    /// whether it was registered.
+    pub fn remove(&mut self, list: &Pubkey) -> bool {
+        let Some(index) = self.lists().iter().position(|l| l == list) else {
+            return false;
+        };
+
+        let last = self.len as usize - 1;
+        self.lists[index] = self.lists[last];
+        self.lists[last] = Pubkey::default();
+        self.len -= 1;
+        true
+    }
+}
+
+impl Transmutable for ListRegistry {
+    const LEN: usize = 1 + 32 + 1 + 32 * Self::CAPACITY;
+}
+
+impl Discriminator for ListRegistry {
+    const DISCRIMINATOR: u8 = 0x04;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
@@ -0,0 +1,48 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::MAX_MERKLE_PROOF_LEN;
+
+use super::{Discriminator, Transmutable};
+
+/// Merkle proof of a member of a MerkleAllow list, written by
+/// SubmitMerkleProof at `[SEED_PREFIX, list_config, member]` so the thaw extra
+/// metas can resolve it. The proof only counts while the list's root version
+/// is the one it was submitted at, and is checked against the root on every
+/// thaw, so a proof left over from a previous root no longer admits the member.
+#[repr(C)]
+pub struct MerkleProof {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    /// Wallet, or token account on lists keyed by token account, the proof is for.
+    pub member: Pubkey,
+    /// Root version of the list the proof was checked against on submission.
+    pub root_version: [u8; 8],
+    /// Number of levels of `proof` in use.
+    pub proof_len: u8,
+    /// Sibling hashes from the leaf up, zeroed past `proof_len`.
+    pub proof: [[u8; 32]; MAX_MERKLE_PROOF_LEN],
+}
+
+impl MerkleProof {
+    pub const SEED_PREFIX: &'static [u8] = b"merkle_proof";
+
+    pub fn get_root_version(&self) -> u64 {
+        u64::from_le_bytes(self.root_version)
+    }
+
+    pub fn get_proof(&self) -> &[[u8; 32]] {
+        &self.proof[..(self.proof_len as usize).min(MAX_MERKLE_PROOF_LEN)]
+    }
+}
+
+impl Transmutable for MerkleProof {
+    const LEN: usize = 1 + 32 + 32 + 8 + 1 + 32 * MAX_MERKLE_PROOF_LEN;
+}
+
+impl Discriminator for MerkleProof {
+    const DISCRIMINATOR: u8 = 0x08;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
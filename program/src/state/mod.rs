@@ -1,7 +1,22 @@
+pub mod audit_log;
+pub mod authority_set;
 pub mod list_config;
+pub mod list_registry;
+pub mod merkle_proof;
+pub mod policy_archive;
+pub mod review_queue;
 pub mod wallet_entry;
+pub use audit_log::*;
+pub use authority_set::*;
 pub use list_config::*;
-use pinocchio::account_info::AccountInfo;
+pub use list_registry::*;
+pub use merkle_proof::*;
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::{find_program_address, Pubkey},
+};
+pub use policy_archive::*;
+pub use review_queue::*;
 pub use wallet_entry::*;
 
 use crate::ABLError;
@@ -82,13 +97,167 @@ pub unsafe fn load_mut_unchecked<T: Transmutable>(bytes: &mut [u8]) -> Result<&m
     Ok(&mut *(bytes.as_mut_ptr() as *mut T))
 }
 
+/// Return a mutable reference for an initialized `T` held by a program owned
+/// account, checking the owner, writability when `require_writable` is set,
+/// length and discriminator in one place.
+///
+/// # Safety
+///
+/// The caller must ensure that no other borrow of the account data is alive
+/// while the returned reference is in use.
+#[allow(clippy::mut_from_ref)]
+#[inline(always)]
+pub unsafe fn load_account_mut<T: Discriminator + Transmutable>(
+    account: &AccountInfo,
+    require_writable: bool,
+) -> Result<&mut T, ABLError> {
+    if !account.is_owned_by(&crate::ID) {
+        return Err(ABLError::InvalidAccountOwner);
+    }
+
+    if require_writable && !account.is_writable() {
+        return Err(ABLError::AccountNotWritable);
+    }
+
+    load_mut(account.borrow_mut_data_unchecked())
+}
+
+/// Program id of the original SPL Token program.
+pub const TOKEN_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// Program id of the token-2022 program.
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
 const IMMUTABLE_OWNER_EXTENSION_ID: u16 = 7;
-const TOKEN_ACCOUNT_LEN: usize = 165;
+const NON_TRANSFERABLE_ACCOUNT_EXTENSION_ID: u16 = 13;
+const TRANSFER_HOOK_EXTENSION_ID: u16 = 14;
+pub(crate) const TOKEN_ACCOUNT_LEN: usize = 165;
 const EXTENSION_START_PADDING: usize = 1;
 const EXTENSION_LEN_BYTES_LEN: usize = 2;
 const EXTENSION_TYPE_BYTES_LEN: usize = 2;
 const EXTENSION_HEADER_LEN: usize = EXTENSION_LEN_BYTES_LEN + EXTENSION_TYPE_BYTES_LEN;
 const EXTENSION_DATA_START_INDEX: usize = TOKEN_ACCOUNT_LEN + EXTENSION_START_PADDING;
+const MULTISIG_LEN: usize = 355;
+const MULTISIG_IS_INITIALIZED_OFFSET: usize = 2;
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+const TOKEN_ACCOUNT_OWNER_END: usize = TOKEN_ACCOUNT_OWNER_OFFSET + 32;
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = TOKEN_ACCOUNT_OWNER_END;
+const TOKEN_ACCOUNT_AMOUNT_END: usize = TOKEN_ACCOUNT_AMOUNT_OFFSET + 8;
+// delegate is a COption<Pubkey>: tag (4) + pubkey (32)
+const TOKEN_ACCOUNT_DELEGATE_OFFSET: usize = TOKEN_ACCOUNT_AMOUNT_END;
+const TOKEN_ACCOUNT_DELEGATE_END: usize = TOKEN_ACCOUNT_DELEGATE_OFFSET + 4 + 32;
+
+// ExtraAccountMetaList TLV: discriminator (8) + length (4), followed by the
+// metas slice, which starts with its item count (4)
+const EXTRA_METAS_COUNT_OFFSET: usize = 8 + 4;
+const EXTRA_METAS_COUNT_END: usize = EXTRA_METAS_COUNT_OFFSET + 4;
+
+/// Checks that `extra_metas` is the PDA of `seed` and the mint holding this
+/// program's extra metas, and that it declares exactly `expected` metas, so
+/// stale metas left over from a previous list set are caught.
+#[inline(always)]
+pub fn validate_extra_metas(
+    extra_metas: &AccountInfo,
+    seed: &[u8],
+    mint: &AccountInfo,
+    expected: usize,
+) -> Result<(), ABLError> {
+    let (address, _) = find_program_address(&[seed, mint.key()], &crate::ID);
+    if address.ne(extra_metas.key()) || !extra_metas.is_owned_by(&crate::ID) {
+        return Err(ABLError::InvalidExtraMetasAccount);
+    }
+
+    let data = extra_metas
+        .try_borrow_data()
+        .map_err(|_| ABLError::InvalidExtraMetasAccount)?;
+
+    if data.len() < EXTRA_METAS_COUNT_END {
+        return Err(ABLError::InvalidExtraMetasAccount);
+    }
+
+    let count = u32::from_le_bytes(
+        data[EXTRA_METAS_COUNT_OFFSET..EXTRA_METAS_COUNT_END]
+            .try_into()
+            .unwrap(),
+    );
+    if count as usize != expected {
+        return Err(ABLError::InvalidExtraMetasAccount);
+    }
+
+    Ok(())
+}
+
+/// Reads the owner of a token account.
+///
+/// Extensions are appended after the base account layout, so the owner sits
+/// at the same offset for base and extended token-2022 accounts.
+#[inline(always)]
+pub fn token_account_owner(token_account: &AccountInfo) -> Result<Pubkey, ABLError> {
+    let data = token_account
+        .try_borrow_data()
+        .map_err(|_| ABLError::InvalidAccountData)?;
+
+    if data.len() < TOKEN_ACCOUNT_OWNER_END {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    Ok(data[TOKEN_ACCOUNT_OWNER_OFFSET..TOKEN_ACCOUNT_OWNER_END]
+        .try_into()
+        .unwrap())
+}
+
+/// Reads the delegate of a token account, `None` when it has none.
+#[inline(always)]
+pub fn token_account_delegate(token_account: &AccountInfo) -> Result<Option<Pubkey>, ABLError> {
+    let data = token_account
+        .try_borrow_data()
+        .map_err(|_| ABLError::InvalidAccountData)?;
+
+    if data.len() < TOKEN_ACCOUNT_DELEGATE_END {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    let (tag, delegate) =
+        data[TOKEN_ACCOUNT_DELEGATE_OFFSET..TOKEN_ACCOUNT_DELEGATE_END].split_at(4);
+    Ok((tag[0] != 0).then(|| delegate.try_into().unwrap()))
+}
+
+/// Reads the token balance of a token account.
+#[inline(always)]
+pub fn token_account_amount(token_account: &AccountInfo) -> Result<u64, ABLError> {
+    let data = token_account
+        .try_borrow_data()
+        .map_err(|_| ABLError::InvalidAccountData)?;
+
+    if data.len() < TOKEN_ACCOUNT_AMOUNT_END {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    Ok(u64::from_le_bytes(
+        data[TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_END]
+            .try_into()
+            .unwrap(),
+    ))
+}
+
+/// Checks if the token account is an account of the original SPL Token
+/// program, which has the base layout and no extensions.
+#[inline(always)]
+pub fn is_legacy_token_account(token_account: &AccountInfo) -> bool {
+    token_account.is_owned_by(&TOKEN_PROGRAM_ID)
+}
+
+/// Checks if `account` is an initialized token-2022 multisig: m (1) + n (1) +
+/// is_initialized (1) + signers (11 * 32).
+#[inline(always)]
+pub fn is_token_2022_multisig(account: &AccountInfo) -> bool {
+    account.is_owned_by(&TOKEN_2022_PROGRAM_ID)
+        && account.try_borrow_data().is_ok_and(|data| {
+            data.len() == MULTISIG_LEN && data[MULTISIG_IS_INITIALIZED_OFFSET] == 1
+        })
+}
 
 /// Checks if the token account has the immutable owner extension
 ///
@@ -103,8 +272,40 @@ pub fn has_immutable_owner_extension(token_account: &AccountInfo) -> bool {
     }
     let data = data.unwrap();
 
+    find_extension(&data, IMMUTABLE_OWNER_EXTENSION_ID).is_some()
+}
+
+/// Checks if the token account has the non-transferable account extension,
+/// which token-2022 adds to accounts of non-transferable mints.
+#[inline(always)]
+pub fn has_non_transferable_account_extension(token_account: &AccountInfo) -> bool {
+    token_account
+        .try_borrow_data()
+        .is_ok_and(|data| find_extension(&data, NON_TRANSFERABLE_ACCOUNT_EXTENSION_ID).is_some())
+}
+
+/// Reads the program id of a mint's transfer hook extension, `None` when the
+/// mint has no transfer hook or its program id is unset.
+#[inline(always)]
+pub fn mint_transfer_hook_program_id(mint: &AccountInfo) -> Option<Pubkey> {
+    let data = mint.try_borrow_data().ok()?;
+
+    // transfer hook: authority (32) + program_id (32)
+    let program_id: Pubkey = find_extension(&data, TRANSFER_HOOK_EXTENSION_ID)?
+        .get(32..64)?
+        .try_into()
+        .unwrap();
+
+    (program_id != Pubkey::default()).then_some(program_id)
+}
+
+/// Returns the data of the first extension of `extension_type` in a token-2022
+/// mint or token account. Mints are padded to the token account length, so
+/// extensions start at the same index for both.
+#[inline(always)]
+fn find_extension(data: &[u8], extension_type: u16) -> Option<&[u8]> {
     if data.len() < EXTENSION_DATA_START_INDEX {
-        return false;
+        return None;
     }
 
     let extension_bytes = &data[EXTENSION_DATA_START_INDEX..];
@@ -112,22 +313,24 @@ pub fn has_immutable_owner_extension(token_account: &AccountInfo) -> bool {
     let mut start = 0;
     let end = extension_bytes.len();
 
-    while start < end {
-        let extension_type = u16::from_le_bytes(
+    while start + EXTENSION_HEADER_LEN <= end {
+        let current_type = u16::from_le_bytes(
             extension_bytes[start..start + EXTENSION_TYPE_BYTES_LEN]
                 .try_into()
                 .unwrap(),
         );
-        if extension_type == IMMUTABLE_OWNER_EXTENSION_ID {
-            return true;
-        }
-
         let extension_len = u16::from_le_bytes(
             extension_bytes[start + EXTENSION_TYPE_BYTES_LEN..start + EXTENSION_HEADER_LEN]
                 .try_into()
                 .unwrap(),
-        );
-        start += EXTENSION_HEADER_LEN + extension_len as usize;
+        ) as usize;
+        let data_start = start + EXTENSION_HEADER_LEN;
+
+        if current_type == extension_type {
+            return extension_bytes.get(data_start..data_start + extension_len);
+        }
+
+        start = data_start + extension_len;
     }
-    false
+    None
 }
@@ -0,0 +1,43 @@
+use pinocchio::pubkey::Pubkey;
+
+use super::{Discriminator, ListConfig, Transmutable};
+
+/// Point-in-time copy of a list's policy, written once by ArchivePolicy. No
+/// instruction loads an archive for writing, so it never changes afterwards.
+#[repr(C)]
+pub struct PolicyArchive {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    pub seed: Pubkey,
+    /// Slot the archive was written at.
+    pub slot: [u8; 8],
+    /// Timestamp the archive was written at.
+    pub archived_at: [u8; 8],
+    /// Head of the list's audit log, hashing every membership change up to
+    /// the archive, zero when the list has no audit log.
+    pub membership_hash: [u8; 32],
+    /// Number of membership changes folded into `membership_hash`.
+    pub membership_records: [u8; 8],
+    /// List config data at `slot`: mode, flags, counts and limits.
+    pub policy: [u8; ListConfig::LEN],
+}
+
+impl PolicyArchive {
+    pub const SEED_PREFIX: &'static [u8] = b"policy_archive";
+
+    pub fn get_slot(&self) -> u64 {
+        u64::from_le_bytes(self.slot)
+    }
+}
+
+impl Transmutable for PolicyArchive {
+    const LEN: usize = 1 + 32 + 32 + 8 + 8 + 32 + 8 + ListConfig::LEN;
+}
+
+impl Discriminator for PolicyArchive {
+    const DISCRIMINATOR: u8 = 0x07;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
@@ -0,0 +1,63 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::ABLError;
+
+use super::{Discriminator, Transmutable};
+
+/// Append-only queue of wallets reported against a block list, waiting for the
+/// list authority to drain them into block entries.
+#[repr(C)]
+pub struct ReviewQueue {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    pub head: u8,
+    pub len: u8,
+    pub reports: [Pubkey; ReviewQueue::CAPACITY],
+}
+
+impl ReviewQueue {
+    pub const SEED_PREFIX: &'static [u8] = b"review_queue";
+
+    pub const CAPACITY: usize = 16;
+
+    /// Returns the report at `index` in queue order.
+    pub fn get(&self, index: usize) -> Option<&Pubkey> {
+        if index >= self.len as usize {
+            return None;
+        }
+        Some(&self.reports[(self.head as usize + index) % Self::CAPACITY])
+    }
+
+    pub fn contains(&self, wallet: &Pubkey) -> bool {
+        (0..self.len as usize).any(|i| self.get(i) == Some(wallet))
+    }
+
+    pub fn push(&mut self, wallet: &Pubkey) -> Result<(), ABLError> {
+        if self.len as usize == Self::CAPACITY {
+            return Err(ABLError::ReviewQueueFull);
+        }
+
+        let tail = (self.head as usize + self.len as usize) % Self::CAPACITY;
+        self.reports[tail] = *wallet;
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+        self.reports = [Pubkey::default(); Self::CAPACITY];
+    }
+}
+
+impl Transmutable for ReviewQueue {
+    const LEN: usize = 1 + 32 + 1 + 1 + 32 * Self::CAPACITY;
+}
+
+impl Discriminator for ReviewQueue {
+    const DISCRIMINATOR: u8 = 0x03;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
@@ -7,14 +7,66 @@ pub struct WalletEntry {
     pub discriminator: u8,
     pub wallet_address: Pubkey,
     pub list_config: Pubkey,
+    pub namespace: [u8; 16],
+    /// On block lists, the block lifts from this timestamp on unless reaffirmed; `0` never lifts.
+    pub appeal_deadline: [u8; 8],
+    /// The entry counts as absent from this timestamp on; `0` never expires.
+    pub expires_at: [u8; 8],
+    /// Why the wallet was added, UTF-8 padded with zeros; thaws don't read it.
+    pub label: [u8; 32],
+    /// Creation timestamp of the entry.
+    pub created_at: [u8; 8],
+    /// Signer that created the entry, the list authority or a delegate.
+    pub added_by: Pubkey,
 }
 
 impl WalletEntry {
     pub const SEED_PREFIX: &'static [u8] = b"wallet_entry";
+
+    /// Length of the first deployed layout, up to `list_config`. Those entries
+    /// live at `[SEED_PREFIX, list_config, wallet]`, without the namespace,
+    /// see `MigrateWalletEntry`.
+    pub const LEGACY_LEN: usize = 1 + 32 + 32;
+
+    pub fn get_appeal_deadline(&self) -> i64 {
+        i64::from_le_bytes(self.appeal_deadline)
+    }
+
+    pub fn get_expires_at(&self) -> i64 {
+        i64::from_le_bytes(self.expires_at)
+    }
+
+    pub fn get_created_at(&self) -> i64 {
+        i64::from_le_bytes(self.created_at)
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        let expires_at = self.get_expires_at();
+        expires_at != 0 && now >= expires_at
+    }
+
+    /// The label without its zero padding, empty when it isn't valid UTF-8.
+    pub fn label_str(&self) -> &str {
+        Self::label_text(&self.label).unwrap_or_default()
+    }
+
+    /// Text of a zero padded label, `None` when it isn't valid UTF-8.
+    pub fn label_text(label: &[u8; 32]) -> Option<&str> {
+        let len = label
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |last| last + 1);
+        core::str::from_utf8(&label[..len]).ok()
+    }
+
+    pub fn is_block_lifted(&self, now: i64) -> bool {
+        let deadline = self.get_appeal_deadline();
+        deadline != 0 && now >= deadline
+    }
 }
 
 impl Transmutable for WalletEntry {
-    const LEN: usize = 1 + 32 + 32;
+    const LEN: usize = 1 + 32 + 32 + 16 + 8 + 8 + 32 + 8 + 32;
 }
 
 impl Discriminator for WalletEntry {
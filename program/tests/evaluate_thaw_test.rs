@@ -0,0 +1,166 @@
+use token_acl_gate_program::{
+    evaluate_thaw, load_mut_unchecked, load_unchecked, ABLError, ListConfig, Mode, Transmutable,
+    WalletEntry,
+};
+
+const NOW: i64 = 1_000;
+
+fn list_data(mode: Mode) -> Vec<u8> {
+    let mut data = vec![0u8; ListConfig::LEN];
+    unsafe { load_mut_unchecked::<ListConfig>(&mut data) }
+        .unwrap()
+        .set_mode(mode as u8)
+        .unwrap();
+    data
+}
+
+fn entry_data(expires_at: i64, appeal_deadline: i64) -> Vec<u8> {
+    let mut data = vec![0u8; WalletEntry::LEN];
+    let entry = unsafe { load_mut_unchecked::<WalletEntry>(&mut data) }.unwrap();
+    entry.expires_at = expires_at.to_le_bytes();
+    entry.appeal_deadline = appeal_deadline.to_le_bytes();
+    data
+}
+
+/// Evaluates a `mode` list for an owner with the given entry, if any.
+fn evaluate(mode: Mode, owner_is_on_curve: bool, entry: Option<&[u8]>) -> Result<(), ABLError> {
+    let list_data = list_data(mode);
+    let list_config = unsafe { load_unchecked::<ListConfig>(&list_data) }.unwrap();
+    let wallet_entry = entry.map(|data| unsafe { load_unchecked::<WalletEntry>(data) }.unwrap());
+
+    evaluate_thaw(list_config, owner_is_on_curve, wallet_entry, NOW)
+}
+
+#[test]
+fn allow_requires_active_entry() {
+    assert_eq!(evaluate(Mode::Allow, true, Some(&entry_data(0, 0))), Ok(()));
+    assert_eq!(
+        evaluate(Mode::Allow, true, None),
+        Err(ABLError::AccountBlocked)
+    );
+    assert_eq!(
+        evaluate(Mode::Allow, true, Some(&entry_data(NOW, 0))),
+        Err(ABLError::AccountBlocked)
+    );
+}
+
+#[test]
+fn block_denies_active_entry() {
+    assert_eq!(evaluate(Mode::Block, true, None), Ok(()));
+    assert_eq!(
+        evaluate(Mode::Block, true, Some(&entry_data(0, 0))),
+        Err(ABLError::AccountBlocked)
+    );
+    // expired and lifted blocks no longer deny
+    assert_eq!(
+        evaluate(Mode::Block, true, Some(&entry_data(NOW, 0))),
+        Ok(())
+    );
+    assert_eq!(
+        evaluate(Mode::Block, true, Some(&entry_data(0, NOW - 1))),
+        Ok(())
+    );
+}
+
+#[test]
+fn allow_all_eoas_checks_entry_of_off_curve_owners() {
+    assert_eq!(evaluate(Mode::AllowAllEoas, true, None), Ok(()));
+    assert_eq!(
+        evaluate(Mode::AllowAllEoas, false, None),
+        Err(ABLError::AccountBlocked)
+    );
+    assert_eq!(
+        evaluate(Mode::AllowAllEoas, false, Some(&entry_data(0, 0))),
+        Ok(())
+    );
+}
+
+#[test]
+fn allow_all_pdas_checks_entry_of_on_curve_owners() {
+    assert_eq!(evaluate(Mode::AllowAllPdas, false, None), Ok(()));
+    assert_eq!(
+        evaluate(Mode::AllowAllPdas, true, None),
+        Err(ABLError::AccountBlocked)
+    );
+    assert_eq!(
+        evaluate(Mode::AllowAllPdas, true, Some(&entry_data(0, 0))),
+        Ok(())
+    );
+}
+
+#[test]
+fn deadlines_take_effect_at_their_timestamp() {
+    // expiries, appeal deadlines and sunsets all apply from their second on
+    assert_eq!(
+        evaluate(Mode::Allow, true, Some(&entry_data(NOW + 1, 0))),
+        Ok(())
+    );
+    assert_eq!(
+        evaluate(Mode::Allow, true, Some(&entry_data(NOW, 0))),
+        Err(ABLError::AccountBlocked)
+    );
+    assert_eq!(
+        evaluate(Mode::Block, true, Some(&entry_data(0, NOW + 1))),
+        Err(ABLError::AccountBlocked)
+    );
+    assert_eq!(
+        evaluate(Mode::Block, true, Some(&entry_data(0, NOW))),
+        Ok(())
+    );
+
+    let mut data = list_data(Mode::Allow);
+    let list_config = unsafe { load_mut_unchecked::<ListConfig>(&mut data) }.unwrap();
+    list_config.sunset_at = NOW.to_le_bytes();
+    assert!(!list_config.is_sunset(NOW - 1));
+    assert!(list_config.is_sunset(NOW));
+}
+
+#[test]
+fn expired_entries_count_as_absent_unless_denied() {
+    let expired = entry_data(NOW, 0);
+
+    // by default the mode decides as if there were no entry
+    assert_eq!(evaluate(Mode::AllowAllEoas, true, Some(&expired)), Ok(()));
+    assert_eq!(evaluate(Mode::AllowAllPdas, false, Some(&expired)), Ok(()));
+
+    let mut data = list_data(Mode::AllowAllEoas);
+    unsafe { load_mut_unchecked::<ListConfig>(&mut data) }
+        .unwrap()
+        .deny_expired_entries = 1;
+    let list_config = unsafe { load_unchecked::<ListConfig>(&data) }.unwrap();
+    let evaluate = |entry: Option<&[u8]>| {
+        let wallet_entry =
+            entry.map(|data| unsafe { load_unchecked::<WalletEntry>(data) }.unwrap());
+        evaluate_thaw(list_config, true, wallet_entry, NOW)
+    };
+
+    // denied by its expired entry, the owner is still let through without one
+    // or with an active one
+    assert_eq!(evaluate(Some(&expired)), Err(ABLError::AccountBlocked));
+    assert_eq!(evaluate(None), Ok(()));
+    assert_eq!(evaluate(Some(&entry_data(NOW + 1, 0))), Ok(()));
+}
+
+#[test]
+fn allow_lets_eoas_through_during_their_grace_period() {
+    let evaluate_with_grace = |eoa_grace_until: i64, owner_is_on_curve: bool| {
+        let mut data = list_data(Mode::Allow);
+        unsafe { load_mut_unchecked::<ListConfig>(&mut data) }
+            .unwrap()
+            .eoa_grace_until = eoa_grace_until.to_le_bytes();
+        let list_config = unsafe { load_unchecked::<ListConfig>(&data) }.unwrap();
+        evaluate_thaw(list_config, owner_is_on_curve, None, NOW)
+    };
+
+    assert_eq!(evaluate_with_grace(NOW + 1, true), Ok(()));
+    // PDAs were never let through without an entry
+    assert_eq!(
+        evaluate_with_grace(NOW + 1, false),
+        Err(ABLError::AccountBlocked)
+    );
+    // the grace period ends at its timestamp
+    assert_eq!(
+        evaluate_with_grace(NOW, true),
+        Err(ABLError::AccountBlocked)
+    );
+}
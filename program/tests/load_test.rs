@@ -0,0 +1,59 @@
+use token_acl_gate_program::{
+    load, ABLError, Discriminator, ListConfig, Transmutable, WalletEntry,
+};
+
+/// Account data of `T`'s length starting with `discriminator`.
+fn data_of<T: Transmutable>(discriminator: u8) -> Vec<u8> {
+    let mut data = vec![0u8; T::LEN];
+    data[0] = discriminator;
+    data
+}
+
+#[test]
+fn loads_matching_discriminator() {
+    let data = data_of::<ListConfig>(ListConfig::DISCRIMINATOR);
+    assert!(unsafe { load::<ListConfig>(&data) }.is_ok());
+
+    let data = data_of::<WalletEntry>(WalletEntry::DISCRIMINATOR);
+    assert!(unsafe { load::<WalletEntry>(&data) }.is_ok());
+}
+
+#[test]
+fn rejects_wallet_entry_as_list_config() {
+    let data = data_of::<WalletEntry>(WalletEntry::DISCRIMINATOR);
+    assert_eq!(
+        unsafe { load::<ListConfig>(&data) }.err(),
+        Some(ABLError::InvalidAccountData)
+    );
+
+    // a wallet entry discriminator is rejected even at the list config length
+    let data = data_of::<ListConfig>(WalletEntry::DISCRIMINATOR);
+    assert_eq!(
+        unsafe { load::<ListConfig>(&data) }.err(),
+        Some(ABLError::InvalidAccountData)
+    );
+}
+
+#[test]
+fn rejects_list_config_as_wallet_entry() {
+    let data = data_of::<ListConfig>(ListConfig::DISCRIMINATOR);
+    assert_eq!(
+        unsafe { load::<WalletEntry>(&data) }.err(),
+        Some(ABLError::InvalidAccountData)
+    );
+
+    let data = data_of::<WalletEntry>(ListConfig::DISCRIMINATOR);
+    assert_eq!(
+        unsafe { load::<WalletEntry>(&data) }.err(),
+        Some(ABLError::InvalidAccountData)
+    );
+}
+
+#[test]
+fn rejects_uninitialized_data() {
+    let data = data_of::<ListConfig>(0);
+    assert_eq!(
+        unsafe { load::<ListConfig>(&data) }.err(),
+        Some(ABLError::InvalidAccountData)
+    );
+}
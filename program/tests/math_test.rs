@@ -0,0 +1,65 @@
+use token_acl_gate_program::{
+    add_lamports, dec_count, inc_count, load_mut, ABLError, Discriminator, ListConfig, Transmutable,
+};
+
+#[test]
+fn add_lamports_up_to_max() {
+    assert_eq!(add_lamports(1, 2), Ok(3));
+    assert_eq!(add_lamports(u64::MAX - 1, 1), Ok(u64::MAX));
+    assert_eq!(add_lamports(u64::MAX, 0), Ok(u64::MAX));
+}
+
+#[test]
+fn add_lamports_overflow() {
+    assert_eq!(add_lamports(u64::MAX, 1), Err(ABLError::ArithmeticOverflow));
+    assert_eq!(
+        add_lamports(u64::MAX, u64::MAX),
+        Err(ABLError::ArithmeticOverflow)
+    );
+}
+
+#[test]
+fn inc_count_up_to_max() {
+    assert_eq!(inc_count(0), Ok(1));
+    assert_eq!(inc_count(u64::MAX - 1), Ok(u64::MAX));
+}
+
+#[test]
+fn inc_count_overflow() {
+    assert_eq!(inc_count(u64::MAX), Err(ABLError::ArithmeticOverflow));
+}
+
+#[test]
+fn dec_count_down_to_zero() {
+    assert_eq!(dec_count(u64::MAX), Ok(u64::MAX - 1));
+    assert_eq!(dec_count(1), Ok(0));
+}
+
+#[test]
+fn dec_count_underflow() {
+    assert_eq!(dec_count(0), Err(ABLError::ArithmeticUnderflow));
+}
+
+#[test]
+fn wallets_count_stays_at_its_bounds() {
+    let mut data = vec![0u8; ListConfig::LEN];
+    data[0] = ListConfig::DISCRIMINATOR;
+    let list_config = unsafe { load_mut::<ListConfig>(&mut data) }.unwrap();
+
+    assert_eq!(
+        list_config.decrement_wallets_count(),
+        Err(ABLError::ArithmeticUnderflow.into())
+    );
+    assert_eq!(list_config.get_wallets_count(), 0);
+
+    list_config.wallets_count = (u64::MAX - 1).to_le_bytes();
+    assert!(list_config.increment_wallets_count().is_ok());
+    assert_eq!(list_config.get_wallets_count(), u64::MAX);
+
+    // a failed increment leaves the count as it was
+    assert_eq!(
+        list_config.increment_wallets_count(),
+        Err(ABLError::ArithmeticOverflow.into())
+    );
+    assert_eq!(list_config.get_wallets_count(), u64::MAX);
+}
@@ -0,0 +1,578 @@
+use std::{fmt, str::FromStr};
+
+use num_traits::FromPrimitive;
+use solana_program_error::ProgramError;
+use solana_pubkey::Pubkey;
+
+use crate::{
+    accounts::{ListConfig, WalletEntry, LIST_CONFIG_DISCRIMINATOR},
+    instructions::{
+        AddWalletBatchBuilder, ConfigurePolicyBuilder, GetMembershipBatchBuilder,
+        PromoteToAllowAllEoasBuilder, UpdateMerkleRootBuilder,
+    },
+    types::Mode,
+};
+
+impl Mode {
+    /// Every mode, in discriminant order.
+    pub const ALL: [Mode; 5] = [
+        Mode::Allow,
+        Mode::AllowAllEoas,
+        Mode::Block,
+        Mode::AllowAllPdas,
+        Mode::MerkleAllow,
+    ];
+
+    /// Canonical name of the mode, as used by `Display` and `FromStr`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Mode::Allow => "allow",
+            Mode::AllowAllEoas => "allow_all_eoas",
+            Mode::Block => "block",
+            Mode::AllowAllPdas => "allow_all_pdas",
+            Mode::MerkleAllow => "merkle_allow",
+        }
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when parsing a string that isn't a canonical mode name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseModeError(pub String);
+
+impl fmt::Display for ParseModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown mode `{}`, expected one of ", self.0)?;
+        for (i, mode) in Mode::ALL.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(mode.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseModeError {}
+
+impl FromStr for Mode {
+    type Err = ParseModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Mode::ALL
+            .into_iter()
+            .find(|mode| mode.as_str() == s)
+            .ok_or_else(|| ParseModeError(s.to_string()))
+    }
+}
+
+/// Why a thaw is denied, decoded from a verdict reported by `DiagnoseThaw`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DenialReason {
+    /// The token account failed the checks run before any list.
+    TokenAccount,
+    /// The list is missing or isn't a list config account.
+    InvalidListConfig,
+    /// The list requires an allow entry for the owner and no active one exists.
+    MissingAllowEntry,
+    /// The owner has an active entry on a block list.
+    BlockEntryPresent,
+    /// The entry at the owner's address belongs to a different list or namespace.
+    InvalidWalletEntry,
+    /// The list is paused, denying every thaw.
+    Paused,
+    /// The list doesn't gate the token account's program.
+    InvalidGatingProgram,
+    /// The mint's transfer hook doesn't match the one the list expects.
+    TransferHookMismatch,
+    /// The thaw happens outside the list's allowed window.
+    OutsideAllowedWindow,
+    /// Any other error returned by the list's checks.
+    Other,
+}
+
+impl DenialReason {
+    /// Decodes the verdict of a list, `list_config` being its decoded data.
+    fn from_list_verdict(error: &ProgramError, list_config: Option<&ListConfig>) -> Self {
+        match (error, list_config) {
+            (ProgramError::Custom(2), Some(list_config)) if list_config.paused => {
+                DenialReason::Paused
+            }
+            (ProgramError::Custom(2), Some(list_config))
+                if list_config.mode == Mode::Block as u8 =>
+            {
+                DenialReason::BlockEntryPresent
+            }
+            (ProgramError::Custom(2), _) => DenialReason::MissingAllowEntry,
+            (ProgramError::Custom(6), _) => DenialReason::InvalidGatingProgram,
+            (ProgramError::Custom(15), _) => DenialReason::InvalidWalletEntry,
+            (ProgramError::Custom(16), _) => DenialReason::InvalidListConfig,
+            (ProgramError::Custom(21), _) => DenialReason::TransferHookMismatch,
+            (ProgramError::Custom(30), _) => DenialReason::OutsideAllowedWindow,
+            _ => DenialReason::Other,
+        }
+    }
+
+    fn describe(&self) -> &'static str {
+        match self {
+            DenialReason::TokenAccount => "the token account failed its checks",
+            DenialReason::InvalidListConfig => "the list is not a valid list config",
+            DenialReason::MissingAllowEntry => "no allow entry exists for the owner",
+            DenialReason::BlockEntryPresent => "an active block entry exists",
+            DenialReason::InvalidWalletEntry => {
+                "the wallet entry belongs to another list or namespace"
+            }
+            DenialReason::Paused => "the list is paused",
+            DenialReason::InvalidGatingProgram => "the list doesn't gate the token program",
+            DenialReason::TransferHookMismatch => "the mint's transfer hook doesn't match",
+            DenialReason::OutsideAllowedWindow => "the thaw is outside the allowed window",
+            DenialReason::Other => "the list rejected the thaw",
+        }
+    }
+}
+
+/// Structured explanation of the first verdict that denies a thaw.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DenialExplanation {
+    /// `None` when the token account itself was denied.
+    pub list: Option<Pubkey>,
+    pub wallet_entry: Option<Pubkey>,
+    /// `None` when the list account itself couldn't be decoded.
+    pub mode: Option<Mode>,
+    pub reason: DenialReason,
+    /// Error the program returned.
+    pub error: ProgramError,
+}
+
+impl DenialExplanation {
+    /// Program error code of the denial, the custom code for gate errors.
+    pub fn error_code(&self) -> u64 {
+        u64::from(self.error.clone())
+    }
+}
+
+impl fmt::Display for DenialExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.list {
+            Some(list) => write!(f, "denied by list {}", list)?,
+            None => f.write_str("denied by the token account")?,
+        }
+        if let Some(mode) = &self.mode {
+            write!(f, " in {:?} mode", mode)?;
+        }
+        write!(
+            f,
+            " because {}, error code {}",
+            self.reason.describe(),
+            self.error_code()
+        )
+    }
+}
+
+impl WalletEntry {
+    /// The entry's label without its zero padding, empty when it isn't valid
+    /// UTF-8.
+    pub fn label_str(&self) -> &str {
+        let len = self
+            .label
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |last| last + 1);
+        std::str::from_utf8(&self.label[..len]).unwrap_or_default()
+    }
+}
+
+/// Seeds of the `ListConfig` PDA in derivation order, without the bump: the
+/// prefix, the authority and the seed key.
+pub fn list_config_seeds(authority: &Pubkey, seed: &Pubkey) -> Vec<Vec<u8>> {
+    vec![
+        ListConfig::PREFIX.to_vec(),
+        authority.to_bytes().to_vec(),
+        seed.to_bytes().to_vec(),
+    ]
+}
+
+/// Seeds of the `WalletEntry` PDA in derivation order, without the bump: the
+/// prefix, the list's namespace, the list config and the wallet (the token
+/// account on lists keyed by token account).
+pub fn wallet_entry_seeds(
+    namespace: &[u8; 16],
+    list_config: &Pubkey,
+    wallet: &Pubkey,
+) -> Vec<Vec<u8>> {
+    vec![
+        WalletEntry::PREFIX.to_vec(),
+        namespace.to_vec(),
+        list_config.to_bytes().to_vec(),
+        wallet.to_bytes().to_vec(),
+    ]
+}
+
+/// Address and bump of the wallet entry a thaw of `token_account`, owned by
+/// `owner`, is checked against on `list`: the token account's entry on lists
+/// keyed by token account, the owner's otherwise.
+pub fn find_thaw_wallet_entry(
+    list: &Pubkey,
+    list_config: &ListConfig,
+    owner: &Pubkey,
+    token_account: &Pubkey,
+) -> (Pubkey, u8) {
+    let wallet = if list_config.key_by_token_account {
+        token_account
+    } else {
+        owner
+    };
+    WalletEntry::find_pda(&list_config.namespace, list, wallet)
+}
+
+/// Explains the first denial of a `DiagnoseThaw` run over the
+/// `(list, wallet_entry)` `pairs`, returning `None` if every verdict passes.
+///
+/// Verdicts are taken in the order `CanThaw` evaluates them: the token account
+/// first, then block lists, then every other list, each in pair order.
+/// `get_account_data` returns the data of an account, or `None` if it doesn't
+/// exist; it is only read to name the mode of the denying list.
+///
+/// `DiagnoseThaw` evaluates each list on its own, so the outcome of allow
+/// thresholds and of block lists yielding to allow lists isn't explained.
+pub fn explain_thaw_denial<F>(
+    pairs: &[(Pubkey, Pubkey)],
+    diagnosis: &DiagnoseThawReturnData,
+    get_account_data: F,
+) -> Option<DenialExplanation>
+where
+    F: Fn(&Pubkey) -> Option<Vec<u8>>,
+{
+    if let Some(error) = &diagnosis.token_account {
+        return Some(DenialExplanation {
+            list: None,
+            wallet_entry: None,
+            mode: None,
+            reason: DenialReason::TokenAccount,
+            error: error.clone(),
+        });
+    }
+
+    let lists = pairs
+        .iter()
+        .zip(&diagnosis.lists)
+        .map(|((list, wallet_entry), verdict)| {
+            let list_config = get_account_data(list)
+                .filter(|data| data.first() == Some(&LIST_CONFIG_DISCRIMINATOR))
+                .and_then(|data| ListConfig::from_bytes(&data).ok());
+            (list, wallet_entry, verdict, list_config)
+        });
+    let (block_lists, other_lists): (Vec<_>, Vec<_>) = lists.partition(|(_, _, _, list_config)| {
+        list_config
+            .as_ref()
+            .is_some_and(|list_config| list_config.mode == Mode::Block as u8)
+    });
+
+    block_lists.into_iter().chain(other_lists).find_map(
+        |(list, wallet_entry, verdict, list_config)| {
+            let error = verdict.as_ref()?;
+            Some(DenialExplanation {
+                list: Some(*list),
+                wallet_entry: Some(*wallet_entry),
+                mode: list_config
+                    .as_ref()
+                    .and_then(|list_config| Mode::from_u8(list_config.mode)),
+                reason: DenialReason::from_list_verdict(error, list_config.as_ref()),
+                error: error.clone(),
+            })
+        },
+    )
+}
+
+/// Return data written by `RemoveWallet`, so callers can reconcile the rent
+/// reclaimed from a closed wallet entry without parsing logs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RemoveWalletReturnData {
+    /// Lamports moved out of the closed wallet entry.
+    pub reclaimed_lamports: u64,
+    /// Account the reclaimed lamports were sent to, the list authority.
+    pub recipient: Pubkey,
+    /// Number of wallets left on the list after the removal.
+    pub wallets_count: u64,
+}
+
+impl RemoveWalletReturnData {
+    pub const LEN: usize = 8 + 32 + 8;
+
+    /// Decodes the return data, returning `None` if it has an unexpected length.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() != Self::LEN {
+            return None;
+        }
+
+        Some(Self {
+            reclaimed_lamports: u64::from_le_bytes(data[..8].try_into().unwrap()),
+            recipient: Pubkey::new_from_array(data[8..40].try_into().unwrap()),
+            wallets_count: u64::from_le_bytes(data[40..].try_into().unwrap()),
+        })
+    }
+}
+
+/// Return data written by `DiagnoseThaw`: the verdict of the token account
+/// checks and of every (list, wallet entry) pair, `None` when it passes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagnoseThawReturnData {
+    pub token_account: Option<ProgramError>,
+    /// One verdict per pair, in account order.
+    pub lists: Vec<Option<ProgramError>>,
+}
+
+impl DiagnoseThawReturnData {
+    /// Decodes the return data, returning `None` if it has an unexpected length.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 || !data.len().is_multiple_of(8) {
+            return None;
+        }
+
+        let mut verdicts = data.chunks_exact(8).map(|chunk| {
+            let code = u64::from_le_bytes(chunk.try_into().unwrap());
+            (code != 0).then(|| ProgramError::from(code))
+        });
+
+        Some(Self {
+            token_account: verdicts.next().unwrap(),
+            lists: verdicts.collect(),
+        })
+    }
+
+    /// Whether the thaw would be allowed, every verdict passing.
+    pub fn is_allowed(&self) -> bool {
+        self.token_account.is_none() && self.lists.iter().all(Option::is_none)
+    }
+}
+
+/// Status returned by `GetMembership`, whether the list allows the wallet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Membership {
+    Denied,
+    Allowed,
+}
+
+impl Membership {
+    /// Decodes the one byte return data, `None` when it isn't a status.
+    pub fn from_return_data(data: &[u8]) -> Option<Self> {
+        match data {
+            [0] => Some(Self::Denied),
+            [1] => Some(Self::Allowed),
+            _ => None,
+        }
+    }
+
+    /// Decodes the bitmask returned by `GetMembershipBatch` for `count`
+    /// owners, in pair order, `None` when its length doesn't match.
+    pub fn from_batch_return_data(data: &[u8], count: usize) -> Option<Vec<Self>> {
+        if data.len() != count.div_ceil(8) {
+            return None;
+        }
+
+        Some(
+            (0..count)
+                .map(|i| match (data[i / 8] >> (i % 8)) & 1 {
+                    0 => Self::Denied,
+                    _ => Self::Allowed,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Health flags returned by `AuditEntries`, one byte per wallet entry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EntryHealth(pub u8);
+
+impl EntryHealth {
+    /// The list config exists and is initialized.
+    pub const CONFIG_PRESENT: u8 = 1 << 0;
+    /// The entry is a wallet entry of the list, in the list's namespace when
+    /// the list config is present.
+    pub const BINDING_OK: u8 = 1 << 1;
+    /// The entry is bound to the list and the list config is present.
+    pub const NOT_ORPHANED: u8 = 1 << 2;
+
+    /// Decodes the return data into one health value per entry.
+    pub fn from_return_data(data: &[u8]) -> Vec<Self> {
+        data.iter().copied().map(Self).collect()
+    }
+
+    pub fn is_config_present(&self) -> bool {
+        self.0 & Self::CONFIG_PRESENT != 0
+    }
+
+    pub fn is_binding_ok(&self) -> bool {
+        self.0 & Self::BINDING_OK != 0
+    }
+
+    pub fn is_orphaned(&self) -> bool {
+        self.0 & Self::NOT_ORPHANED == 0
+    }
+
+    /// Whether every check passed.
+    pub fn is_healthy(&self) -> bool {
+        self.is_config_present() && self.is_binding_ok() && !self.is_orphaned()
+    }
+}
+
+impl AddWalletBatchBuilder {
+    /// Maximum number of wallets the program accepts in one `AddWalletBatch`,
+    /// larger batches fail with `BatchTooLarge`.
+    pub const MAX_BATCH: usize = 10;
+}
+
+impl PromoteToAllowAllEoasBuilder {
+    /// Maximum number of entries the program accepts in one
+    /// `PromoteToAllowAllEoas`, larger sets fail with `BatchTooLarge`.
+    pub const MAX_BATCH: usize = 20;
+}
+
+impl UpdateMerkleRootBuilder {
+    /// Instruction that only replaces the root while the list is still at
+    /// `root_version`, the version the new root was computed from; it fails
+    /// with `RootVersionMismatch` once the root has changed since.
+    pub fn instruction_expecting(&self, root_version: u64) -> solana_instruction::Instruction {
+        let mut ix = self.instruction();
+        ix.data.extend_from_slice(&root_version.to_le_bytes());
+        ix
+    }
+}
+
+impl GetMembershipBatchBuilder {
+    /// Maximum number of owners the program accepts in one
+    /// `GetMembershipBatch`, larger batches fail with `InvalidRemainingAccounts`.
+    ///
+    /// It is the number of distinct pairs fitting in the 128 accounts a
+    /// transaction can lock, next to the fee payer, the program and the list
+    /// config. Legacy transactions run into the 1232 byte packet limit well
+    /// before that, so batches this large need address lookup tables.
+    pub const MAX_OWNERS: usize = (128 - 3) / 2;
+}
+
+/// Policy parameters for `ConfigurePolicy`; only the `Some` ones are updated.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PolicyUpdate {
+    /// Enrollment window length and the enrollments allowed per window.
+    pub enroll_rate: Option<(u64, u32)>,
+    pub transfer_hook_check: Option<u8>,
+    pub appeal_period_secs: Option<u64>,
+    pub require_nonzero_balance: Option<bool>,
+    /// Start and end second of the day (UTC).
+    pub allowed_window: Option<(u32, u32)>,
+    pub require_authority_cosign: Option<bool>,
+    pub allow_threshold: Option<u8>,
+    pub any_list: Option<bool>,
+    pub meter: Option<bool>,
+    /// Most levels of a merkle proof, `0` for the program's maximum.
+    pub max_proof_depth: Option<u8>,
+    /// Whether an expired entry denies owners the mode would let through.
+    pub deny_expired_entries: Option<bool>,
+    /// Most entries the list holds, `0` for no cap.
+    pub max_wallets: Option<u32>,
+    /// Key that can add and remove wallets, the default key for none. Only
+    /// the list authority can set it.
+    pub manager: Option<Pubkey>,
+    /// Most compute units a merkle proof check may be estimated to take, `0`
+    /// for no ceiling.
+    pub max_cu_per_eval: Option<u32>,
+}
+
+impl PolicyUpdate {
+    pub const ENROLL_RATE: u16 = 1 << 0;
+    pub const TRANSFER_HOOK_CHECK: u16 = 1 << 1;
+    pub const APPEAL_PERIOD: u16 = 1 << 2;
+    pub const REQUIRE_NONZERO_BALANCE: u16 = 1 << 3;
+    pub const ALLOWED_WINDOW: u16 = 1 << 4;
+    pub const REQUIRE_AUTHORITY_COSIGN: u16 = 1 << 5;
+    pub const ALLOW_THRESHOLD: u16 = 1 << 6;
+    pub const ANY_LIST: u16 = 1 << 7;
+    pub const METER: u16 = 1 << 8;
+    pub const MAX_PROOF_DEPTH: u16 = 1 << 9;
+    pub const DENY_EXPIRED_ENTRIES: u16 = 1 << 10;
+    pub const MAX_WALLETS: u16 = 1 << 11;
+    pub const MANAGER: u16 = 1 << 12;
+    pub const MAX_CU_PER_EVAL: u16 = 1 << 13;
+
+    /// Bitmask selecting the parameters that are set.
+    pub fn mask(&self) -> u16 {
+        let mut mask = 0;
+        if self.enroll_rate.is_some() {
+            mask |= Self::ENROLL_RATE;
+        }
+        if self.transfer_hook_check.is_some() {
+            mask |= Self::TRANSFER_HOOK_CHECK;
+        }
+        if self.appeal_period_secs.is_some() {
+            mask |= Self::APPEAL_PERIOD;
+        }
+        if self.require_nonzero_balance.is_some() {
+            mask |= Self::REQUIRE_NONZERO_BALANCE;
+        }
+        if self.allowed_window.is_some() {
+            mask |= Self::ALLOWED_WINDOW;
+        }
+        if self.require_authority_cosign.is_some() {
+            mask |= Self::REQUIRE_AUTHORITY_COSIGN;
+        }
+        if self.allow_threshold.is_some() {
+            mask |= Self::ALLOW_THRESHOLD;
+        }
+        if self.any_list.is_some() {
+            mask |= Self::ANY_LIST;
+        }
+        if self.meter.is_some() {
+            mask |= Self::METER;
+        }
+        if self.max_proof_depth.is_some() {
+            mask |= Self::MAX_PROOF_DEPTH;
+        }
+        if self.deny_expired_entries.is_some() {
+            mask |= Self::DENY_EXPIRED_ENTRIES;
+        }
+        if self.max_wallets.is_some() {
+            mask |= Self::MAX_WALLETS;
+        }
+        if self.manager.is_some() {
+            mask |= Self::MANAGER;
+        }
+        if self.max_cu_per_eval.is_some() {
+            mask |= Self::MAX_CU_PER_EVAL;
+        }
+        mask
+    }
+
+    /// Builder with the mask and every value filled in, only the authority
+    /// and list config accounts are left to set.
+    pub fn builder(&self) -> ConfigurePolicyBuilder {
+        let (enroll_window_secs, max_enrolls_per_window) = self.enroll_rate.unwrap_or_default();
+        let (allowed_window_start, allowed_window_end) = self.allowed_window.unwrap_or_default();
+
+        let mut builder = ConfigurePolicyBuilder::new();
+        builder
+            .mask(self.mask())
+            .enroll_window_secs(enroll_window_secs)
+            .max_enrolls_per_window(max_enrolls_per_window)
+            .transfer_hook_check(self.transfer_hook_check.unwrap_or_default())
+            .appeal_period_secs(self.appeal_period_secs.unwrap_or_default())
+            .require_nonzero_balance(self.require_nonzero_balance.unwrap_or_default())
+            .allowed_window_start(allowed_window_start)
+            .allowed_window_end(allowed_window_end)
+            .require_authority_cosign(self.require_authority_cosign.unwrap_or_default())
+            .allow_threshold(self.allow_threshold.unwrap_or_default())
+            .any_list(self.any_list.unwrap_or_default())
+            .meter(self.meter.unwrap_or_default())
+            .max_proof_depth(self.max_proof_depth.unwrap_or_default())
+            .deny_expired_entries(self.deny_expired_entries.unwrap_or_default())
+            .max_wallets(self.max_wallets.unwrap_or_default())
+            .manager(self.manager.unwrap_or_default())
+            .max_cu_per_eval(self.max_cu_per_eval.unwrap_or_default());
+        builder
+    }
+}
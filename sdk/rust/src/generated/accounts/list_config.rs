@@ -25,12 +25,65 @@ pub struct ListConfig {
     pub seed: Pubkey,
     pub mode: u8,
     pub wallets_count: u64,
+    pub namespace: [u8; 16],
+    pub enroll_window_secs: u64,
+    pub max_enrolls_per_window: u32,
+    pub window_start: i64,
+    pub window_enrolls: u32,
+    pub key_by_token_account: bool,
+    pub warmup_until: i64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub token_program: Pubkey,
+    pub transfer_hook_check: u8,
+    pub appeal_period_secs: u64,
+    pub require_nonzero_balance: bool,
+    pub allowed_window_start: u32,
+    pub allowed_window_end: u32,
+    pub require_authority_cosign: bool,
+    pub allow_threshold: u8,
+    pub allow_freeze_authority_delegate: bool,
+    pub strict_block_mode: bool,
+    pub allow_precedence: bool,
+    pub sunset_at: i64,
+    pub max_wallets: u32,
+    pub allow_multisig_owner: bool,
+    pub owner_extensions: u8,
+    pub merkle_root: [u8; 32],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_authority: Pubkey,
+    pub updated_at: i64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub last_actor: Pubkey,
+    pub paused: bool,
+    pub any_list: bool,
+    pub meter: bool,
+    pub root_version: u64,
+    pub max_proof_depth: u8,
+    pub deny_expired_entries: bool,
+    pub eoa_grace_until: i64,
+    pub frozen: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub manager: Pubkey,
+    pub max_cu_per_eval: u32,
+    pub reserved: [u8; 28],
 }
 
 pub const LIST_CONFIG_DISCRIMINATOR: u8 = 1;
 
 impl ListConfig {
-    pub const LEN: usize = 74;
+    pub const LEN: usize = 382;
 
     /// Prefix values used to generate a PDA for this account.
     ///
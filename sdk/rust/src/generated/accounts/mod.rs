@@ -5,8 +5,20 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#audit_log;
+pub(crate) mod r#authority_set;
 pub(crate) mod r#list_config;
+pub(crate) mod r#list_registry;
+pub(crate) mod r#merkle_proof;
+pub(crate) mod r#policy_archive;
+pub(crate) mod r#review_queue;
 pub(crate) mod r#wallet_entry;
 
+pub use self::r#audit_log::*;
+pub use self::r#authority_set::*;
 pub use self::r#list_config::*;
+pub use self::r#list_registry::*;
+pub use self::r#merkle_proof::*;
+pub use self::r#policy_archive::*;
+pub use self::r#review_queue::*;
 pub use self::r#wallet_entry::*;
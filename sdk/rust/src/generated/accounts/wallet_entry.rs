@@ -23,23 +23,35 @@ pub struct WalletEntry {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub list_config: Pubkey,
+    pub namespace: [u8; 16],
+    pub appeal_deadline: i64,
+    pub expires_at: i64,
+    pub label: [u8; 32],
+    pub created_at: i64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub added_by: Pubkey,
 }
 
 pub const WALLET_ENTRY_DISCRIMINATOR: u8 = 2;
 
 impl WalletEntry {
-    pub const LEN: usize = 65;
+    pub const LEN: usize = 169;
 
     /// Prefix values used to generate a PDA for this account.
     ///
     /// Values are positional and appear in the following order:
     ///
     ///   0. `WalletEntry::PREFIX`
-    ///   1. list_config (`Pubkey`)
-    ///   2. wallet (`Pubkey`)
+    ///   1. namespace (`[u8; 16]`)
+    ///   2. list_config (`Pubkey`)
+    ///   3. wallet (`Pubkey`)
     pub const PREFIX: &'static [u8] = "wallet_entry".as_bytes();
 
     pub fn create_pda(
+        namespace: [u8; 16],
         list_config: Pubkey,
         wallet: Pubkey,
         bump: u8,
@@ -47,6 +59,7 @@ impl WalletEntry {
         solana_pubkey::Pubkey::create_program_address(
             &[
                 "wallet_entry".as_bytes(),
+                namespace.as_ref(),
                 list_config.as_ref(),
                 wallet.as_ref(),
                 &[bump],
@@ -55,10 +68,15 @@ impl WalletEntry {
         )
     }
 
-    pub fn find_pda(list_config: &Pubkey, wallet: &Pubkey) -> (solana_pubkey::Pubkey, u8) {
+    pub fn find_pda(
+        namespace: &[u8; 16],
+        list_config: &Pubkey,
+        wallet: &Pubkey,
+    ) -> (solana_pubkey::Pubkey, u8) {
         solana_pubkey::Pubkey::find_program_address(
             &[
                 "wallet_entry".as_bytes(),
+                namespace.as_ref(),
                 list_config.as_ref(),
                 wallet.as_ref(),
             ],
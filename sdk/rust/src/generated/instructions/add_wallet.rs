@@ -27,13 +27,14 @@ pub struct AddWallet {
 }
 
 impl AddWallet {
-    pub fn instruction(&self) -> solana_instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(&self, args: AddWalletInstructionArgs) -> solana_instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: AddWalletInstructionArgs,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
         let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
@@ -59,7 +60,9 @@ impl AddWallet {
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = AddWalletInstructionData::new().try_to_vec().unwrap();
+        let mut data = AddWalletInstructionData::new().try_to_vec().unwrap();
+        let mut args = args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         solana_instruction::Instruction {
             program_id: crate::TOKEN_ACL_GATE_PROGRAM_ID,
@@ -73,11 +76,15 @@ impl AddWallet {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddWalletInstructionData {
     discriminator: u8,
+    version: u8,
 }
 
 impl AddWalletInstructionData {
     pub fn new() -> Self {
-        Self { discriminator: 2 }
+        Self {
+            discriminator: 2,
+            version: 3,
+        }
     }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -91,6 +98,20 @@ impl Default for AddWalletInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddWalletInstructionArgs {
+    pub expires_at: i64,
+    pub label: [u8; 32],
+    pub idempotent: bool,
+}
+
+impl AddWalletInstructionArgs {
+    pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
+        borsh::to_vec(self)
+    }
+}
+
 /// Instruction builder for `AddWallet`.
 ///
 /// ### Accounts:
@@ -109,6 +130,9 @@ pub struct AddWalletBuilder {
     wallet: Option<solana_pubkey::Pubkey>,
     wallet_entry: Option<solana_pubkey::Pubkey>,
     system_program: Option<solana_pubkey::Pubkey>,
+    expires_at: Option<i64>,
+    label: Option<[u8; 32]>,
+    idempotent: Option<bool>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -147,6 +171,24 @@ impl AddWalletBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// Timestamp the entry stops counting from; `0` never expires.
+    #[inline(always)]
+    pub fn expires_at(&mut self, expires_at: i64) -> &mut Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+    /// Why the wallet is added, UTF-8 padded with zeros.
+    #[inline(always)]
+    pub fn label(&mut self, label: [u8; 32]) -> &mut Self {
+        self.label = Some(label);
+        self
+    }
+    /// Succeeds without changes when the wallet is already on the list.
+    #[inline(always)]
+    pub fn idempotent(&mut self, idempotent: bool) -> &mut Self {
+        self.idempotent = Some(idempotent);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -174,8 +216,13 @@ impl AddWalletBuilder {
                 .system_program
                 .unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
         };
+        let args = AddWalletInstructionArgs {
+            expires_at: self.expires_at.clone().expect("expires_at is not set"),
+            label: self.label.clone().expect("label is not set"),
+            idempotent: self.idempotent.clone().expect("idempotent is not set"),
+        };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -210,12 +257,15 @@ pub struct AddWalletCpi<'a, 'b> {
     pub wallet_entry: &'b solana_account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: AddWalletInstructionArgs,
 }
 
 impl<'a, 'b> AddWalletCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_account_info::AccountInfo<'a>,
         accounts: AddWalletCpiAccounts<'a, 'b>,
+        args: AddWalletInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -225,6 +275,7 @@ impl<'a, 'b> AddWalletCpi<'a, 'b> {
             wallet: accounts.wallet,
             wallet_entry: accounts.wallet_entry,
             system_program: accounts.system_program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -279,7 +330,9 @@ impl<'a, 'b> AddWalletCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = AddWalletInstructionData::new().try_to_vec().unwrap();
+        let mut data = AddWalletInstructionData::new().try_to_vec().unwrap();
+        let mut args = self.__args.try_to_vec().unwrap();
+        data.append(&mut args);
 
         let instruction = solana_instruction::Instruction {
             program_id: crate::TOKEN_ACL_GATE_PROGRAM_ID,
@@ -331,6 +384,9 @@ impl<'a, 'b> AddWalletCpiBuilder<'a, 'b> {
             wallet: None,
             wallet_entry: None,
             system_program: None,
+            expires_at: None,
+            label: None,
+            idempotent: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -374,6 +430,24 @@ impl<'a, 'b> AddWalletCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// Timestamp the entry stops counting from; `0` never expires.
+    #[inline(always)]
+    pub fn expires_at(&mut self, expires_at: i64) -> &mut Self {
+        self.instruction.expires_at = Some(expires_at);
+        self
+    }
+    /// Why the wallet is added, UTF-8 padded with zeros.
+    #[inline(always)]
+    pub fn label(&mut self, label: [u8; 32]) -> &mut Self {
+        self.instruction.label = Some(label);
+        self
+    }
+    /// Succeeds without changes when the wallet is already on the list.
+    #[inline(always)]
+    pub fn idempotent(&mut self, idempotent: bool) -> &mut Self {
+        self.instruction.idempotent = Some(idempotent);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -408,6 +482,19 @@ impl<'a, 'b> AddWalletCpiBuilder<'a, 'b> {
     #[allow(clippy::clone_on_copy)]
     #[allow(clippy::vec_init_then_push)]
     pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
+        let args = AddWalletInstructionArgs {
+            expires_at: self
+                .instruction
+                .expires_at
+                .clone()
+                .expect("expires_at is not set"),
+            label: self.instruction.label.clone().expect("label is not set"),
+            idempotent: self
+                .instruction
+                .idempotent
+                .clone()
+                .expect("idempotent is not set"),
+        };
         let instruction = AddWalletCpi {
             __program: self.instruction.__program,
 
@@ -431,6 +518,7 @@ impl<'a, 'b> AddWalletCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -448,6 +536,9 @@ struct AddWalletCpiBuilderInstruction<'a, 'b> {
     wallet: Option<&'b solana_account_info::AccountInfo<'a>>,
     wallet_entry: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    expires_at: Option<i64>,
+    label: Option<[u8; 32]>,
+    idempotent: Option<bool>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
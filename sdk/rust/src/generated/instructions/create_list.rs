@@ -66,11 +66,15 @@ impl CreateList {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CreateListInstructionData {
     discriminator: u8,
+    version: u8,
 }
 
 impl CreateListInstructionData {
     pub fn new() -> Self {
-        Self { discriminator: 1 }
+        Self {
+            discriminator: 1,
+            version: 1,
+        }
     }
 
     pub(crate) fn try_to_vec(&self) -> Result<Vec<u8>, std::io::Error> {
@@ -89,6 +93,26 @@ impl Default for CreateListInstructionData {
 pub struct CreateListInstructionArgs {
     pub mode: Mode,
     pub seed: Pubkey,
+    pub namespace: [u8; 16],
+    pub enroll_window_secs: u64,
+    pub max_enrolls_per_window: u32,
+    pub key_by_token_account: bool,
+    pub warmup_secs: u64,
+    pub token_program: Pubkey,
+    pub transfer_hook_check: u8,
+    pub appeal_period_secs: u64,
+    pub require_nonzero_balance: bool,
+    pub allowed_window_start: u32,
+    pub allowed_window_end: u32,
+    pub require_authority_cosign: bool,
+    pub allow_threshold: u8,
+    pub allow_freeze_authority_delegate: bool,
+    pub strict_block_mode: bool,
+    pub allow_precedence: bool,
+    pub sunset_at: i64,
+    pub max_wallets: u32,
+    pub allow_multisig_owner: bool,
+    pub owner_extensions: u8,
 }
 
 impl CreateListInstructionArgs {
@@ -113,6 +137,26 @@ pub struct CreateListBuilder {
     system_program: Option<solana_pubkey::Pubkey>,
     mode: Option<Mode>,
     seed: Option<Pubkey>,
+    namespace: Option<[u8; 16]>,
+    enroll_window_secs: Option<u64>,
+    max_enrolls_per_window: Option<u32>,
+    key_by_token_account: Option<bool>,
+    warmup_secs: Option<u64>,
+    token_program: Option<Pubkey>,
+    transfer_hook_check: Option<u8>,
+    appeal_period_secs: Option<u64>,
+    require_nonzero_balance: Option<bool>,
+    allowed_window_start: Option<u32>,
+    allowed_window_end: Option<u32>,
+    require_authority_cosign: Option<bool>,
+    allow_threshold: Option<u8>,
+    allow_freeze_authority_delegate: Option<bool>,
+    strict_block_mode: Option<bool>,
+    allow_precedence: Option<bool>,
+    sunset_at: Option<i64>,
+    max_wallets: Option<u32>,
+    allow_multisig_owner: Option<bool>,
+    owner_extensions: Option<u8>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -151,6 +195,109 @@ impl CreateListBuilder {
         self.seed = Some(seed);
         self
     }
+    #[inline(always)]
+    pub fn namespace(&mut self, namespace: [u8; 16]) -> &mut Self {
+        self.namespace = Some(namespace);
+        self
+    }
+    #[inline(always)]
+    pub fn enroll_window_secs(&mut self, enroll_window_secs: u64) -> &mut Self {
+        self.enroll_window_secs = Some(enroll_window_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn max_enrolls_per_window(&mut self, max_enrolls_per_window: u32) -> &mut Self {
+        self.max_enrolls_per_window = Some(max_enrolls_per_window);
+        self
+    }
+    #[inline(always)]
+    pub fn key_by_token_account(&mut self, key_by_token_account: bool) -> &mut Self {
+        self.key_by_token_account = Some(key_by_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn warmup_secs(&mut self, warmup_secs: u64) -> &mut Self {
+        self.warmup_secs = Some(warmup_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_hook_check(&mut self, transfer_hook_check: u8) -> &mut Self {
+        self.transfer_hook_check = Some(transfer_hook_check);
+        self
+    }
+    #[inline(always)]
+    pub fn appeal_period_secs(&mut self, appeal_period_secs: u64) -> &mut Self {
+        self.appeal_period_secs = Some(appeal_period_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn require_nonzero_balance(&mut self, require_nonzero_balance: bool) -> &mut Self {
+        self.require_nonzero_balance = Some(require_nonzero_balance);
+        self
+    }
+    #[inline(always)]
+    pub fn allowed_window_start(&mut self, allowed_window_start: u32) -> &mut Self {
+        self.allowed_window_start = Some(allowed_window_start);
+        self
+    }
+    #[inline(always)]
+    pub fn allowed_window_end(&mut self, allowed_window_end: u32) -> &mut Self {
+        self.allowed_window_end = Some(allowed_window_end);
+        self
+    }
+    #[inline(always)]
+    pub fn require_authority_cosign(&mut self, require_authority_cosign: bool) -> &mut Self {
+        self.require_authority_cosign = Some(require_authority_cosign);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_threshold(&mut self, allow_threshold: u8) -> &mut Self {
+        self.allow_threshold = Some(allow_threshold);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_freeze_authority_delegate(
+        &mut self,
+        allow_freeze_authority_delegate: bool,
+    ) -> &mut Self {
+        self.allow_freeze_authority_delegate = Some(allow_freeze_authority_delegate);
+        self
+    }
+    #[inline(always)]
+    pub fn strict_block_mode(&mut self, strict_block_mode: bool) -> &mut Self {
+        self.strict_block_mode = Some(strict_block_mode);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_precedence(&mut self, allow_precedence: bool) -> &mut Self {
+        self.allow_precedence = Some(allow_precedence);
+        self
+    }
+    #[inline(always)]
+    pub fn sunset_at(&mut self, sunset_at: i64) -> &mut Self {
+        self.sunset_at = Some(sunset_at);
+        self
+    }
+    #[inline(always)]
+    pub fn max_wallets(&mut self, max_wallets: u32) -> &mut Self {
+        self.max_wallets = Some(max_wallets);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_multisig_owner(&mut self, allow_multisig_owner: bool) -> &mut Self {
+        self.allow_multisig_owner = Some(allow_multisig_owner);
+        self
+    }
+    #[inline(always)]
+    pub fn owner_extensions(&mut self, owner_extensions: u8) -> &mut Self {
+        self.owner_extensions = Some(owner_extensions);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -179,6 +326,74 @@ impl CreateListBuilder {
         let args = CreateListInstructionArgs {
             mode: self.mode.clone().expect("mode is not set"),
             seed: self.seed.clone().expect("seed is not set"),
+            namespace: self.namespace.clone().expect("namespace is not set"),
+            enroll_window_secs: self
+                .enroll_window_secs
+                .clone()
+                .expect("enroll_window_secs is not set"),
+            max_enrolls_per_window: self
+                .max_enrolls_per_window
+                .clone()
+                .expect("max_enrolls_per_window is not set"),
+            key_by_token_account: self
+                .key_by_token_account
+                .clone()
+                .expect("key_by_token_account is not set"),
+            warmup_secs: self.warmup_secs.clone().expect("warmup_secs is not set"),
+            token_program: self
+                .token_program
+                .clone()
+                .expect("token_program is not set"),
+            transfer_hook_check: self
+                .transfer_hook_check
+                .clone()
+                .expect("transfer_hook_check is not set"),
+            appeal_period_secs: self
+                .appeal_period_secs
+                .clone()
+                .expect("appeal_period_secs is not set"),
+            require_nonzero_balance: self
+                .require_nonzero_balance
+                .clone()
+                .expect("require_nonzero_balance is not set"),
+            allowed_window_start: self
+                .allowed_window_start
+                .clone()
+                .expect("allowed_window_start is not set"),
+            allowed_window_end: self
+                .allowed_window_end
+                .clone()
+                .expect("allowed_window_end is not set"),
+            require_authority_cosign: self
+                .require_authority_cosign
+                .clone()
+                .expect("require_authority_cosign is not set"),
+            allow_threshold: self
+                .allow_threshold
+                .clone()
+                .expect("allow_threshold is not set"),
+            allow_freeze_authority_delegate: self
+                .allow_freeze_authority_delegate
+                .clone()
+                .expect("allow_freeze_authority_delegate is not set"),
+            strict_block_mode: self
+                .strict_block_mode
+                .clone()
+                .expect("strict_block_mode is not set"),
+            allow_precedence: self
+                .allow_precedence
+                .clone()
+                .expect("allow_precedence is not set"),
+            sunset_at: self.sunset_at.clone().expect("sunset_at is not set"),
+            max_wallets: self.max_wallets.clone().expect("max_wallets is not set"),
+            allow_multisig_owner: self
+                .allow_multisig_owner
+                .clone()
+                .expect("allow_multisig_owner is not set"),
+            owner_extensions: self
+                .owner_extensions
+                .clone()
+                .expect("owner_extensions is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -321,6 +536,26 @@ impl<'a, 'b> CreateListCpiBuilder<'a, 'b> {
             system_program: None,
             mode: None,
             seed: None,
+            namespace: None,
+            enroll_window_secs: None,
+            max_enrolls_per_window: None,
+            key_by_token_account: None,
+            warmup_secs: None,
+            token_program: None,
+            transfer_hook_check: None,
+            appeal_period_secs: None,
+            require_nonzero_balance: None,
+            allowed_window_start: None,
+            allowed_window_end: None,
+            require_authority_cosign: None,
+            allow_threshold: None,
+            allow_freeze_authority_delegate: None,
+            strict_block_mode: None,
+            allow_precedence: None,
+            sunset_at: None,
+            max_wallets: None,
+            allow_multisig_owner: None,
+            owner_extensions: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -361,6 +596,109 @@ impl<'a, 'b> CreateListCpiBuilder<'a, 'b> {
         self.instruction.seed = Some(seed);
         self
     }
+    #[inline(always)]
+    pub fn namespace(&mut self, namespace: [u8; 16]) -> &mut Self {
+        self.instruction.namespace = Some(namespace);
+        self
+    }
+    #[inline(always)]
+    pub fn enroll_window_secs(&mut self, enroll_window_secs: u64) -> &mut Self {
+        self.instruction.enroll_window_secs = Some(enroll_window_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn max_enrolls_per_window(&mut self, max_enrolls_per_window: u32) -> &mut Self {
+        self.instruction.max_enrolls_per_window = Some(max_enrolls_per_window);
+        self
+    }
+    #[inline(always)]
+    pub fn key_by_token_account(&mut self, key_by_token_account: bool) -> &mut Self {
+        self.instruction.key_by_token_account = Some(key_by_token_account);
+        self
+    }
+    #[inline(always)]
+    pub fn warmup_secs(&mut self, warmup_secs: u64) -> &mut Self {
+        self.instruction.warmup_secs = Some(warmup_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: Pubkey) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_hook_check(&mut self, transfer_hook_check: u8) -> &mut Self {
+        self.instruction.transfer_hook_check = Some(transfer_hook_check);
+        self
+    }
+    #[inline(always)]
+    pub fn appeal_period_secs(&mut self, appeal_period_secs: u64) -> &mut Self {
+        self.instruction.appeal_period_secs = Some(appeal_period_secs);
+        self
+    }
+    #[inline(always)]
+    pub fn require_nonzero_balance(&mut self, require_nonzero_balance: bool) -> &mut Self {
+        self.instruction.require_nonzero_balance = Some(require_nonzero_balance);
+        self
+    }
+    #[inline(always)]
+    pub fn allowed_window_start(&mut self, allowed_window_start: u32) -> &mut Self {
+        self.instruction.allowed_window_start = Some(allowed_window_start);
+        self
+    }
+    #[inline(always)]
+    pub fn allowed_window_end(&mut self, allowed_window_end: u32) -> &mut Self {
+        self.instruction.allowed_window_end = Some(allowed_window_end);
+        self
+    }
+    #[inline(always)]
+    pub fn require_authority_cosign(&mut self, require_authority_cosign: bool) -> &mut Self {
+        self.instruction.require_authority_cosign = Some(require_authority_cosign);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_threshold(&mut self, allow_threshold: u8) -> &mut Self {
+        self.instruction.allow_threshold = Some(allow_threshold);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_freeze_authority_delegate(
+        &mut self,
+        allow_freeze_authority_delegate: bool,
+    ) -> &mut Self {
+        self.instruction.allow_freeze_authority_delegate = Some(allow_freeze_authority_delegate);
+        self
+    }
+    #[inline(always)]
+    pub fn strict_block_mode(&mut self, strict_block_mode: bool) -> &mut Self {
+        self.instruction.strict_block_mode = Some(strict_block_mode);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_precedence(&mut self, allow_precedence: bool) -> &mut Self {
+        self.instruction.allow_precedence = Some(allow_precedence);
+        self
+    }
+    #[inline(always)]
+    pub fn sunset_at(&mut self, sunset_at: i64) -> &mut Self {
+        self.instruction.sunset_at = Some(sunset_at);
+        self
+    }
+    #[inline(always)]
+    pub fn max_wallets(&mut self, max_wallets: u32) -> &mut Self {
+        self.instruction.max_wallets = Some(max_wallets);
+        self
+    }
+    #[inline(always)]
+    pub fn allow_multisig_owner(&mut self, allow_multisig_owner: bool) -> &mut Self {
+        self.instruction.allow_multisig_owner = Some(allow_multisig_owner);
+        self
+    }
+    #[inline(always)]
+    pub fn owner_extensions(&mut self, owner_extensions: u8) -> &mut Self {
+        self.instruction.owner_extensions = Some(owner_extensions);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -398,6 +736,106 @@ impl<'a, 'b> CreateListCpiBuilder<'a, 'b> {
         let args = CreateListInstructionArgs {
             mode: self.instruction.mode.clone().expect("mode is not set"),
             seed: self.instruction.seed.clone().expect("seed is not set"),
+            namespace: self
+                .instruction
+                .namespace
+                .clone()
+                .expect("namespace is not set"),
+            enroll_window_secs: self
+                .instruction
+                .enroll_window_secs
+                .clone()
+                .expect("enroll_window_secs is not set"),
+            max_enrolls_per_window: self
+                .instruction
+                .max_enrolls_per_window
+                .clone()
+                .expect("max_enrolls_per_window is not set"),
+            key_by_token_account: self
+                .instruction
+                .key_by_token_account
+                .clone()
+                .expect("key_by_token_account is not set"),
+            warmup_secs: self
+                .instruction
+                .warmup_secs
+                .clone()
+                .expect("warmup_secs is not set"),
+            token_program: self
+                .instruction
+                .token_program
+                .clone()
+                .expect("token_program is not set"),
+            transfer_hook_check: self
+                .instruction
+                .transfer_hook_check
+                .clone()
+                .expect("transfer_hook_check is not set"),
+            appeal_period_secs: self
+                .instruction
+                .appeal_period_secs
+                .clone()
+                .expect("appeal_period_secs is not set"),
+            require_nonzero_balance: self
+                .instruction
+                .require_nonzero_balance
+                .clone()
+                .expect("require_nonzero_balance is not set"),
+            allowed_window_start: self
+                .instruction
+                .allowed_window_start
+                .clone()
+                .expect("allowed_window_start is not set"),
+            allowed_window_end: self
+                .instruction
+                .allowed_window_end
+                .clone()
+                .expect("allowed_window_end is not set"),
+            require_authority_cosign: self
+                .instruction
+                .require_authority_cosign
+                .clone()
+                .expect("require_authority_cosign is not set"),
+            allow_threshold: self
+                .instruction
+                .allow_threshold
+                .clone()
+                .expect("allow_threshold is not set"),
+            allow_freeze_authority_delegate: self
+                .instruction
+                .allow_freeze_authority_delegate
+                .clone()
+                .expect("allow_freeze_authority_delegate is not set"),
+            strict_block_mode: self
+                .instruction
+                .strict_block_mode
+                .clone()
+                .expect("strict_block_mode is not set"),
+            allow_precedence: self
+                .instruction
+                .allow_precedence
+                .clone()
+                .expect("allow_precedence is not set"),
+            sunset_at: self
+                .instruction
+                .sunset_at
+                .clone()
+                .expect("sunset_at is not set"),
+            max_wallets: self
+                .instruction
+                .max_wallets
+                .clone()
+                .expect("max_wallets is not set"),
+            allow_multisig_owner: self
+                .instruction
+                .allow_multisig_owner
+                .clone()
+                .expect("allow_multisig_owner is not set"),
+            owner_extensions: self
+                .instruction
+                .owner_extensions
+                .clone()
+                .expect("owner_extensions is not set"),
         };
         let instruction = CreateListCpi {
             __program: self.instruction.__program,
@@ -433,6 +871,26 @@ struct CreateListCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
     mode: Option<Mode>,
     seed: Option<Pubkey>,
+    namespace: Option<[u8; 16]>,
+    enroll_window_secs: Option<u64>,
+    max_enrolls_per_window: Option<u32>,
+    key_by_token_account: Option<bool>,
+    warmup_secs: Option<u64>,
+    token_program: Option<Pubkey>,
+    transfer_hook_check: Option<u8>,
+    appeal_period_secs: Option<u64>,
+    require_nonzero_balance: Option<bool>,
+    allowed_window_start: Option<u32>,
+    allowed_window_end: Option<u32>,
+    require_authority_cosign: Option<bool>,
+    allow_threshold: Option<u8>,
+    allow_freeze_authority_delegate: Option<bool>,
+    strict_block_mode: Option<bool>,
+    allow_precedence: Option<bool>,
+    sunset_at: Option<i64>,
+    max_wallets: Option<u32>,
+    allow_multisig_owner: Option<bool>,
+    owner_extensions: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
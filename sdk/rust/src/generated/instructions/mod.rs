@@ -5,14 +5,68 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#accept_authority_transfer;
 pub(crate) mod r#add_wallet;
+pub(crate) mod r#add_wallet_batch;
+pub(crate) mod r#archive_policy;
+pub(crate) mod r#audit_entries;
+pub(crate) mod r#close_merkle_proof;
+pub(crate) mod r#compact_authority_set;
+pub(crate) mod r#configure_policy;
+pub(crate) mod r#create_audit_log;
 pub(crate) mod r#create_list;
 pub(crate) mod r#delete_list;
+pub(crate) mod r#diagnose_thaw;
+pub(crate) mod r#drain_queue;
+pub(crate) mod r#enqueue_report;
+pub(crate) mod r#get_membership;
+pub(crate) mod r#get_membership_batch;
+pub(crate) mod r#migrate_list_config;
+pub(crate) mod r#migrate_wallet_entry;
+pub(crate) mod r#promote_to_allow_all_eoas;
+pub(crate) mod r#propose_authority_transfer;
+pub(crate) mod r#reaffirm_block;
 pub(crate) mod r#remove_wallet;
+pub(crate) mod r#set_delegate;
+pub(crate) mod r#set_frozen;
+pub(crate) mod r#set_manager;
+pub(crate) mod r#set_paused;
 pub(crate) mod r#setup_extra_metas;
+pub(crate) mod r#setup_freeze_extra_metas;
+pub(crate) mod r#submit_merkle_proof;
+pub(crate) mod r#update_merkle_root;
+pub(crate) mod r#update_mode;
+pub(crate) mod r#verify_merkle_membership;
 
+pub use self::r#accept_authority_transfer::*;
 pub use self::r#add_wallet::*;
+pub use self::r#add_wallet_batch::*;
+pub use self::r#archive_policy::*;
+pub use self::r#audit_entries::*;
+pub use self::r#close_merkle_proof::*;
+pub use self::r#compact_authority_set::*;
+pub use self::r#configure_policy::*;
+pub use self::r#create_audit_log::*;
 pub use self::r#create_list::*;
 pub use self::r#delete_list::*;
+pub use self::r#diagnose_thaw::*;
+pub use self::r#drain_queue::*;
+pub use self::r#enqueue_report::*;
+pub use self::r#get_membership::*;
+pub use self::r#get_membership_batch::*;
+pub use self::r#migrate_list_config::*;
+pub use self::r#migrate_wallet_entry::*;
+pub use self::r#promote_to_allow_all_eoas::*;
+pub use self::r#propose_authority_transfer::*;
+pub use self::r#reaffirm_block::*;
 pub use self::r#remove_wallet::*;
+pub use self::r#set_delegate::*;
+pub use self::r#set_frozen::*;
+pub use self::r#set_manager::*;
+pub use self::r#set_paused::*;
 pub use self::r#setup_extra_metas::*;
+pub use self::r#setup_freeze_extra_metas::*;
+pub use self::r#submit_merkle_proof::*;
+pub use self::r#update_merkle_root::*;
+pub use self::r#update_mode::*;
+pub use self::r#verify_merkle_membership::*;
@@ -0,0 +1,199 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{ListConfig, WalletEntry},
+    instructions::AddWalletBatchBuilder,
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+fn wallets_count(context: &TestContext, list_config: &Pubkey) -> u64 {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap().wallets_count
+}
+
+fn send_batch(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallets: &[Pubkey],
+    wallet_entries: &[Pubkey],
+) -> litesvm::types::TransactionResult {
+    let remaining_accounts = wallet_entries
+        .iter()
+        .map(|wallet_entry| AccountMeta::new(*wallet_entry, false))
+        .collect::<Vec<_>>();
+
+    let ix = token_acl_gate_client::instructions::AddWalletBatchBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .wallets(wallets.to_vec())
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+#[tokio::test]
+async fn adds_every_wallet() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let wallets = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let (res, wallet_entries) = context.try_add_wallets_to_list(&list_config, &wallets);
+    assert!(res.is_ok());
+
+    for (wallet, wallet_entry) in wallets.iter().zip(&wallet_entries) {
+        let account = context.vm.get_account(wallet_entry).unwrap();
+        let entry = WalletEntry::from_bytes(&account.data).unwrap();
+        assert_eq!(entry.wallet_address, *wallet);
+        assert_eq!(entry.list_config, list_config);
+    }
+    assert_eq!(wallets_count(&context, &list_config), 3);
+}
+
+#[tokio::test]
+async fn reverts_batch_with_existing_entry() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let existing = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list_config, &existing);
+
+    let new = Pubkey::new_unique();
+    let (res, wallet_entries) = context.try_add_wallets_to_list(&list_config, &[new, existing]);
+    assert!(res.is_err());
+
+    // the entry created before the failing one is rolled back
+    assert!(context.vm.get_account(&wallet_entries[0]).is_none());
+    assert_eq!(wallets_count(&context, &list_config), 1);
+}
+
+#[tokio::test]
+async fn rejects_wrong_entry_address() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let wallets = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let namespace = context.list_namespace(&list_config);
+    let mut wallet_entries = wallets
+        .iter()
+        .map(|wallet| WalletEntry::find_pda(&namespace, &list_config, wallet).0)
+        .collect::<Vec<_>>();
+    wallet_entries.reverse();
+
+    let res = send_batch(&mut context, &list_config, &wallets, &wallet_entries);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+    assert_eq!(wallets_count(&context, &list_config), 0);
+}
+
+#[tokio::test]
+async fn rejects_missing_entries() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let wallets = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let (wallet_entry, _) = WalletEntry::find_pda(
+        &context.list_namespace(&list_config),
+        &list_config,
+        &wallets[0],
+    );
+
+    let res = send_batch(&mut context, &list_config, &wallets, &[wallet_entry]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(3))
+    );
+}
+
+#[tokio::test]
+async fn rejects_non_authority() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let other = Keypair::new();
+    context.vm.airdrop(&other.pubkey(), 1_000_000_000).unwrap();
+
+    let wallet = Pubkey::new_unique();
+    let (wallet_entry, _) =
+        WalletEntry::find_pda(&context.list_namespace(&list_config), &list_config, &wallet);
+
+    let ix = token_acl_gate_client::instructions::AddWalletBatchBuilder::new()
+        .authority(other.pubkey())
+        .payer(other.pubkey())
+        .list_config(list_config)
+        .wallets(vec![wallet])
+        .add_remaining_account(AccountMeta::new(wallet_entry, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&other.pubkey()),
+        &[other],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn adds_batch_at_the_cap() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let wallets = (0..AddWalletBatchBuilder::MAX_BATCH)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+    let (res, _) = context.try_add_wallets_to_list(&list_config, &wallets);
+    assert!(res.is_ok());
+    assert_eq!(
+        wallets_count(&context, &list_config),
+        AddWalletBatchBuilder::MAX_BATCH as u64
+    );
+}
+
+#[tokio::test]
+async fn rejects_batch_over_the_cap() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let wallets = (0..=AddWalletBatchBuilder::MAX_BATCH)
+        .map(|_| Pubkey::new_unique())
+        .collect::<Vec<_>>();
+    let (res, wallet_entries) = context.try_add_wallets_to_list(&list_config, &wallets);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(34))
+    );
+
+    // the count is checked too when the entries aren't passed
+    let res = send_batch(&mut context, &list_config, &wallets, &wallet_entries[..1]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(34))
+    );
+    assert_eq!(wallets_count(&context, &list_config), 0);
+}
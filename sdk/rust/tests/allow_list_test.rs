@@ -1,5 +1,9 @@
 pub mod program_test;
-use solana_sdk::signer::Signer;
+use solana_sdk::{
+    instruction::InstructionError,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
 use token_acl_gate_client::types::Mode;
 
 use crate::program_test::TestContext;
@@ -35,3 +39,136 @@ async fn thaws_whitelisted_wallet() {
     let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
     assert!(res.is_ok());
 }
+
+#[tokio::test]
+async fn fails_to_thaw_with_odd_remaining_accounts() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    // the list is passed without its wallet entry
+    let mut ix = context.can_thaw_permissionless_instruction(
+        &context.auth.pubkey(),
+        &wallet.pubkey(),
+        &ta,
+        &[(list_config, wallet_entry)],
+    );
+    ix.accounts.pop();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
+
+#[tokio::test]
+async fn fails_to_thaw_with_list_as_its_own_wallet_entry() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, list_config)]);
+    assert!(res.is_err());
+
+    let err = res.err().unwrap();
+    assert_eq!(
+        err.err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
+
+#[tokio::test]
+async fn thaws_with_matching_extra_metas_count() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_thaw_with_stale_extra_metas_count() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let other_list = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config, other_list]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_err());
+
+    let err = res.err().unwrap();
+    assert_eq!(
+        err.err,
+        TransactionError::InstructionError(0, InstructionError::Custom(9))
+    );
+}
+
+#[tokio::test]
+async fn fails_to_thaw_with_the_freeze_extra_metas() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config]);
+    let _ = context.setup_freeze_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    // same metas and count, but not the thaw extra metas address
+    let mut ix = context.can_thaw_permissionless_instruction(
+        &context.auth.pubkey(),
+        &wallet.pubkey(),
+        &ta,
+        &[(list_config, wallet_entry)],
+    );
+    ix.accounts[5].pubkey = token_acl_interface::get_freeze_extra_account_metas_address(
+        &context.token.mint,
+        &token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert_eq!(
+        context.vm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(9))
+    );
+}
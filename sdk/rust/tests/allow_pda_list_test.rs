@@ -0,0 +1,64 @@
+pub mod program_test;
+use solana_sdk::signer::Signer;
+use token_acl_gate_client::{accounts::ListConfig, types::Mode};
+
+use crate::program_test::TestContext;
+
+#[tokio::test]
+async fn stores_allow_all_pdas_mode() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::AllowAllPdas);
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.mode, Mode::AllowAllPdas as u8);
+    assert_eq!(config.mode, 3);
+}
+
+#[tokio::test]
+async fn thaws_non_eoa_wallet() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::AllowAllPdas);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    // list_config is acting as the ta owner for test simplicity
+    // as this is one of the off-the-curve available pubkeys
+    let ta = context.create_token_account_from_pubkey(&list_config);
+
+    let res = context.thaw_permissionless(&list_config, &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_thaw_eoa_wallet() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::AllowAllPdas);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn thaws_eoa_added_wallet() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::AllowAllPdas);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let _ = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
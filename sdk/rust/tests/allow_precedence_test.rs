@@ -0,0 +1,105 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext};
+
+/// Creates an allow list and a block list for the mint, the block list giving
+/// precedence to allow lists when `allow_precedence` is set.
+fn setup_lists(
+    context: &mut TestContext,
+    allow_mode: Mode,
+    allow_precedence: bool,
+) -> (Pubkey, Pubkey) {
+    let _ = context.setup_token_acl();
+    let allow_list = context.create_list(allow_mode);
+    let block_list = context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            allow_precedence,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[allow_list, block_list]);
+
+    (allow_list, block_list)
+}
+
+fn can_thaw(
+    context: &mut TestContext,
+    wallet: &Keypair,
+    allow_list: &Pubkey,
+    block_list: &Pubkey,
+) -> TransactionResult {
+    let ta = context.create_token_account(wallet);
+    let entry = |context: &TestContext, list: &Pubkey| {
+        WalletEntry::find_pda(&context.list_namespace(list), list, &wallet.pubkey()).0
+    };
+    let pairs = [
+        (*allow_list, entry(context, allow_list)),
+        (*block_list, entry(context, block_list)),
+    ];
+
+    context.can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs)
+}
+
+#[tokio::test]
+async fn block_wins_by_default() {
+    let mut context = TestContext::new();
+    let (allow_list, block_list) = setup_lists(&mut context, Mode::Allow, false);
+
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&allow_list, &wallet.pubkey());
+    let _ = context.add_wallet_to_list(&block_list, &wallet.pubkey());
+
+    let res = can_thaw(&mut context, &wallet, &allow_list, &block_list);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn allow_wins_with_allow_precedence() {
+    let mut context = TestContext::new();
+    let (allow_list, block_list) = setup_lists(&mut context, Mode::Allow, true);
+
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&allow_list, &wallet.pubkey());
+    let _ = context.add_wallet_to_list(&block_list, &wallet.pubkey());
+
+    let res = can_thaw(&mut context, &wallet, &allow_list, &block_list);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn blocks_owner_without_allow_entry_with_allow_precedence() {
+    let mut context = TestContext::new();
+    let (allow_list, block_list) = setup_lists(&mut context, Mode::AllowAllEoas, true);
+
+    // the eoa passes the allow all eoas list without holding an entry on it
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&block_list, &wallet.pubkey());
+
+    let res = can_thaw(&mut context, &wallet, &allow_list, &block_list);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn thaws_non_blocked_owner_with_allow_precedence() {
+    let mut context = TestContext::new();
+    let (allow_list, block_list) = setup_lists(&mut context, Mode::Allow, true);
+
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&allow_list, &wallet.pubkey());
+
+    let res = can_thaw(&mut context, &wallet, &allow_list, &block_list);
+    assert!(res.is_ok());
+}
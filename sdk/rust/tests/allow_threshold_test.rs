@@ -0,0 +1,82 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::{ListOptions, TestContext};
+
+/// Creates three allow lists with `allow_threshold` and puts the owner on the
+/// first two of them.
+fn setup_layered_lists(
+    context: &mut TestContext,
+    allow_threshold: u8,
+) -> (Keypair, Pubkey, Vec<(Pubkey, Pubkey)>) {
+    let _ = context.setup_token_acl();
+    let lists = (0..3)
+        .map(|_| {
+            context.create_list_with_config(
+                Mode::Allow,
+                ListOptions {
+                    allow_threshold,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let _ = context.setup_extra_metas(&lists);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let pairs = lists
+        .iter()
+        .enumerate()
+        .map(|(i, list)| {
+            let wallet_entry = if i < 2 {
+                context.add_wallet_to_list(list, &wallet.pubkey())
+            } else {
+                token_acl_gate_client::accounts::WalletEntry::find_pda(
+                    &context.list_namespace(list),
+                    list,
+                    &wallet.pubkey(),
+                )
+                .0
+            };
+            (*list, wallet_entry)
+        })
+        .collect();
+
+    (wallet, ta, pairs)
+}
+
+#[tokio::test]
+async fn allows_owner_meeting_threshold() {
+    let mut context = TestContext::new();
+    let (wallet, ta, pairs) = setup_layered_lists(&mut context, 2);
+
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn denies_owner_below_threshold() {
+    let mut context = TestContext::new();
+    let (wallet, ta, pairs) = setup_layered_lists(&mut context, 3);
+
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(31))
+    );
+}
+
+#[tokio::test]
+async fn requires_every_allow_list_without_threshold() {
+    let mut context = TestContext::new();
+    let (wallet, ta, pairs) = setup_layered_lists(&mut context, 0);
+
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs);
+    assert!(res.is_err());
+}
@@ -0,0 +1,86 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    clock::Clock, instruction::InstructionError, pubkey::Pubkey, signer::Signer,
+    transaction::TransactionError,
+};
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::{ListOptions, TestContext};
+
+const HOUR: u32 = 60 * 60;
+
+fn setup_windowed_list(
+    context: &mut TestContext,
+    allowed_window_start: u32,
+    allowed_window_end: u32,
+) -> (Pubkey, Keypair, Pubkey) {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            allowed_window_start,
+            allowed_window_end,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    (list_config, wallet, ta)
+}
+
+fn set_second_of_day(context: &mut TestContext, second_of_day: u32) {
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp = 20_000 * 86_400 + second_of_day as i64;
+    context.vm.set_sysvar::<Clock>(&clock);
+    context.vm.expire_blockhash();
+}
+
+#[tokio::test]
+async fn allows_thaw_inside_window() {
+    let mut context = TestContext::new();
+    let (_, wallet, ta) = setup_windowed_list(&mut context, 14 * HOUR, 21 * HOUR);
+
+    set_second_of_day(&mut context, 15 * HOUR);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn denies_thaw_outside_window() {
+    let mut context = TestContext::new();
+    let (list_config, wallet, ta) = setup_windowed_list(&mut context, 14 * HOUR, 21 * HOUR);
+
+    // the window end is exclusive
+    set_second_of_day(&mut context, 21 * HOUR);
+
+    let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &context.list_namespace(&list_config),
+        &list_config,
+        &wallet.pubkey(),
+    );
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(30))
+    );
+}
+
+#[tokio::test]
+async fn evaluates_window_wrapping_past_midnight() {
+    let mut context = TestContext::new();
+    let (_, wallet, ta) = setup_windowed_list(&mut context, 22 * HOUR, 2 * HOUR);
+
+    set_second_of_day(&mut context, HOUR);
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+
+    set_second_of_day(&mut context, 12 * HOUR);
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+}
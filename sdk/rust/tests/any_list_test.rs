@@ -0,0 +1,157 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{accounts::WalletEntry, client::PolicyUpdate, types::Mode};
+
+use crate::program_test::TestContext;
+
+/// Runs CanThaw for `wallet` against every list, appending `flag` to the
+/// instruction data when set.
+fn can_thaw(
+    context: &mut TestContext,
+    wallet: &Keypair,
+    lists: &[Pubkey],
+    flag: Option<u8>,
+) -> TransactionResult {
+    let ta = context.create_token_account(wallet);
+    let pairs = lists
+        .iter()
+        .map(|list| {
+            let namespace = context.list_namespace(list);
+            (
+                *list,
+                WalletEntry::find_pda(&namespace, list, &wallet.pubkey()).0,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut ix = context.can_thaw_permissionless_instruction(
+        &context.auth.pubkey(),
+        &wallet.pubkey(),
+        &ta,
+        &pairs,
+    );
+    ix.data.extend(flag);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// Lets thaws checked against `list` pass on any list, once all of them do.
+fn set_any_list(context: &mut TestContext, list: &Pubkey) {
+    let ix = PolicyUpdate {
+        any_list: Some(true),
+        ..Default::default()
+    }
+    .builder()
+    .authority(context.auth.pubkey())
+    .list_config(*list)
+    .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+}
+
+/// Two allow lists for the mint, with a wallet on the first one only.
+fn setup(context: &mut TestContext) -> ([Pubkey; 2], Keypair) {
+    let _ = context.setup_token_acl();
+    let lists = [
+        context.create_list(Mode::Allow),
+        context.create_list(Mode::Allow),
+    ];
+    let _ = context.setup_extra_metas(&lists);
+
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&lists[0], &wallet.pubkey());
+
+    (lists, wallet)
+}
+
+#[tokio::test]
+async fn requires_every_list_by_default() {
+    let mut context = TestContext::new();
+    let (lists, wallet) = setup(&mut context);
+
+    let res = can_thaw(&mut context, &wallet, &lists, None);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn passes_on_any_list() {
+    let mut context = TestContext::new();
+    let (lists, wallet) = setup(&mut context);
+    for list in &lists {
+        set_any_list(&mut context, list);
+    }
+
+    let res = can_thaw(&mut context, &wallet, &lists, None);
+    assert!(res.is_ok());
+
+    // the allowing list doesn't have to come first
+    context.vm.expire_blockhash();
+    let res = can_thaw(&mut context, &wallet, &[lists[1], lists[0]], None);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn requires_every_list_unless_all_of_them_opt_in() {
+    // neither the allowing list nor the other one relaxes the thaw alone
+    for opted_in in [0, 1] {
+        let mut context = TestContext::new();
+        let (lists, wallet) = setup(&mut context);
+        set_any_list(&mut context, &lists[opted_in]);
+
+        let res = can_thaw(&mut context, &wallet, &lists, None);
+        assert_eq!(
+            res.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(2))
+        );
+    }
+}
+
+#[tokio::test]
+async fn fails_when_no_list_allows() {
+    let mut context = TestContext::new();
+    let (lists, _) = setup(&mut context);
+    for list in &lists {
+        set_any_list(&mut context, list);
+    }
+
+    let res = can_thaw(&mut context, &Keypair::new(), &lists, None);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn rejects_the_any_list_flag_in_instruction_data() {
+    let mut context = TestContext::new();
+    let (lists, wallet) = setup(&mut context);
+
+    // any-list is a list setting now, a flags byte is malformed data
+    let res = can_thaw(&mut context, &wallet, &lists, Some(1 << 0));
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
@@ -0,0 +1,105 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{clock::Clock, pubkey::Pubkey, signer::Signer, transaction::Transaction};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const APPEAL_PERIOD_SECS: u64 = 3600;
+
+fn setup_blocked_wallet(context: &mut TestContext) -> (Pubkey, Pubkey, Keypair, Pubkey) {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            appeal_period_secs: APPEAL_PERIOD_SECS,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+
+    (list_config, wallet_entry, wallet, ta)
+}
+
+fn advance_clock(context: &mut TestContext, secs: i64) {
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += secs;
+    context.vm.set_sysvar::<Clock>(&clock);
+    context.vm.expire_blockhash();
+}
+
+fn reaffirm_block(context: &mut TestContext, list_config: &Pubkey, wallet_entry: &Pubkey) {
+    let ix = token_acl_gate_client::instructions::ReaffirmBlockBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list_config)
+        .wallet_entry(*wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
+fn appeal_deadline(context: &TestContext, wallet_entry: &Pubkey) -> i64 {
+    let account = context.vm.get_account(wallet_entry).unwrap();
+    WalletEntry::from_bytes(&account.data)
+        .unwrap()
+        .appeal_deadline
+}
+
+#[tokio::test]
+async fn blocks_until_appeal_deadline() {
+    let mut context = TestContext::new();
+
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let (_, wallet_entry, wallet, ta) = setup_blocked_wallet(&mut context);
+    assert_eq!(
+        appeal_deadline(&context, &wallet_entry),
+        now + APPEAL_PERIOD_SECS as i64
+    );
+
+    advance_clock(&mut context, APPEAL_PERIOD_SECS as i64 - 1);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn lifts_block_at_appeal_deadline() {
+    let mut context = TestContext::new();
+
+    let (_, _, wallet, ta) = setup_blocked_wallet(&mut context);
+
+    advance_clock(&mut context, APPEAL_PERIOD_SECS as i64);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn blocks_again_after_reaffirmation() {
+    let mut context = TestContext::new();
+
+    let (list_config, wallet_entry, wallet, ta) = setup_blocked_wallet(&mut context);
+
+    advance_clock(&mut context, APPEAL_PERIOD_SECS as i64 + 1);
+    reaffirm_block(&mut context, &list_config, &wallet_entry);
+
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    assert_eq!(
+        appeal_deadline(&context, &wallet_entry),
+        now + APPEAL_PERIOD_SECS as i64
+    );
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+}
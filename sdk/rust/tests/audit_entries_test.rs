@@ -0,0 +1,75 @@
+pub mod program_test;
+use solana_instruction::AccountMeta;
+use solana_sdk::{account::Account, pubkey::Pubkey, signer::Signer, transaction::Transaction};
+use token_acl_gate_client::{client::EntryHealth, types::Mode};
+
+use crate::program_test::TestContext;
+
+fn audit_entries(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallet_entries: &[Pubkey],
+) -> Vec<EntryHealth> {
+    let ix = token_acl_gate_client::instructions::AuditEntriesBuilder::new()
+        .list_config(*list_config)
+        .add_remaining_accounts(
+            &wallet_entries
+                .iter()
+                .map(|entry| AccountMeta::new_readonly(*entry, false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let meta = context.vm.send_transaction(tx).unwrap();
+
+    EntryHealth::from_return_data(&meta.return_data.data)
+}
+
+#[tokio::test]
+async fn reports_correctly_bound_entry() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let other_list = context.create_list(Mode::Allow);
+    let entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    let other_entry = context.add_wallet_to_list(&other_list, &Pubkey::new_unique());
+
+    let health = audit_entries(&mut context, &list_config, &[entry, other_entry]);
+
+    assert!(health[0].is_healthy());
+    assert!(health[1].is_config_present());
+    assert!(!health[1].is_binding_ok());
+}
+
+#[tokio::test]
+async fn flags_orphaned_entry() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    // the list config is gone while its entry is left behind
+    let res = context.vm.set_account(
+        list_config,
+        Account {
+            lamports: 0,
+            data: vec![],
+            owner: Pubkey::default(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    assert!(res.is_ok());
+
+    let health = audit_entries(&mut context, &list_config, &[entry]);
+
+    assert!(!health[0].is_config_present());
+    assert!(health[0].is_binding_ok());
+    assert!(health[0].is_orphaned());
+}
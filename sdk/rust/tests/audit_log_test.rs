@@ -0,0 +1,373 @@
+pub mod program_test;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_sdk::{
+    clock::Clock, hash::hashv, pubkey::Pubkey, signer::Signer, transaction::Transaction,
+};
+use token_acl_gate_client::{
+    accounts::{AuditLog, ListConfig, MerkleProof, WalletEntry},
+    client::PolicyUpdate,
+    types::Mode,
+};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const ACTION_ADD: u8 = 1;
+const ACTION_REMOVE: u8 = 2;
+const ACTION_UPDATE_MODE: u8 = 3;
+const ACTION_SET_PAUSED: u8 = 4;
+const ACTION_CONFIGURE_POLICY: u8 = 5;
+const ACTION_UPDATE_MERKLE_ROOT: u8 = 8;
+const ACTION_PROPOSE_AUTHORITY: u8 = 9;
+const ACTION_ACCEPT_AUTHORITY: u8 = 10;
+const ACTION_REAFFIRM_BLOCK: u8 = 11;
+const ACTION_CLOSE_MERKLE_PROOF: u8 = 12;
+
+fn send(context: &mut TestContext, ix: Instruction) {
+    let signer = context.auth.insecure_clone();
+    send_signed(context, &signer, ix);
+}
+
+fn send_signed(context: &mut TestContext, signer: &Keypair, ix: Instruction) {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), signer.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
+fn get_log(context: &TestContext, audit_log: &Pubkey) -> AuditLog {
+    let account = context.vm.get_account(audit_log).unwrap();
+    AuditLog::from_bytes(&account.data).unwrap()
+}
+
+fn create_audit_log(context: &mut TestContext, list_config: &Pubkey) -> Pubkey {
+    let (audit_log, _) = AuditLog::find_pda(list_config);
+
+    let ix = token_acl_gate_client::instructions::CreateAuditLogBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .audit_log(audit_log)
+        .instruction();
+    send(context, ix);
+
+    audit_log
+}
+
+fn add_wallet(context: &mut TestContext, list_config: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    let (audit_log, _) = AuditLog::find_pda(list_config);
+    let (wallet_entry, _) =
+        token_acl_gate_client::accounts::WalletEntry::find_pda(&[0; 16], list_config, wallet);
+
+    let ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .add_remaining_account(AccountMeta::new(audit_log, false))
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(false)
+        .instruction();
+    send(context, ix);
+
+    wallet_entry
+}
+
+fn remove_wallet(context: &mut TestContext, list_config: &Pubkey, wallet_entry: &Pubkey) {
+    let (audit_log, _) = AuditLog::find_pda(list_config);
+
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list_config)
+        .wallet_entry(*wallet_entry)
+        .add_remaining_account(AccountMeta::new(audit_log, false))
+        .instruction();
+    send(context, ix);
+}
+
+fn chain(head: [u8; 32], action: u8, key: &Pubkey, slot: u64, payload: &[u8]) -> [u8; 32] {
+    hashv(&[&head, &[action], key.as_ref(), &slot.to_le_bytes(), payload]).to_bytes()
+}
+
+#[tokio::test]
+async fn appends_hash_chained_records() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let audit_log = create_audit_log(&mut context, &list_config);
+    let slot = context.vm.get_sysvar::<Clock>().slot;
+
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+    let first_entry = add_wallet(&mut context, &list_config, &first);
+    add_wallet(&mut context, &list_config, &second);
+    remove_wallet(&mut context, &list_config, &first_entry);
+
+    let mut expected = [0u8; 32];
+    expected = chain(expected, ACTION_ADD, &first, slot, &[]);
+    expected = chain(expected, ACTION_ADD, &second, slot, &[]);
+    expected = chain(expected, ACTION_REMOVE, &first, slot, &[]);
+
+    let log = get_log(&context, &audit_log);
+    assert_eq!(log.list_config, list_config);
+    assert_eq!(log.count, 3);
+    assert_eq!(log.head, expected);
+}
+
+#[tokio::test]
+async fn records_list_changes_by_their_authority() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let audit_log = create_audit_log(&mut context, &list_config);
+    let slot = context.vm.get_sysvar::<Clock>().slot;
+    let authority = context.auth.pubkey();
+
+    let audit_log_meta = AccountMeta::new(audit_log, false);
+    let update_mode = token_acl_gate_client::instructions::UpdateModeBuilder::new()
+        .authority(authority)
+        .list_config(list_config)
+        .mode(Mode::Block)
+        .acknowledge(false)
+        .eoa_grace_period_secs(0)
+        .add_remaining_account(audit_log_meta.clone())
+        .instruction();
+    send(&mut context, update_mode);
+
+    let set_paused = token_acl_gate_client::instructions::SetPausedBuilder::new()
+        .authority(authority)
+        .list_config(list_config)
+        .paused(true)
+        .add_remaining_account(audit_log_meta.clone())
+        .instruction();
+    send(&mut context, set_paused);
+
+    let configure_policy = PolicyUpdate {
+        appeal_period_secs: Some(3600),
+        ..Default::default()
+    }
+    .builder()
+    .authority(authority)
+    .list_config(list_config)
+    .add_remaining_account(audit_log_meta)
+    .instruction();
+    // the policy record holds the mask and values as sent
+    let policy = configure_policy.data[1..].to_vec();
+    send(&mut context, configure_policy);
+
+    // the mode record holds the mode and the end of the eoa grace period
+    let mut mode = vec![Mode::Block as u8];
+    mode.extend_from_slice(&0i64.to_le_bytes());
+
+    let mut expected = [0u8; 32];
+    for (action, payload) in [
+        (ACTION_UPDATE_MODE, &mode[..]),
+        (ACTION_SET_PAUSED, &[1]),
+        (ACTION_CONFIGURE_POLICY, &policy),
+    ] {
+        expected = chain(expected, action, &authority, slot, payload);
+    }
+
+    let log = get_log(&context, &audit_log);
+    assert_eq!(log.count, 3);
+    assert_eq!(log.head, expected);
+}
+
+#[tokio::test]
+async fn records_merkle_and_authority_changes() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::MerkleAllow);
+    let audit_log = create_audit_log(&mut context, &list_config);
+    let slot = context.vm.get_sysvar::<Clock>().slot;
+    let authority = context.auth.pubkey();
+    let audit_log_meta = AccountMeta::new(audit_log, false);
+
+    // a tree of one member, whose leaf is the root and whose proof is empty
+    let member = Keypair::new();
+    let root = hashv(&[&[0], member.pubkey().as_ref()]).to_bytes();
+    let ix = token_acl_gate_client::instructions::UpdateMerkleRootBuilder::new()
+        .authority(authority)
+        .list_config(list_config)
+        .merkle_root(root)
+        .add_remaining_account(audit_log_meta.clone())
+        .instruction();
+    send(&mut context, ix);
+
+    let (merkle_proof, _) = MerkleProof::find_pda(&list_config, &member.pubkey());
+    let ix = token_acl_gate_client::instructions::SubmitMerkleProofBuilder::new()
+        .payer(authority)
+        .list_config(list_config)
+        .member(member.pubkey())
+        .merkle_proof(merkle_proof)
+        .proof(vec![])
+        .instruction();
+    send(&mut context, ix);
+
+    let ix = token_acl_gate_client::instructions::CloseMerkleProofBuilder::new()
+        .authority(member.pubkey())
+        .list_config(list_config)
+        .merkle_proof(merkle_proof)
+        .add_remaining_account(audit_log_meta.clone())
+        .instruction();
+    send_signed(&mut context, &member, ix);
+
+    let new_authority = Keypair::new();
+    let ix = token_acl_gate_client::instructions::ProposeAuthorityTransferBuilder::new()
+        .authority(authority)
+        .list_config(list_config)
+        .new_authority(new_authority.pubkey())
+        .add_remaining_account(audit_log_meta.clone())
+        .instruction();
+    send(&mut context, ix);
+
+    let ix = token_acl_gate_client::instructions::AcceptAuthorityTransferBuilder::new()
+        .pending_authority(new_authority.pubkey())
+        .list_config(list_config)
+        .add_remaining_account(audit_log_meta)
+        .instruction();
+    send_signed(&mut context, &new_authority, ix);
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    let mut root_payload = root.to_vec();
+    root_payload.extend_from_slice(&config.root_version.to_le_bytes());
+
+    let mut expected = [0u8; 32];
+    expected = chain(
+        expected,
+        ACTION_UPDATE_MERKLE_ROOT,
+        &authority,
+        slot,
+        &root_payload,
+    );
+    expected = chain(
+        expected,
+        ACTION_CLOSE_MERKLE_PROOF,
+        &member.pubkey(),
+        slot,
+        &[],
+    );
+    expected = chain(
+        expected,
+        ACTION_PROPOSE_AUTHORITY,
+        &authority,
+        slot,
+        new_authority.pubkey().as_ref(),
+    );
+    expected = chain(
+        expected,
+        ACTION_ACCEPT_AUTHORITY,
+        &new_authority.pubkey(),
+        slot,
+        &[],
+    );
+
+    let log = get_log(&context, &audit_log);
+    assert_eq!(log.count, 4);
+    assert_eq!(log.head, expected);
+}
+
+#[tokio::test]
+async fn records_confirmed_reports_and_reaffirmed_blocks() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            appeal_period_secs: 3600,
+            ..Default::default()
+        },
+    );
+    let audit_log = create_audit_log(&mut context, &list_config);
+    let slot = context.vm.get_sysvar::<Clock>().slot;
+    let audit_log_meta = AccountMeta::new(audit_log, false);
+
+    let reporter = Keypair::new();
+    assert!(context
+        .vm
+        .airdrop(&reporter.pubkey(), 1_000_000_000)
+        .is_ok());
+    let wallet = Pubkey::new_unique();
+    assert!(context
+        .enqueue_report(&reporter, &list_config, &wallet)
+        .is_ok());
+
+    let (review_queue, _) = token_acl_gate_client::accounts::ReviewQueue::find_pda(&list_config);
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet);
+    let ix = token_acl_gate_client::instructions::DrainQueueBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .review_queue(review_queue)
+        .confirmed(0b1)
+        .add_remaining_account(AccountMeta::new(wallet_entry, false))
+        .add_remaining_account(audit_log_meta.clone())
+        .instruction();
+    send(&mut context, ix);
+
+    let ix = token_acl_gate_client::instructions::ReaffirmBlockBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config)
+        .wallet_entry(wallet_entry)
+        .add_remaining_account(audit_log_meta)
+        .instruction();
+    send(&mut context, ix);
+
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry = WalletEntry::from_bytes(&account.data).unwrap();
+
+    let mut expected = [0u8; 32];
+    expected = chain(expected, ACTION_ADD, &wallet, slot, &[]);
+    expected = chain(
+        expected,
+        ACTION_REAFFIRM_BLOCK,
+        &wallet,
+        slot,
+        &entry.appeal_deadline.to_le_bytes(),
+    );
+
+    let log = get_log(&context, &audit_log);
+    assert_eq!(log.count, 2);
+    assert_eq!(log.head, expected);
+}
+
+#[tokio::test]
+async fn fails_to_append_to_audit_log_of_another_list() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let other_list = context.create_list(Mode::Allow);
+    let other_audit_log = create_audit_log(&mut context, &other_list);
+
+    let wallet = Pubkey::new_unique();
+    let (wallet_entry, _) =
+        token_acl_gate_client::accounts::WalletEntry::find_pda(&[0; 16], &list_config, &wallet);
+
+    let ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .wallet(wallet)
+        .wallet_entry(wallet_entry)
+        .add_remaining_account(AccountMeta::new(other_audit_log, false))
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_err());
+}
@@ -0,0 +1,61 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::{ListOptions, TestContext};
+
+fn setup_cosigned_list(context: &mut TestContext) -> (Pubkey, Pubkey, Keypair, Pubkey) {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            require_authority_cosign: true,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+
+    (list_config, wallet_entry, wallet, ta)
+}
+
+#[tokio::test]
+async fn allows_thaw_cosigned_by_authority() {
+    let mut context = TestContext::new();
+    let (list_config, wallet_entry, wallet, ta) = setup_cosigned_list(&mut context);
+
+    let authority = context.auth.insecure_clone();
+    let res = context.can_thaw_permissionless_signed_by(
+        &authority,
+        &wallet.pubkey(),
+        &ta,
+        &[(list_config, wallet_entry)],
+    );
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn denies_thaw_without_authority_cosign() {
+    let mut context = TestContext::new();
+    let (list_config, wallet_entry, wallet, ta) = setup_cosigned_list(&mut context);
+
+    let signer = Keypair::new();
+    let _ = context.vm.airdrop(&signer.pubkey(), 1_000_000_000);
+
+    let res = context.can_thaw_permissionless_signed_by(
+        &signer,
+        &wallet.pubkey(),
+        &ta,
+        &[(list_config, wallet_entry)],
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
@@ -0,0 +1,247 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{AuthoritySet, ListConfig, WalletEntry},
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+const ADD: u16 = 1 << 0;
+const REMOVE: u16 = 1 << 1;
+
+fn set_delegate(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    delegate: &Pubkey,
+    permissions: u16,
+) {
+    let (authority_set, _) = AuthoritySet::find_pda(list_config);
+
+    let ix = token_acl_gate_client::instructions::SetDelegateBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .authority_set(authority_set)
+        .delegate(*delegate)
+        .permissions(permissions)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
+fn delegate_add_wallet(
+    context: &mut TestContext,
+    delegate: &Keypair,
+    list_config: &Pubkey,
+    wallet: &Pubkey,
+) -> (TransactionResult, Pubkey) {
+    let (authority_set, _) = AuthoritySet::find_pda(list_config);
+    let (wallet_entry, _) =
+        token_acl_gate_client::accounts::WalletEntry::find_pda(&[0; 16], list_config, wallet);
+
+    let ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
+        .authority(delegate.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .add_remaining_account(AccountMeta::new_readonly(authority_set, false))
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(false)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), delegate.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    (context.vm.send_transaction(tx), wallet_entry)
+}
+
+fn delegate_remove_wallet(
+    context: &mut TestContext,
+    delegate: &Keypair,
+    list_config: &Pubkey,
+    wallet_entry: &Pubkey,
+) -> TransactionResult {
+    let (authority_set, _) = AuthoritySet::find_pda(list_config);
+
+    // the entry's rent goes to the list authority, passed first
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(delegate.pubkey())
+        .list_config(*list_config)
+        .wallet_entry(*wallet_entry)
+        .add_remaining_account(AccountMeta::new(context.auth.pubkey(), false))
+        .add_remaining_account(AccountMeta::new_readonly(authority_set, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), delegate.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn new_delegate(context: &mut TestContext) -> Keypair {
+    let delegate = Keypair::new();
+    context
+        .vm
+        .airdrop(&delegate.pubkey(), 1_000_000_000)
+        .unwrap();
+    delegate
+}
+
+#[tokio::test]
+async fn delegate_with_add_permission_adds_wallet() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let delegate = new_delegate(&mut context);
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), ADD);
+
+    let (res, wallet_entry) =
+        delegate_add_wallet(&mut context, &delegate, &list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+
+    // the entry records the delegate as the signer that added it
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry = WalletEntry::from_bytes(&account.data).unwrap();
+    assert_eq!(entry.added_by, delegate.pubkey());
+}
+
+#[tokio::test]
+async fn fails_to_remove_wallet_without_remove_permission() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let delegate = new_delegate(&mut context);
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), ADD);
+
+    let (res, wallet_entry) =
+        delegate_add_wallet(&mut context, &delegate, &list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+
+    let res = delegate_remove_wallet(&mut context, &delegate, &list_config, &wallet_entry);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn delegate_with_remove_permission_removes_wallet() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let delegate = new_delegate(&mut context);
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), ADD | REMOVE);
+
+    let (res, wallet_entry) =
+        delegate_add_wallet(&mut context, &delegate, &list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+
+    let res = delegate_remove_wallet(&mut context, &delegate, &list_config, &wallet_entry);
+    assert!(res.is_ok());
+    assert!(context.vm.get_account(&wallet_entry).is_none());
+}
+
+#[tokio::test]
+async fn fails_to_add_wallet_after_delegate_is_removed() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let delegate = new_delegate(&mut context);
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), ADD);
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), 0);
+
+    let (res, _) =
+        delegate_add_wallet(&mut context, &delegate, &list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+/// Sends `ix` signed by `delegate`, passing the list's authority set.
+fn send_as_delegate(
+    context: &mut TestContext,
+    delegate: &Keypair,
+    list_config: &Pubkey,
+    mut ix: Instruction,
+) -> TransactionResult {
+    let (authority_set, _) = AuthoritySet::find_pda(list_config);
+    ix.accounts
+        .push(AccountMeta::new_readonly(authority_set, false));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), delegate.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+#[tokio::test]
+async fn membership_delegate_cannot_change_mode_or_authority() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let manager = new_delegate(&mut context);
+    set_delegate(&mut context, &list_config, &manager.pubkey(), ADD | REMOVE);
+
+    let (res, wallet_entry) =
+        delegate_add_wallet(&mut context, &manager, &list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+    let res = delegate_remove_wallet(&mut context, &manager, &list_config, &wallet_entry);
+    assert!(res.is_ok());
+
+    let ix = token_acl_gate_client::instructions::UpdateModeBuilder::new()
+        .authority(manager.pubkey())
+        .list_config(list_config)
+        .mode(Mode::Block)
+        .acknowledge(false)
+        .eoa_grace_period_secs(0)
+        .instruction();
+    let res = send_as_delegate(&mut context, &manager, &list_config, ix);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+
+    let ix = token_acl_gate_client::instructions::ProposeAuthorityTransferBuilder::new()
+        .authority(manager.pubkey())
+        .list_config(list_config)
+        .new_authority(manager.pubkey())
+        .instruction();
+    let res = send_as_delegate(&mut context, &manager, &list_config, ix);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.mode, Mode::Allow as u8);
+    assert_eq!(config.authority, context.auth.pubkey());
+}
@@ -0,0 +1,209 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::AccountMeta;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{AuthoritySet, ListConfig},
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+const TRANSFER: u16 = 1 << 3;
+
+fn send(
+    context: &mut TestContext,
+    ix: solana_instruction::Instruction,
+    signer: &Keypair,
+) -> TransactionResult {
+    let mut signers = vec![context.auth.insecure_clone()];
+    if signer.pubkey() != context.auth.pubkey() {
+        signers.push(signer.insecure_clone());
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &signers,
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn propose(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    new_authority: &Pubkey,
+) -> TransactionResult {
+    let mut builder = token_acl_gate_client::instructions::ProposeAuthorityTransferBuilder::new();
+    builder
+        .authority(authority.pubkey())
+        .list_config(*list_config)
+        .new_authority(*new_authority);
+
+    // delegates pass the authority set
+    if authority.pubkey() != context.auth.pubkey() {
+        let (authority_set, _) = AuthoritySet::find_pda(list_config);
+        builder.add_remaining_account(AccountMeta::new_readonly(authority_set, false));
+    }
+
+    send(context, builder.instruction(), authority)
+}
+
+fn accept(
+    context: &mut TestContext,
+    pending_authority: &Keypair,
+    list_config: &Pubkey,
+) -> TransactionResult {
+    let ix = token_acl_gate_client::instructions::AcceptAuthorityTransferBuilder::new()
+        .pending_authority(pending_authority.pubkey())
+        .list_config(*list_config)
+        .instruction();
+
+    send(context, ix, pending_authority)
+}
+
+fn get_list_config(context: &TestContext, list_config: &Pubkey) -> ListConfig {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn transfers_authority_once_accepted() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let authority = context.auth.insecure_clone();
+    let new_authority = Keypair::new();
+
+    let res = propose(
+        &mut context,
+        &authority,
+        &list_config,
+        &new_authority.pubkey(),
+    );
+    assert!(res.is_ok());
+
+    // the current authority stays in charge until the transfer is accepted
+    let config = get_list_config(&context, &list_config);
+    assert_eq!(config.authority, authority.pubkey());
+    assert_eq!(config.pending_authority, new_authority.pubkey());
+
+    let res = accept(&mut context, &new_authority, &list_config);
+    assert!(res.is_ok());
+
+    let config = get_list_config(&context, &list_config);
+    assert_eq!(config.authority, new_authority.pubkey());
+    assert_eq!(config.pending_authority, Pubkey::default());
+    assert_eq!(config.last_actor, new_authority.pubkey());
+
+    // the previous authority lost its permissions
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn rejects_default_pending_authority() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let authority = context.auth.insecure_clone();
+
+    let res = propose(&mut context, &authority, &list_config, &Pubkey::default());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
+
+#[tokio::test]
+async fn rejects_proposal_from_non_authority() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let other = Keypair::new();
+
+    let res = propose(&mut context, &other, &list_config, &other.pubkey());
+    assert!(res.is_err());
+    assert_eq!(
+        get_list_config(&context, &list_config).pending_authority,
+        Pubkey::default()
+    );
+}
+
+#[tokio::test]
+async fn rejects_accept_from_other_key() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let authority = context.auth.insecure_clone();
+    let new_authority = Keypair::new();
+
+    let res = propose(
+        &mut context,
+        &authority,
+        &list_config,
+        &new_authority.pubkey(),
+    );
+    assert!(res.is_ok());
+
+    let res = accept(&mut context, &Keypair::new(), &list_config);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+    assert_eq!(
+        get_list_config(&context, &list_config).authority,
+        authority.pubkey()
+    );
+}
+
+#[tokio::test]
+async fn rejects_accept_without_proposal() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let res = accept(&mut context, &Keypair::new(), &list_config);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn delegate_with_transfer_permission_proposes() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let delegate = Keypair::new();
+    let new_authority = Keypair::new();
+
+    let (authority_set, _) = AuthoritySet::find_pda(&list_config);
+    let ix = token_acl_gate_client::instructions::SetDelegateBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .authority_set(authority_set)
+        .delegate(delegate.pubkey())
+        .permissions(TRANSFER)
+        .instruction();
+    let authority = context.auth.insecure_clone();
+    assert!(send(&mut context, ix, &authority).is_ok());
+
+    let res = propose(
+        &mut context,
+        &delegate,
+        &list_config,
+        &new_authority.pubkey(),
+    );
+    assert!(res.is_ok());
+    assert_eq!(
+        get_list_config(&context, &list_config).pending_authority,
+        new_authority.pubkey()
+    );
+}
@@ -9,6 +9,7 @@ use solana_sdk::{
 };
 use token_acl_gate_client::{
     accounts::{ListConfig, WalletEntry},
+    client::RemoveWalletReturnData,
     types::Mode,
 };
 
@@ -28,6 +29,26 @@ async fn creates_list() {
         .list_config(list_config_address)
         .mode(Mode::Allow)
         .seed(seed)
+        .namespace([0; 16])
+        .enroll_window_secs(0)
+        .max_enrolls_per_window(0)
+        .key_by_token_account(false)
+        .warmup_secs(0)
+        .token_program(Pubkey::default())
+        .transfer_hook_check(0)
+        .appeal_period_secs(0)
+        .require_nonzero_balance(false)
+        .allowed_window_start(0)
+        .allowed_window_end(0)
+        .require_authority_cosign(false)
+        .allow_threshold(0)
+        .allow_freeze_authority_delegate(false)
+        .strict_block_mode(false)
+        .allow_precedence(false)
+        .sunset_at(0)
+        .max_wallets(0)
+        .allow_multisig_owner(false)
+        .owner_extensions(0)
         .instruction();
 
     let tx = Transaction::new_signed_with_payer(
@@ -46,6 +67,73 @@ async fn creates_list() {
     assert_eq!(config.seed, seed);
     assert_eq!(config.mode, Mode::Allow as u8);
     assert_eq!(config.wallets_count, 0);
+    assert_eq!(config.namespace, [0; 16]);
+}
+
+fn create_list_ix_with_data(context: &TestContext, seed: &Pubkey, data: Vec<u8>) -> Transaction {
+    let (list_config_address, _) =
+        token_acl_gate_client::accounts::ListConfig::find_pda(&context.auth.pubkey(), seed);
+
+    let ix = solana_instruction::Instruction {
+        program_id: token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(context.auth.pubkey(), true),
+            AccountMeta::new(context.auth.pubkey(), true),
+            AccountMeta::new(list_config_address, false),
+            AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+        ],
+        data,
+    };
+
+    Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    )
+}
+
+#[tokio::test]
+async fn creates_list_with_v0_data() {
+    let mut context = TestContext::new();
+
+    let seed = Pubkey::new_unique();
+    let data = [1, 0, Mode::Block as u8]
+        .into_iter()
+        .chain(seed.to_bytes())
+        .collect();
+    let tx = create_list_ix_with_data(&context, &seed, data);
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let (list_config_address, _) =
+        token_acl_gate_client::accounts::ListConfig::find_pda(&context.auth.pubkey(), &seed);
+    let list_config = context.vm.get_account(&list_config_address).unwrap();
+    let config = ListConfig::from_bytes(&list_config.data).unwrap();
+
+    assert_eq!(config.seed, seed);
+    assert_eq!(config.mode, Mode::Block as u8);
+    assert_eq!(config.token_program, Pubkey::default());
+    assert_eq!(config.max_wallets, 0);
+    assert!(config.reserved.iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn fails_to_create_list_with_unknown_data_version() {
+    let mut context = TestContext::new();
+
+    let seed = Pubkey::new_unique();
+    let data = [1, 99, Mode::Allow as u8]
+        .into_iter()
+        .chain(seed.to_bytes())
+        .collect();
+    let tx = create_list_ix_with_data(&context, &seed, data);
+    let res = context.vm.send_transaction(tx);
+
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
 }
 
 #[tokio::test]
@@ -56,8 +144,11 @@ async fn fails_to_creates_list_with_non_pda_list() {
     let (_list_config_address, _) =
         token_acl_gate_client::accounts::ListConfig::find_pda(&context.auth.pubkey(), &seed);
 
-        let list_cfg_kp = Keypair::new();
-        context.vm.airdrop(&list_cfg_kp.pubkey(), 1_000_000_000).unwrap();
+    let list_cfg_kp = Keypair::new();
+    context
+        .vm
+        .airdrop(&list_cfg_kp.pubkey(), 1_000_000_000)
+        .unwrap();
 
     let ix = token_acl_gate_client::instructions::CreateListBuilder::new()
         .authority(context.auth.pubkey())
@@ -65,6 +156,26 @@ async fn fails_to_creates_list_with_non_pda_list() {
         .list_config(list_cfg_kp.pubkey())
         .mode(Mode::Allow)
         .seed(seed)
+        .namespace([0; 16])
+        .enroll_window_secs(0)
+        .max_enrolls_per_window(0)
+        .key_by_token_account(false)
+        .warmup_secs(0)
+        .token_program(Pubkey::default())
+        .transfer_hook_check(0)
+        .appeal_period_secs(0)
+        .require_nonzero_balance(false)
+        .allowed_window_start(0)
+        .allowed_window_end(0)
+        .require_authority_cosign(false)
+        .allow_threshold(0)
+        .allow_freeze_authority_delegate(false)
+        .strict_block_mode(false)
+        .allow_precedence(false)
+        .sunset_at(0)
+        .max_wallets(0)
+        .allow_multisig_owner(false)
+        .owner_extensions(0)
         .instruction();
 
     let tx = Transaction::new_signed_with_payer(
@@ -130,6 +241,35 @@ async fn deletes_list() {
     assert!(list_config.is_none());
 }
 
+#[tokio::test]
+async fn fails_to_delete_non_empty_list() {
+    let mut context = TestContext::new();
+
+    let list_config_address = context.create_list(Mode::Allow);
+    let _ = context.add_wallet_to_list(&list_config_address, &Pubkey::new_unique());
+
+    let ix = token_acl_gate_client::instructions::DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config_address)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+
+    let res = context.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(13))
+    );
+
+    let list_config = context.vm.get_account(&list_config_address).unwrap();
+    assert!(ListConfig::from_bytes(&list_config.data).is_ok());
+}
+
 #[tokio::test]
 async fn fails_to_delete_list_with_invalid_authority() {
     let mut context = TestContext::new();
@@ -171,6 +311,7 @@ async fn adds_wallet() {
     let wallet_address = Pubkey::new_unique();
     let list_config_address = context.create_list(Mode::Allow);
     let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
         &list_config_address,
         &wallet_address,
     );
@@ -181,6 +322,9 @@ async fn adds_wallet() {
         .list_config(list_config_address)
         .wallet(wallet_address)
         .wallet_entry(wallet_entry)
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(false)
         .instruction();
 
     let tx = Transaction::new_signed_with_payer(
@@ -205,6 +349,69 @@ async fn adds_wallet() {
     assert_eq!(entry.list_config, list_config_address);
 }
 
+fn add_wallet_ix_with_data(context: &mut TestContext, data: Vec<u8>) -> Transaction {
+    let wallet_address = Pubkey::new_unique();
+    let list_config_address = context.create_list(Mode::Allow);
+    let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
+        &list_config_address,
+        &wallet_address,
+    );
+
+    let mut ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config_address)
+        .wallet(wallet_address)
+        .wallet_entry(wallet_entry)
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(false)
+        .instruction();
+    ix.data = data;
+
+    Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    )
+}
+
+#[tokio::test]
+async fn adds_wallet_with_v0_data() {
+    let mut context = TestContext::new();
+
+    let tx = add_wallet_ix_with_data(&mut context, vec![2, 0]);
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn adds_wallet_with_unversioned_data() {
+    let mut context = TestContext::new();
+
+    // clients predating the version header send the discriminator alone
+    let tx = add_wallet_ix_with_data(&mut context, vec![2]);
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_add_wallet_with_unknown_data_version() {
+    let mut context = TestContext::new();
+
+    let tx = add_wallet_ix_with_data(&mut context, vec![2, 99]);
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let err = res.err().unwrap();
+    assert_eq!(
+        err.err,
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
+}
+
 #[tokio::test]
 async fn removes_wallet() {
     let mut context = TestContext::new();
@@ -239,6 +446,43 @@ async fn removes_wallet() {
     assert!(wallet_entry.is_none());
 }
 
+#[tokio::test]
+async fn removes_wallet_with_return_data() {
+    let mut context = TestContext::new();
+
+    let list_config_address = context.create_list(Mode::Allow);
+    let _ = context.add_wallet_to_list(&list_config_address, &Pubkey::new_unique());
+    let wallet_entry = context.add_wallet_to_list(&list_config_address, &Pubkey::new_unique());
+    let entry_lamports = context.vm.get_account(&wallet_entry).unwrap().lamports;
+
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config_address)
+        .wallet_entry(wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let return_data = res.unwrap().return_data;
+    assert_eq!(
+        return_data.program_id,
+        token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID
+    );
+
+    let data = RemoveWalletReturnData::from_bytes(&return_data.data).unwrap();
+    assert_eq!(data.reclaimed_lamports, entry_lamports);
+    assert_eq!(data.recipient, context.auth.pubkey());
+    assert_eq!(data.wallets_count, 1);
+}
+
 #[tokio::test]
 async fn setup_list_extra_metas() {
     let mut context = TestContext::new();
@@ -274,6 +518,7 @@ async fn setup_list_extra_metas() {
     let wallet = solana_keypair::Keypair::new();
     let user_pubkey = wallet.pubkey();
     let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
         &list_config_address,
         &wallet.pubkey(),
     );
@@ -351,14 +596,17 @@ async fn setup_list_extra_metas_with_multiple_lists() {
     let wallet = solana_keypair::Keypair::new();
     let user_pubkey = wallet.pubkey();
     let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
         &list_config_address,
         &wallet.pubkey(),
     );
     let (wallet_entry2, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
         &list_config_address_2,
         &wallet.pubkey(),
     );
     let (wallet_entry3, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
         &list_config_address_3,
         &wallet.pubkey(),
     );
@@ -1,5 +1,7 @@
 pub mod program_test;
-use solana_sdk::signer::Signer;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
 use token_acl_gate_client::types::Mode;
 
 use crate::program_test::TestContext;
@@ -35,3 +37,75 @@ async fn fails_to_thaw_blocked_wallet() {
     let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
     assert!(res.is_err());
 }
+
+#[tokio::test]
+async fn fails_to_thaw_with_wallet_entry_as_list() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let other = solana_keypair::Keypair::new();
+    let other_entry = context.add_wallet_to_list(&list_config, &other.pubkey());
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
+        &list_config,
+        &wallet.pubkey(),
+    );
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(other_entry, wallet_entry)]);
+    assert!(res.is_err());
+
+    let err = res.err().unwrap();
+    assert_eq!(
+        err.err,
+        TransactionError::InstructionError(0, InstructionError::Custom(16))
+    );
+}
+
+#[tokio::test]
+async fn skips_entry_data_of_system_account() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    // a system account holding the data of a block entry is not a block entry
+    let mut account = context.vm.get_account(&wallet_entry).unwrap();
+    account.owner = Pubkey::default();
+    context.vm.set_account(wallet_entry, account).unwrap();
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_thaw_with_program_owned_block_entry() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
@@ -0,0 +1,207 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    clock::Clock,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_discriminator::SplDiscriminate;
+use token_acl_gate_client::{
+    accounts::WalletEntry, programs::TOKEN_ACL_GATE_PROGRAM_ID, types::Mode,
+};
+use token_acl_interface::instruction::CanFreezePermissionlessInstruction;
+
+use crate::program_test::TestContext;
+
+const NOT_BLOCKED: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(37));
+
+/// Lists of `modes` gating the mint and an owner with a token account.
+fn setup(context: &mut TestContext, modes: &[Mode]) -> (Vec<Pubkey>, Keypair, Pubkey) {
+    let _ = context.setup_token_acl();
+    let lists = modes
+        .iter()
+        .map(|mode| context.create_list(*mode))
+        .collect::<Vec<_>>();
+    let _ = context.setup_extra_metas(&lists);
+    let _ = context.setup_freeze_extra_metas(&lists);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    (lists, wallet, ta)
+}
+
+fn pairs(lists: &[Pubkey], wallet: &Pubkey) -> Vec<(Pubkey, Pubkey)> {
+    lists
+        .iter()
+        .map(|list| (*list, WalletEntry::find_pda(&[0; 16], list, wallet).0))
+        .collect()
+}
+
+#[tokio::test]
+async fn freezes_blocked_wallet() {
+    let mut context = TestContext::new();
+    let (lists, wallet, ta) = setup(&mut context, &[Mode::Block]);
+    let _ = context.add_wallet_to_list(&lists[0], &wallet.pubkey());
+
+    let res =
+        context.can_freeze_permissionless(&wallet.pubkey(), &ta, &pairs(&lists, &wallet.pubkey()));
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn denies_freeze_of_wallet_not_blocked() {
+    let mut context = TestContext::new();
+    let (lists, wallet, ta) = setup(&mut context, &[Mode::Block]);
+
+    let res =
+        context.can_freeze_permissionless(&wallet.pubkey(), &ta, &pairs(&lists, &wallet.pubkey()));
+    assert_eq!(res.unwrap_err().err, NOT_BLOCKED);
+}
+
+#[tokio::test]
+async fn freezes_once_any_block_list_blocks() {
+    let mut context = TestContext::new();
+    let (lists, wallet, ta) = setup(&mut context, &[Mode::Allow, Mode::Block, Mode::Block]);
+    let _ = context.add_wallet_to_list(&lists[0], &wallet.pubkey());
+    let _ = context.add_wallet_to_list(&lists[2], &wallet.pubkey());
+
+    let res =
+        context.can_freeze_permissionless(&wallet.pubkey(), &ta, &pairs(&lists, &wallet.pubkey()));
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn allow_lists_do_not_gate_freezes() {
+    let mut context = TestContext::new();
+    let (lists, wallet, ta) = setup(&mut context, &[Mode::Allow]);
+
+    // neither without an entry nor with one
+    let res =
+        context.can_freeze_permissionless(&wallet.pubkey(), &ta, &pairs(&lists, &wallet.pubkey()));
+    assert_eq!(res.unwrap_err().err, NOT_BLOCKED);
+
+    let _ = context.add_wallet_to_list(&lists[0], &wallet.pubkey());
+    context.vm.expire_blockhash();
+    let res =
+        context.can_freeze_permissionless(&wallet.pubkey(), &ta, &pairs(&lists, &wallet.pubkey()));
+    assert_eq!(res.unwrap_err().err, NOT_BLOCKED);
+}
+
+#[tokio::test]
+async fn rejects_another_wallets_block_entry() {
+    let mut context = TestContext::new();
+    let (lists, wallet, ta) = setup(&mut context, &[Mode::Block]);
+
+    let blocked = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&lists[0], &blocked);
+
+    let res = context.can_freeze_permissionless(&wallet.pubkey(), &ta, &pairs(&lists, &blocked));
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
+
+#[tokio::test]
+async fn denies_freeze_once_the_block_expires() {
+    let mut context = TestContext::new();
+    let (lists, wallet, ta) = setup(&mut context, &[Mode::Block]);
+
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let res = context.try_add_wallet_to_list_with_expiry(&lists[0], &wallet.pubkey(), now + 60);
+    assert!(res.is_ok());
+
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp = now + 60;
+    context.vm.set_sysvar::<Clock>(&clock);
+
+    let res =
+        context.can_freeze_permissionless(&wallet.pubkey(), &ta, &pairs(&lists, &wallet.pubkey()));
+    assert_eq!(res.unwrap_err().err, NOT_BLOCKED);
+}
+
+#[tokio::test]
+async fn writes_the_freeze_extra_metas() {
+    let mut context = TestContext::new();
+    let _ = setup(&mut context, &[Mode::Allow, Mode::Block]);
+
+    let thaw_extra_metas = token_acl_interface::get_thaw_extra_account_metas_address(
+        &context.token.mint,
+        &TOKEN_ACL_GATE_PROGRAM_ID,
+    );
+    let freeze_extra_metas = token_acl_interface::get_freeze_extra_account_metas_address(
+        &context.token.mint,
+        &TOKEN_ACL_GATE_PROGRAM_ID,
+    );
+    let thaw = context.vm.get_account(&thaw_extra_metas).unwrap();
+    let freeze = context.vm.get_account(&freeze_extra_metas).unwrap();
+
+    // the same metas, under the freeze instruction's discriminator
+    assert_eq!(freeze.owner, TOKEN_ACL_GATE_PROGRAM_ID);
+    assert_eq!(
+        &freeze.data[..8],
+        CanFreezePermissionlessInstruction::SPL_DISCRIMINATOR_SLICE
+    );
+    assert_eq!(freeze.data[8..], thaw.data[8..]);
+}
+
+#[tokio::test]
+async fn rejects_the_thaw_extra_metas() {
+    let mut context = TestContext::new();
+    let (lists, wallet, ta) = setup(&mut context, &[Mode::Block]);
+    let _ = context.add_wallet_to_list(&lists[0], &wallet.pubkey());
+
+    let mut ix = context.can_thaw_permissionless_instruction(
+        &context.auth.pubkey(),
+        &wallet.pubkey(),
+        &ta,
+        &pairs(&lists, &wallet.pubkey()),
+    );
+    ix.data = CanFreezePermissionlessInstruction::SPL_DISCRIMINATOR_SLICE.to_vec();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert_eq!(
+        context.vm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(9))
+    );
+}
+
+#[tokio::test]
+async fn thaw_setup_leaves_the_freeze_extra_metas_unset() {
+    let mut context = TestContext::new();
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let freeze_extra_metas = token_acl_interface::get_freeze_extra_account_metas_address(
+        &context.token.mint,
+        &TOKEN_ACL_GATE_PROGRAM_ID,
+    );
+    assert!(context.vm.get_account(&freeze_extra_metas).is_none());
+
+    // freezes stay gated off until the freeze extra metas are written
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let _ = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let list_pairs = pairs(&[list_config], &wallet.pubkey());
+    let res = context.can_freeze_permissionless(&wallet.pubkey(), &ta, &list_pairs);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(9))
+    );
+
+    let _ = context.setup_freeze_extra_metas(&[list_config]);
+    context.vm.expire_blockhash();
+    assert!(context
+        .can_freeze_permissionless(&wallet.pubkey(), &ta, &list_pairs)
+        .is_ok());
+}
@@ -0,0 +1,154 @@
+pub mod program_test;
+use solana_pubkey::Pubkey;
+use solana_sdk::{account::ReadableAccount, signer::Signer, transaction::Transaction};
+use token_acl_gate_client::{client::RemoveWalletReturnData, types::Mode};
+
+use crate::program_test::TestContext;
+
+/// Simulates `tx` and returns the data left in `address` right after the
+/// instruction closed it, before the runtime drops the account.
+fn closed_account_data(context: &TestContext, tx: Transaction, address: &Pubkey) -> Vec<u8> {
+    let res = context.vm.simulate_transaction(tx);
+    assert!(res.is_ok());
+
+    let (_, account) = res
+        .unwrap()
+        .post_accounts
+        .into_iter()
+        .find(|(key, _)| key == address)
+        .unwrap();
+    assert_eq!(account.lamports(), 0);
+
+    account.data().to_vec()
+}
+
+#[tokio::test]
+async fn clears_wallet_entry_discriminator_on_remove() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config)
+        .wallet_entry(wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+
+    let data = closed_account_data(&context, tx, &wallet_entry);
+    assert_eq!(data.first().copied().unwrap_or_default(), 0);
+    assert!(data.iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn clears_list_config_discriminator_on_delete() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+
+    let ix = token_acl_gate_client::instructions::DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+
+    let data = closed_account_data(&context, tx, &list_config);
+    assert_eq!(data.first().copied().unwrap_or_default(), 0);
+    assert!(data.iter().all(|byte| *byte == 0));
+}
+
+#[tokio::test]
+async fn refunds_exact_wallet_entry_balance_on_remove() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let entry_lamports = context.vm.get_account(&wallet_entry).unwrap().lamports;
+    let authority_lamports = context.vm.get_balance(&context.auth.pubkey()).unwrap();
+
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config)
+        .wallet_entry(wallet_entry)
+        .instruction();
+
+    // a separate fee payer keeps fees out of the authority's balance
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.token.auth.pubkey()),
+        &[
+            context.token.auth.insecure_clone(),
+            context.auth.insecure_clone(),
+        ],
+        context.vm.latest_blockhash(),
+    );
+
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    assert!(context.vm.get_account(&wallet_entry).is_none());
+    assert_eq!(
+        context.vm.get_balance(&context.auth.pubkey()).unwrap(),
+        authority_lamports + entry_lamports
+    );
+}
+
+#[tokio::test]
+async fn refunds_dust_above_rent_on_remove() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    // lamports sent to the entry after its creation are refunded too
+    let mut entry = context.vm.get_account(&wallet_entry).unwrap();
+    entry.lamports += 1_234;
+    context.vm.set_account(wallet_entry, entry.clone()).unwrap();
+
+    let list_lamports = context.vm.get_balance(&list_config).unwrap();
+    let authority_lamports = context.vm.get_balance(&context.auth.pubkey()).unwrap();
+
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config)
+        .wallet_entry(wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.token.auth.pubkey()),
+        &[
+            context.token.auth.insecure_clone(),
+            context.auth.insecure_clone(),
+        ],
+        context.vm.latest_blockhash(),
+    );
+
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let data = RemoveWalletReturnData::from_bytes(&res.unwrap().return_data.data).unwrap();
+    assert_eq!(data.reclaimed_lamports, entry.lamports);
+
+    // the lamports moved from the entry to the authority, nothing else changed
+    assert!(context.vm.get_account(&wallet_entry).is_none());
+    assert_eq!(
+        context.vm.get_balance(&context.auth.pubkey()).unwrap(),
+        authority_lamports + entry.lamports
+    );
+    assert_eq!(context.vm.get_balance(&list_config).unwrap(), list_lamports);
+}
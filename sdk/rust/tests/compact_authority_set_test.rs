@@ -0,0 +1,147 @@
+pub mod program_test;
+use borsh::BorshSerialize;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{AuthoritySet, ListConfig},
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+const ADD: u16 = 1 << 0;
+const REMOVE: u16 = 1 << 1;
+
+/// Creates the authority set of a new list, then overwrites its delegates
+/// with `entries`, as an older program version could have left them.
+fn create_authority_set(context: &mut TestContext, entries: &[(Pubkey, u16)]) -> (Pubkey, Pubkey) {
+    let list_config = context.create_list(Mode::Allow);
+    let (authority_set, _) = AuthoritySet::find_pda(&list_config);
+
+    let ix = token_acl_gate_client::instructions::SetDelegateBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .authority_set(authority_set)
+        .delegate(Pubkey::new_unique())
+        .permissions(ADD)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+
+    let mut account = context.vm.get_account(&authority_set).unwrap();
+    let mut set = AuthoritySet::from_bytes(&account.data).unwrap();
+    set.len = entries.len() as u8;
+    set.delegates = [Pubkey::default(); 8];
+    set.permissions = [0; 8];
+    for (i, (delegate, permissions)) in entries.iter().enumerate() {
+        set.delegates[i] = *delegate;
+        set.permissions[i] = *permissions;
+    }
+    account.data = set.try_to_vec().unwrap();
+    assert!(context.vm.set_account(authority_set, account).is_ok());
+
+    (list_config, authority_set)
+}
+
+fn compact_authority_set(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    authority_set: &Pubkey,
+) -> TransactionResult {
+    let ix = token_acl_gate_client::instructions::CompactAuthoritySetBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(*list_config)
+        .authority_set(*authority_set)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), authority.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn get_authority_set(context: &TestContext, authority_set: &Pubkey) -> AuthoritySet {
+    let account = context.vm.get_account(authority_set).unwrap();
+    AuthoritySet::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn drops_duplicate_and_default_keys() {
+    let mut context = TestContext::new();
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+    let (list_config, authority_set) = create_authority_set(
+        &mut context,
+        &[
+            (first, ADD),
+            (Pubkey::default(), ADD | REMOVE),
+            (second, REMOVE),
+            (first, ADD | REMOVE),
+            (second, REMOVE),
+        ],
+    );
+
+    let authority = context.auth.insecure_clone();
+    let res = compact_authority_set(&mut context, &authority, &list_config, &authority_set);
+    assert!(res.is_ok());
+    assert_eq!(res.unwrap().return_data.data, vec![2]);
+
+    // the first entry of each key is kept, in order
+    let set = get_authority_set(&context, &authority_set);
+    assert_eq!(set.len, 2);
+    assert_eq!(set.delegates[..2], [first, second]);
+    assert_eq!(set.permissions[..2], [ADD, REMOVE]);
+    assert!(set.delegates[2..].iter().all(|d| *d == Pubkey::default()));
+    assert!(set.permissions[2..].iter().all(|p| *p == 0));
+
+    // the list authority is untouched
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.authority, context.auth.pubkey());
+}
+
+#[tokio::test]
+async fn keeps_a_tidy_set() {
+    let mut context = TestContext::new();
+    let entries = [(Pubkey::new_unique(), ADD), (Pubkey::new_unique(), REMOVE)];
+    let (list_config, authority_set) = create_authority_set(&mut context, &entries);
+    let before = get_authority_set(&context, &authority_set);
+
+    let authority = context.auth.insecure_clone();
+    let res = compact_authority_set(&mut context, &authority, &list_config, &authority_set);
+    assert_eq!(res.unwrap().return_data.data, vec![2]);
+    assert_eq!(get_authority_set(&context, &authority_set), before);
+}
+
+#[tokio::test]
+async fn rejects_non_authority() {
+    let mut context = TestContext::new();
+    let delegate = Keypair::new();
+    let (list_config, authority_set) = create_authority_set(
+        &mut context,
+        &[(delegate.pubkey(), ADD), (delegate.pubkey(), ADD)],
+    );
+
+    let res = compact_authority_set(&mut context, &delegate, &list_config, &authority_set);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+    assert_eq!(get_authority_set(&context, &authority_set).len, 2);
+}
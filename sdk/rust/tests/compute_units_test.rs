@@ -0,0 +1,72 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::TestContext;
+
+// Compute units CanThawPermissionless may use for a single list/wallet pair,
+// per mode. A change pushing a mode over its ceiling fails here; raise the
+// ceiling only when the extra cost is intended.
+const ALLOW_CEILING: u64 = 10_000;
+const BLOCK_CEILING: u64 = 10_000;
+const ALLOW_ALL_EOAS_ON_CURVE_CEILING: u64 = 10_000;
+const ALLOW_ALL_EOAS_OFF_CURVE_CEILING: u64 = 12_000;
+
+/// Thaws a token account of `owner`, an off-curve address when `None`, against
+/// a single `mode` list, adding the owner to the list first when `listed` is
+/// set, and returns the compute units used.
+fn can_thaw_compute_units(mode: Mode, owner: Option<&Keypair>, listed: bool) -> u64 {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(mode);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let (owner, ta) = match owner {
+        Some(owner) => (owner.pubkey(), context.create_token_account(owner)),
+        None => {
+            let (owner, _) = Pubkey::find_program_address(&[b"owner"], &Pubkey::new_unique());
+            (owner, context.create_token_account_from_pubkey(&owner))
+        }
+    };
+
+    let wallet_entry = if listed {
+        context.add_wallet_to_list(&list_config, &owner)
+    } else {
+        WalletEntry::find_pda(&context.list_namespace(&list_config), &list_config, &owner).0
+    };
+
+    let res = context.can_thaw_permissionless(&owner, &ta, &[(list_config, wallet_entry)]);
+    res.unwrap().compute_units_consumed
+}
+
+#[tokio::test]
+async fn allow_stays_under_ceiling() {
+    let units = can_thaw_compute_units(Mode::Allow, Some(&Keypair::new()), true);
+    assert!(units <= ALLOW_CEILING, "{units} > {ALLOW_CEILING}");
+}
+
+#[tokio::test]
+async fn block_stays_under_ceiling() {
+    let units = can_thaw_compute_units(Mode::Block, Some(&Keypair::new()), false);
+    assert!(units <= BLOCK_CEILING, "{units} > {BLOCK_CEILING}");
+}
+
+#[tokio::test]
+async fn allow_all_eoas_on_curve_stays_under_ceiling() {
+    let units = can_thaw_compute_units(Mode::AllowAllEoas, Some(&Keypair::new()), false);
+    assert!(
+        units <= ALLOW_ALL_EOAS_ON_CURVE_CEILING,
+        "{units} > {ALLOW_ALL_EOAS_ON_CURVE_CEILING}"
+    );
+}
+
+#[tokio::test]
+async fn allow_all_eoas_off_curve_stays_under_ceiling() {
+    let units = can_thaw_compute_units(Mode::AllowAllEoas, None, true);
+    assert!(
+        units <= ALLOW_ALL_EOAS_OFF_CURVE_CEILING,
+        "{units} > {ALLOW_ALL_EOAS_OFF_CURVE_CEILING}"
+    );
+}
@@ -0,0 +1,281 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::AccountMeta;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    clock::Clock,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{AuthoritySet, ListConfig},
+    client::PolicyUpdate,
+    types::Mode,
+};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const ADD: u16 = 1 << 0;
+const CONFIGURE: u16 = 1 << 5;
+
+fn configure_policy(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    update: &PolicyUpdate,
+) -> TransactionResult {
+    let (authority_set, _) = AuthoritySet::find_pda(list_config);
+
+    let mut builder = update.builder();
+    builder
+        .authority(authority.pubkey())
+        .list_config(*list_config);
+
+    // delegates pass the authority set and sign next to the payer
+    let mut signers = vec![context.auth.insecure_clone()];
+    if authority.pubkey() != context.auth.pubkey() {
+        builder.add_remaining_account(AccountMeta::new_readonly(authority_set, false));
+        signers.push(authority.insecure_clone());
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &[builder.instruction()],
+        Some(&context.auth.pubkey()),
+        &signers,
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn set_delegate(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    delegate: &Pubkey,
+    permissions: u16,
+) {
+    let (authority_set, _) = AuthoritySet::find_pda(list_config);
+
+    let ix = token_acl_gate_client::instructions::SetDelegateBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .authority_set(authority_set)
+        .delegate(*delegate)
+        .permissions(permissions)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
+fn get_list_config(context: &TestContext, list_config: &Pubkey) -> ListConfig {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn updates_only_selected_parameters() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            transfer_hook_check: 1,
+            require_nonzero_balance: true,
+            ..Default::default()
+        },
+    );
+    let before = get_list_config(&context, &list_config);
+
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 60;
+    context.vm.set_sysvar::<Clock>(&clock);
+
+    let authority = context.auth.insecure_clone();
+    let res = configure_policy(
+        &mut context,
+        &authority,
+        &list_config,
+        &PolicyUpdate {
+            appeal_period_secs: Some(3600),
+            allow_threshold: Some(2),
+            ..Default::default()
+        },
+    );
+    assert!(res.is_ok());
+
+    let after = get_list_config(&context, &list_config);
+    assert_eq!(after.appeal_period_secs, 3600);
+    assert_eq!(after.allow_threshold, 2);
+    assert_eq!(after.updated_at, clock.unix_timestamp);
+    assert_eq!(after.last_actor, authority.pubkey());
+
+    // everything else is left as it was
+    assert_eq!(after.transfer_hook_check, before.transfer_hook_check);
+    assert_eq!(
+        after.require_nonzero_balance,
+        before.require_nonzero_balance
+    );
+    assert_eq!(after.enroll_window_secs, before.enroll_window_secs);
+    assert_eq!(after.allowed_window_start, before.allowed_window_start);
+    assert_eq!(after.allowed_window_end, before.allowed_window_end);
+    assert_eq!(
+        after.require_authority_cosign,
+        before.require_authority_cosign
+    );
+}
+
+#[tokio::test]
+async fn stamps_list_on_creation() {
+    let mut context = TestContext::new();
+
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let list_config = context.create_list(Mode::Block);
+
+    let list = get_list_config(&context, &list_config);
+    assert_eq!(list.updated_at, now);
+    assert_eq!(list.last_actor, context.auth.pubkey());
+}
+
+#[tokio::test]
+async fn rejects_invalid_selected_value() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+
+    // thresholds are only valid on allow lists
+    let authority = context.auth.insecure_clone();
+    let res = configure_policy(
+        &mut context,
+        &authority,
+        &list_config,
+        &PolicyUpdate {
+            appeal_period_secs: Some(3600),
+            allow_threshold: Some(2),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+    assert_eq!(
+        get_list_config(&context, &list_config).appeal_period_secs,
+        0
+    );
+}
+
+#[tokio::test]
+async fn delegate_needs_configure_permission() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+    let delegate = Keypair::new();
+    let update = PolicyUpdate {
+        appeal_period_secs: Some(3600),
+        ..Default::default()
+    };
+
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), ADD);
+    let res = configure_policy(&mut context, &delegate, &list_config, &update);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), CONFIGURE);
+    let res = configure_policy(&mut context, &delegate, &list_config, &update);
+    assert!(res.is_ok());
+    assert_eq!(
+        get_list_config(&context, &list_config).last_actor,
+        delegate.pubkey()
+    );
+}
+
+#[tokio::test]
+async fn sets_max_wallets_and_manager_together() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let manager = Keypair::new();
+
+    let authority = context.auth.insecure_clone();
+    let res = configure_policy(
+        &mut context,
+        &authority,
+        &list_config,
+        &PolicyUpdate {
+            max_wallets: Some(1),
+            manager: Some(manager.pubkey()),
+            ..Default::default()
+        },
+    );
+    assert!(res.is_ok());
+
+    let list = get_list_config(&context, &list_config);
+    assert_eq!(list.max_wallets, 1);
+    assert_eq!(list.manager, manager.pubkey());
+
+    // the cap applies to the manager's additions too
+    let wallets = [Pubkey::new_unique(), Pubkey::new_unique()];
+    for (i, wallet) in wallets.iter().enumerate() {
+        let (wallet_entry, _) =
+            token_acl_gate_client::accounts::WalletEntry::find_pda(&[0; 16], &list_config, wallet);
+        let ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
+            .authority(manager.pubkey())
+            .payer(context.auth.pubkey())
+            .list_config(list_config)
+            .wallet(*wallet)
+            .wallet_entry(wallet_entry)
+            .expires_at(0)
+            .label([0; 32])
+            .idempotent(false)
+            .instruction();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.auth.pubkey()),
+            &[context.auth.insecure_clone(), manager.insecure_clone()],
+            context.vm.latest_blockhash(),
+        );
+        let res = context.vm.send_transaction(tx);
+        if i == 0 {
+            assert!(res.is_ok());
+        } else {
+            assert_eq!(
+                res.unwrap_err().err,
+                TransactionError::InstructionError(0, InstructionError::Custom(35))
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn delegate_cannot_set_manager() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let delegate = Keypair::new();
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), CONFIGURE);
+
+    let res = configure_policy(
+        &mut context,
+        &delegate,
+        &list_config,
+        &PolicyUpdate {
+            max_wallets: Some(1),
+            manager: Some(delegate.pubkey()),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+
+    let list = get_list_config(&context, &list_config);
+    assert_eq!(list.max_wallets, 0);
+    assert_eq!(list.manager, Pubkey::default());
+}
@@ -0,0 +1,54 @@
+pub mod program_test;
+use solana_sdk::signer::Signer;
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::TestContext;
+
+fn count_events(logs: &[String]) -> usize {
+    logs.iter()
+        .filter(|log| log.starts_with("Program data: "))
+        .count()
+}
+
+#[tokio::test]
+async fn logs_an_event_per_allowing_list() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let allow_list = context.create_list(Mode::Allow);
+    let block_list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[allow_list, block_list]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let allow_entry = context.add_wallet_to_list(&allow_list, &wallet.pubkey());
+    let block_entry = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
+        &block_list,
+        &wallet.pubkey(),
+    )
+    .0;
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.can_thaw_permissionless(
+        &wallet.pubkey(),
+        &ta,
+        &[(allow_list, allow_entry), (block_list, block_entry)],
+    );
+    assert_eq!(count_events(&res.unwrap().logs), 2);
+}
+
+#[tokio::test]
+async fn logs_an_event_for_the_denying_list() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let block_list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[block_list]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let block_entry = context.add_wallet_to_list(&block_list, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(block_list, block_entry)]);
+    assert_eq!(count_events(&res.unwrap_err().meta.logs), 1);
+}
@@ -0,0 +1,150 @@
+pub mod program_test;
+use solana_sdk::{clock::Clock, pubkey::Pubkey, signer::Signer};
+use token_acl_gate_client::{
+    accounts::WalletEntry,
+    client::{explain_thaw_denial, DenialReason},
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+#[tokio::test]
+async fn explains_allow_list_missing_entry() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet.pubkey());
+    let pairs = [(list_config, wallet_entry)];
+    let diagnosis = context.diagnose_thaw(&wallet.pubkey(), &ta, &pairs);
+    let explanation = explain_thaw_denial(&pairs, &diagnosis, |pubkey| {
+        context.vm.get_account(pubkey).map(|a| a.data)
+    })
+    .unwrap();
+
+    assert_eq!(explanation.list, Some(list_config));
+    assert_eq!(explanation.mode, Some(Mode::Allow));
+    assert_eq!(explanation.reason, DenialReason::MissingAllowEntry);
+    assert_eq!(explanation.error_code(), 2);
+    assert_eq!(
+        explanation.to_string(),
+        format!(
+            "denied by list {} in Allow mode because no allow entry exists for the owner, error code 2",
+            list_config
+        )
+    );
+}
+
+#[tokio::test]
+async fn explains_block_list_present_entry() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let allow_list = context.create_list(Mode::Allow);
+    let block_list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[allow_list, block_list]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let _ = context.add_wallet_to_list(&allow_list, &wallet.pubkey());
+    let wallet_entry = context.add_wallet_to_list(&block_list, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+
+    // the block list is reported first, whatever the pair order
+    let (allow_entry, _) = WalletEntry::find_pda(&[0; 16], &allow_list, &wallet.pubkey());
+    let pairs = [(allow_list, allow_entry), (block_list, wallet_entry)];
+    let diagnosis = context.diagnose_thaw(&wallet.pubkey(), &ta, &pairs);
+    let explanation = explain_thaw_denial(&pairs, &diagnosis, |pubkey| {
+        context.vm.get_account(pubkey).map(|a| a.data)
+    })
+    .unwrap();
+
+    assert_eq!(explanation.list, Some(block_list));
+    assert_eq!(explanation.wallet_entry, Some(wallet_entry));
+    assert_eq!(explanation.mode, Some(Mode::Block));
+    assert_eq!(explanation.reason, DenialReason::BlockEntryPresent);
+    assert_eq!(
+        explanation.to_string(),
+        format!(
+            "denied by list {} in Block mode because an active block entry exists, error code 2",
+            block_list
+        )
+    );
+}
+
+#[tokio::test]
+async fn explains_nothing_when_thaw_is_allowed() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet = solana_keypair::Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+
+    let ta = context.create_token_account(&wallet);
+
+    let pairs = [(list_config, wallet_entry)];
+    let diagnosis = context.diagnose_thaw(&wallet.pubkey(), &ta, &pairs);
+    let explanation = explain_thaw_denial(&pairs, &diagnosis, |pubkey| {
+        context.vm.get_account(pubkey).map(|a| a.data)
+    });
+    assert!(explanation.is_none());
+}
+
+#[tokio::test]
+async fn explains_expired_allow_entry() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    // an expired entry is denied on-chain even though the account exists
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let _ = context.try_add_wallet_to_list_with_expiry(&list_config, &wallet.pubkey(), now + 10);
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet.pubkey());
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp = now + 10;
+    context.vm.set_sysvar(&clock);
+
+    let pairs = [(list_config, wallet_entry)];
+    let diagnosis = context.diagnose_thaw(&wallet.pubkey(), &ta, &pairs);
+    let explanation = explain_thaw_denial(&pairs, &diagnosis, |pubkey| {
+        context.vm.get_account(pubkey).map(|a| a.data)
+    })
+    .unwrap();
+
+    assert_eq!(explanation.reason, DenialReason::MissingAllowEntry);
+    assert_eq!(explanation.error_code(), 2);
+}
+
+#[tokio::test]
+async fn explains_token_account_denial() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Block);
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet.pubkey());
+
+    // the owner passed doesn't own the token account
+    let pairs = [(list_config, wallet_entry)];
+    let diagnosis = context.diagnose_thaw(&Pubkey::new_unique(), &ta, &pairs);
+    let explanation = explain_thaw_denial(&pairs, &diagnosis, |pubkey| {
+        context.vm.get_account(pubkey).map(|a| a.data)
+    })
+    .unwrap();
+
+    assert_eq!(explanation.list, None);
+    assert_eq!(explanation.reason, DenialReason::TokenAccount);
+    assert_eq!(explanation.error_code(), 17);
+}
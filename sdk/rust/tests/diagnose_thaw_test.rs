@@ -0,0 +1,109 @@
+pub mod program_test;
+use litesvm::types::TransactionMetadata;
+use solana_instruction::AccountMeta;
+use solana_keypair::Keypair;
+use solana_program_error::ProgramError;
+use solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::Transaction};
+use token_acl_gate_client::{accounts::WalletEntry, client::DiagnoseThawReturnData, types::Mode};
+
+use crate::program_test::TestContext;
+
+fn diagnose_thaw(
+    context: &mut TestContext,
+    owner: &Pubkey,
+    token_account: &Pubkey,
+    pairs: &[(Pubkey, Pubkey)],
+) -> TransactionMetadata {
+    let ix = token_acl_gate_client::instructions::DiagnoseThawBuilder::new()
+        .token_account(*token_account)
+        .mint(context.token.mint)
+        .owner(*owner)
+        .add_remaining_accounts(
+            &pairs
+                .iter()
+                .flat_map(|(list, wallet_entry)| {
+                    [
+                        AccountMeta::new_readonly(*list, false),
+                        AccountMeta::new_readonly(*wallet_entry, false),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx).unwrap()
+}
+
+fn logged_verdict(meta: &TransactionMetadata, list: &Pubkey) -> Option<u64> {
+    let prefix = format!("Program log: List {} verdict: ", list);
+    meta.logs
+        .iter()
+        .find_map(|log| log.strip_prefix(&prefix).map(|code| code.parse().unwrap()))
+}
+
+#[tokio::test]
+async fn reports_verdict_for_every_list() {
+    let mut context = TestContext::new();
+
+    let allowing_list = context.create_list(Mode::Allow);
+    let denying_list = context.create_list(Mode::Allow);
+    let block_list = context.create_list(Mode::Block);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let allow_entry = context.add_wallet_to_list(&allowing_list, &wallet.pubkey());
+    let block_entry = context.add_wallet_to_list(&block_list, &wallet.pubkey());
+    let (missing_entry, _) = WalletEntry::find_pda(&[0; 16], &denying_list, &wallet.pubkey());
+
+    let meta = diagnose_thaw(
+        &mut context,
+        &wallet.pubkey(),
+        &ta,
+        &[
+            (allowing_list, allow_entry),
+            (denying_list, missing_entry),
+            (block_list, block_entry),
+        ],
+    );
+
+    let verdicts = DiagnoseThawReturnData::from_bytes(&meta.return_data.data).unwrap();
+    assert_eq!(verdicts.token_account, None);
+    assert_eq!(
+        verdicts.lists,
+        vec![
+            None,
+            Some(ProgramError::Custom(2)),
+            Some(ProgramError::Custom(2))
+        ]
+    );
+    assert!(!verdicts.is_allowed());
+
+    // logs and return data agree
+    assert_eq!(logged_verdict(&meta, &allowing_list), Some(0));
+    assert_eq!(logged_verdict(&meta, &denying_list), Some(2));
+    assert_eq!(logged_verdict(&meta, &block_list), Some(2));
+}
+
+#[tokio::test]
+async fn reports_token_account_verdict() {
+    let mut context = TestContext::new();
+
+    let block_list = context.create_list(Mode::Block);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let other = Keypair::new();
+    let (entry, _) = WalletEntry::find_pda(&[0; 16], &block_list, &other.pubkey());
+
+    let meta = diagnose_thaw(&mut context, &other.pubkey(), &ta, &[(block_list, entry)]);
+
+    let verdicts = DiagnoseThawReturnData::from_bytes(&meta.return_data.data).unwrap();
+    assert_eq!(verdicts.token_account, Some(ProgramError::Custom(17)));
+    assert_eq!(verdicts.lists, vec![None]);
+}
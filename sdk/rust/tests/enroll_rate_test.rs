@@ -0,0 +1,73 @@
+pub mod program_test;
+use solana_sdk::{
+    clock::Clock, instruction::InstructionError, pubkey::Pubkey, transaction::TransactionError,
+};
+use token_acl_gate_client::{accounts::ListConfig, types::Mode};
+
+use crate::program_test::TestContext;
+
+const WINDOW_SECS: u64 = 60;
+const MAX_ENROLLS: u32 = 2;
+
+fn advance_clock(context: &mut TestContext, secs: i64) {
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += secs;
+    context.vm.set_sysvar::<Clock>(&clock);
+    context.vm.expire_blockhash();
+}
+
+#[tokio::test]
+async fn rejects_enrollments_over_the_window_quota() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list_with_enroll_rate(Mode::Allow, WINDOW_SECS, MAX_ENROLLS);
+
+    for _ in 0..MAX_ENROLLS {
+        context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    }
+
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(18))
+    );
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.wallets_count, MAX_ENROLLS as u64);
+    assert_eq!(config.window_enrolls, MAX_ENROLLS);
+}
+
+#[tokio::test]
+async fn allows_enrollments_again_after_the_window_rolls_over() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list_with_enroll_rate(Mode::Allow, WINDOW_SECS, MAX_ENROLLS);
+
+    for _ in 0..MAX_ENROLLS {
+        context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    }
+    assert!(context
+        .try_add_wallet_to_list(&list_config, &Pubkey::new_unique())
+        .is_err());
+
+    advance_clock(&mut context, WINDOW_SECS as i64);
+
+    context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.wallets_count, MAX_ENROLLS as u64 + 1);
+    assert_eq!(config.window_enrolls, 1);
+}
+
+#[tokio::test]
+async fn does_not_limit_enrollments_without_a_window() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+
+    for _ in 0..MAX_ENROLLS + 1 {
+        context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    }
+}
@@ -0,0 +1,66 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{clock::Clock, pubkey::Pubkey, signer::Signer};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::TestContext;
+
+fn get_entry(context: &TestContext, wallet_entry: &Pubkey) -> WalletEntry {
+    let account = context.vm.get_account(wallet_entry).unwrap();
+    WalletEntry::from_bytes(&account.data).unwrap()
+}
+
+fn set_unix_timestamp(context: &mut TestContext, unix_timestamp: i64) {
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp = unix_timestamp;
+    context.vm.set_sysvar::<Clock>(&clock);
+}
+
+#[tokio::test]
+async fn records_creation_time_and_signer() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    set_unix_timestamp(&mut context, 1_750_000_000);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let entry = get_entry(&context, &wallet_entry);
+    assert_eq!(entry.created_at, 1_750_000_000);
+    assert_eq!(entry.added_by, context.auth.pubkey());
+}
+
+#[tokio::test]
+async fn records_creation_of_batched_entries() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    set_unix_timestamp(&mut context, 1_750_000_000);
+    let (res, wallet_entries) = context
+        .try_add_wallets_to_list(&list_config, &[Pubkey::new_unique(), Pubkey::new_unique()]);
+    assert!(res.is_ok());
+
+    for wallet_entry in wallet_entries {
+        let entry = get_entry(&context, &wallet_entry);
+        assert_eq!(entry.created_at, 1_750_000_000);
+        assert_eq!(entry.added_by, context.auth.pubkey());
+    }
+}
+
+#[tokio::test]
+async fn keeps_creation_time_through_thaws() {
+    let mut context = TestContext::new();
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    set_unix_timestamp(&mut context, 1_750_000_000);
+    let wallet = Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    set_unix_timestamp(&mut context, 1_760_000_000);
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+    assert_eq!(get_entry(&context, &wallet_entry).created_at, 1_750_000_000);
+}
@@ -0,0 +1,84 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::TestContext;
+
+fn failed_list(res: litesvm::types::TransactionResult) -> Option<Pubkey> {
+    let err = res.unwrap_err();
+    assert_eq!(
+        err.err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+
+    err.meta.logs.iter().find_map(|log| {
+        log.strip_prefix("Program log: Failed to pass validation for list ")
+            .map(|list| list.parse().unwrap())
+    })
+}
+
+#[tokio::test]
+async fn reports_block_list_denial_before_allow_list() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let allow_list = context.create_list(Mode::Allow);
+    let block_list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[allow_list, block_list]);
+
+    // the wallet is missing from the allow list and blocked by the block list,
+    // so both lists would deny the thaw
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let block_entry = context.add_wallet_to_list(&block_list, &wallet.pubkey());
+    let (allow_entry, _) = WalletEntry::find_pda(&[0; 16], &allow_list, &wallet.pubkey());
+
+    let res = context.can_thaw_permissionless(
+        &wallet.pubkey(),
+        &ta,
+        &[(allow_list, allow_entry), (block_list, block_entry)],
+    );
+    assert_eq!(failed_list(res), Some(block_list));
+
+    context.vm.expire_blockhash();
+    let res = context.can_thaw_permissionless(
+        &wallet.pubkey(),
+        &ta,
+        &[(block_list, block_entry), (allow_list, allow_entry)],
+    );
+    assert_eq!(failed_list(res), Some(block_list));
+}
+
+#[tokio::test]
+async fn evaluates_allow_list_after_block_lists_pass() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let allow_list = context.create_list(Mode::Allow);
+    let block_list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[allow_list, block_list]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (allow_entry, _) = WalletEntry::find_pda(&[0; 16], &allow_list, &wallet.pubkey());
+    let (block_entry, _) = WalletEntry::find_pda(&[0; 16], &block_list, &wallet.pubkey());
+
+    let res = context.can_thaw_permissionless(
+        &wallet.pubkey(),
+        &ta,
+        &[(allow_list, allow_entry), (block_list, block_entry)],
+    );
+    assert_eq!(failed_list(res), Some(allow_list));
+
+    let _ = context.add_wallet_to_list(&allow_list, &wallet.pubkey());
+    context.vm.expire_blockhash();
+    let res = context.can_thaw_permissionless(
+        &wallet.pubkey(),
+        &ta,
+        &[(allow_list, allow_entry), (block_list, block_entry)],
+    );
+    assert!(res.is_ok());
+}
@@ -0,0 +1,185 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    clock::Clock,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{accounts::WalletEntry, client::PolicyUpdate, types::Mode};
+
+use crate::program_test::TestContext;
+
+const TTL: i64 = 3600;
+
+struct ExpiringEntry {
+    list_config: Pubkey,
+    wallet_entry: Pubkey,
+    wallet: Keypair,
+    ta: Pubkey,
+    expires_at: i64,
+}
+
+fn setup_entry(context: &mut TestContext, mode: Mode, ttl: i64) -> ExpiringEntry {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(mode);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let expires_at = if ttl == 0 { 0 } else { now + ttl };
+    let res =
+        context.try_add_wallet_to_list_with_expiry(&list_config, &wallet.pubkey(), expires_at);
+    assert!(res.is_ok());
+
+    let (wallet_entry, _) = WalletEntry::find_pda(
+        &context.list_namespace(&list_config),
+        &list_config,
+        &wallet.pubkey(),
+    );
+    let ta = context.create_token_account(&wallet);
+
+    ExpiringEntry {
+        list_config,
+        wallet_entry,
+        wallet,
+        ta,
+        expires_at,
+    }
+}
+
+fn can_thaw_at(
+    context: &mut TestContext,
+    entry: &ExpiringEntry,
+    unix_timestamp: i64,
+) -> TransactionResult {
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp = unix_timestamp;
+    context.vm.set_sysvar::<Clock>(&clock);
+    context.vm.expire_blockhash();
+
+    context.can_thaw_permissionless(
+        &entry.wallet.pubkey(),
+        &entry.ta,
+        &[(entry.list_config, entry.wallet_entry)],
+    )
+}
+
+fn deny_expired_entries(context: &mut TestContext, list_config: &Pubkey) {
+    let ix = PolicyUpdate {
+        deny_expired_entries: Some(true),
+        ..Default::default()
+    }
+    .builder()
+    .authority(context.auth.pubkey())
+    .list_config(*list_config)
+    .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn stores_expiry_on_entry() {
+    let mut context = TestContext::new();
+    let entry = setup_entry(&mut context, Mode::Allow, TTL);
+
+    let account = context.vm.get_account(&entry.wallet_entry).unwrap();
+    let wallet_entry = WalletEntry::from_bytes(&account.data).unwrap();
+    assert_eq!(wallet_entry.expires_at, entry.expires_at);
+}
+
+#[tokio::test]
+async fn allows_allow_entry_until_just_before_expiry() {
+    let mut context = TestContext::new();
+    let entry = setup_entry(&mut context, Mode::Allow, TTL);
+
+    let res = can_thaw_at(&mut context, &entry, entry.expires_at - 1);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn denies_allow_entry_once_expired() {
+    let mut context = TestContext::new();
+    let entry = setup_entry(&mut context, Mode::Allow, TTL);
+
+    let res = can_thaw_at(&mut context, &entry, entry.expires_at);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn never_expires_entry_without_expiry() {
+    let mut context = TestContext::new();
+    let entry = setup_entry(&mut context, Mode::Allow, 0);
+
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let res = can_thaw_at(&mut context, &entry, now + 100 * TTL);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn blocks_until_block_entry_expires() {
+    let mut context = TestContext::new();
+    let entry = setup_entry(&mut context, Mode::Block, TTL);
+
+    let res = can_thaw_at(&mut context, &entry, entry.expires_at - 1);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+
+    let res = can_thaw_at(&mut context, &entry, entry.expires_at);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn allows_eoa_with_expired_entry_by_default() {
+    let mut context = TestContext::new();
+    let entry = setup_entry(&mut context, Mode::AllowAllEoas, TTL);
+
+    // the expired entry counts as absent, and the mode lets EOAs through
+    let res = can_thaw_at(&mut context, &entry, entry.expires_at);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn denies_eoa_with_expired_entry_when_configured() {
+    let mut context = TestContext::new();
+    let entry = setup_entry(&mut context, Mode::AllowAllEoas, TTL);
+    deny_expired_entries(&mut context, &entry.list_config);
+
+    let res = can_thaw_at(&mut context, &entry, entry.expires_at - 1);
+    assert!(res.is_ok());
+
+    let res = can_thaw_at(&mut context, &entry, entry.expires_at);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+
+    // EOAs without an entry are still let through
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = WalletEntry::find_pda(
+        &context.list_namespace(&entry.list_config),
+        &entry.list_config,
+        &wallet.pubkey(),
+    );
+    let res = context.can_thaw_permissionless(
+        &wallet.pubkey(),
+        &ta,
+        &[(entry.list_config, wallet_entry)],
+    );
+    assert!(res.is_ok());
+}
@@ -0,0 +1,94 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext, TokenAccountDataBuilder};
+
+fn setup_list(context: &mut TestContext, allow_freeze_authority_delegate: bool) -> Pubkey {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            allow_freeze_authority_delegate,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    list_config
+}
+
+/// Thaws an account of a wallet that isn't on the list, delegated to `delegate`.
+fn can_thaw_delegated_to(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    delegate: &Pubkey,
+) -> TransactionResult {
+    let wallet = Keypair::new();
+    let (wallet_entry, _) = WalletEntry::find_pda(
+        &context.list_namespace(list_config),
+        list_config,
+        &wallet.pubkey(),
+    );
+
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey())
+        .delegate(delegate)
+        .immutable_owner()
+        .build();
+    let ta = context.set_token_account(data);
+
+    context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(*list_config, wallet_entry)])
+}
+
+#[tokio::test]
+async fn allows_account_delegated_to_freeze_authority() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, true);
+
+    let freeze_authority = context.token.auth.pubkey();
+    let res = can_thaw_delegated_to(&mut context, &list_config, &freeze_authority);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn validates_account_delegated_to_someone_else() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, true);
+
+    let res = can_thaw_delegated_to(&mut context, &list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn validates_account_delegated_to_thaw_signer() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, true);
+
+    // anyone can sign the thaw, so only the mint's freeze authority counts
+    let signer = context.auth.pubkey();
+    let res = can_thaw_delegated_to(&mut context, &list_config, &signer);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn validates_account_delegated_to_freeze_authority_by_default() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, false);
+
+    let freeze_authority = context.token.auth.pubkey();
+    let res = can_thaw_delegated_to(&mut context, &list_config, &freeze_authority);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
@@ -0,0 +1,295 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::Instruction;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    hash::hashv,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{AuthoritySet, ListConfig, MerkleProof, WalletEntry},
+    client::PolicyUpdate,
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+const BLOCKED: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(2));
+const LIST_FROZEN: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(42));
+
+fn send(context: &mut TestContext, authority: &Keypair, ix: Instruction) -> TransactionResult {
+    // toggling back and forth sends the same transaction
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), authority.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn set_frozen(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    frozen: bool,
+) -> TransactionResult {
+    let ix = token_acl_gate_client::instructions::SetFrozenBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(*list_config)
+        .frozen(frozen)
+        .instruction();
+    send(context, authority, ix)
+}
+
+fn set_paused(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    paused: bool,
+) -> TransactionResult {
+    let ix = token_acl_gate_client::instructions::SetPausedBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(*list_config)
+        .paused(paused)
+        .instruction();
+    send(context, authority, ix)
+}
+
+fn get_list_config(context: &TestContext, list_config: &Pubkey) -> ListConfig {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn pause_wins_over_freeze() {
+    // (paused, frozen) and whether the member can thaw
+    for (paused, frozen, allowed) in [
+        (false, false, true),
+        (true, false, false),
+        (false, true, true),
+        (true, true, false),
+    ] {
+        let mut context = TestContext::new();
+        let authority = context.auth.insecure_clone();
+        let _ = context.setup_token_acl();
+        let list_config = context.create_list(Mode::Allow);
+        let _ = context.setup_extra_metas(&[list_config]);
+
+        let wallet = Keypair::new();
+        let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+        let ta = context.create_token_account(&wallet);
+
+        assert!(set_paused(&mut context, &authority, &list_config, paused).is_ok());
+        assert!(set_frozen(&mut context, &authority, &list_config, frozen).is_ok());
+
+        let config = get_list_config(&context, &list_config);
+        assert_eq!((config.paused, config.frozen), (paused, frozen));
+
+        let res =
+            context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+        if allowed {
+            assert!(res.is_ok(), "paused {paused}, frozen {frozen}");
+        } else {
+            assert_eq!(
+                res.unwrap_err().err,
+                BLOCKED,
+                "paused {paused}, frozen {frozen}"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn rejects_changes_while_frozen() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let list_config = context.create_list(Mode::Allow);
+    let member = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list_config, &member);
+    assert!(set_frozen(&mut context, &authority, &list_config, true).is_ok());
+    let before = get_list_config(&context, &list_config);
+
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert_eq!(res.unwrap_err().err, LIST_FROZEN);
+
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &member);
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(list_config)
+        .wallet_entry(wallet_entry)
+        .instruction();
+    assert_eq!(
+        send(&mut context, &authority, ix).unwrap_err().err,
+        LIST_FROZEN
+    );
+
+    let ix = PolicyUpdate {
+        meter: Some(true),
+        ..Default::default()
+    }
+    .builder()
+    .authority(authority.pubkey())
+    .list_config(list_config)
+    .instruction();
+    assert_eq!(
+        send(&mut context, &authority, ix).unwrap_err().err,
+        LIST_FROZEN
+    );
+
+    let ix = token_acl_gate_client::instructions::UpdateModeBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(list_config)
+        .mode(Mode::Block)
+        .acknowledge(false)
+        .eoa_grace_period_secs(0)
+        .instruction();
+    assert_eq!(
+        send(&mut context, &authority, ix).unwrap_err().err,
+        LIST_FROZEN
+    );
+
+    assert_eq!(get_list_config(&context, &list_config), before);
+
+    // pausing stays available, and unfreezing lets changes through again
+    assert!(set_paused(&mut context, &authority, &list_config, true).is_ok());
+    assert!(set_frozen(&mut context, &authority, &list_config, false).is_ok());
+    context.vm.expire_blockhash();
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn rejects_non_authority() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let res = set_frozen(&mut context, &Keypair::new(), &list_config, true);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+    assert!(!get_list_config(&context, &list_config).frozen);
+}
+
+#[tokio::test]
+async fn rejects_delegation_and_authority_changes_while_frozen() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let list_config = context.create_list(Mode::Allow);
+    let (authority_set, _) = AuthoritySet::find_pda(&list_config);
+    let set_delegate_ix = |delegate: Pubkey| {
+        token_acl_gate_client::instructions::SetDelegateBuilder::new()
+            .authority(authority.pubkey())
+            .payer(authority.pubkey())
+            .list_config(list_config)
+            .authority_set(authority_set)
+            .delegate(delegate)
+            .permissions(1)
+            .instruction()
+    };
+
+    // a delegate and a pending transfer from before the freeze
+    let new_authority = Keypair::new();
+    let ix = set_delegate_ix(Pubkey::new_unique());
+    assert!(send(&mut context, &authority, ix).is_ok());
+    let ix = token_acl_gate_client::instructions::ProposeAuthorityTransferBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(list_config)
+        .new_authority(new_authority.pubkey())
+        .instruction();
+    assert!(send(&mut context, &authority, ix).is_ok());
+    assert!(set_frozen(&mut context, &authority, &list_config, true).is_ok());
+    let before = get_list_config(&context, &list_config);
+    let set_before = context.vm.get_account(&authority_set).unwrap();
+
+    let ix = set_delegate_ix(Pubkey::new_unique());
+    assert_eq!(
+        send(&mut context, &authority, ix).unwrap_err().err,
+        LIST_FROZEN
+    );
+
+    let ix = token_acl_gate_client::instructions::CompactAuthoritySetBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(list_config)
+        .authority_set(authority_set)
+        .instruction();
+    assert_eq!(
+        send(&mut context, &authority, ix).unwrap_err().err,
+        LIST_FROZEN
+    );
+
+    let ix = token_acl_gate_client::instructions::ProposeAuthorityTransferBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(list_config)
+        .new_authority(Pubkey::new_unique())
+        .instruction();
+    assert_eq!(
+        send(&mut context, &authority, ix).unwrap_err().err,
+        LIST_FROZEN
+    );
+
+    let ix = token_acl_gate_client::instructions::AcceptAuthorityTransferBuilder::new()
+        .pending_authority(new_authority.pubkey())
+        .list_config(list_config)
+        .instruction();
+    assert_eq!(
+        send(&mut context, &new_authority, ix).unwrap_err().err,
+        LIST_FROZEN
+    );
+
+    assert_eq!(get_list_config(&context, &list_config), before);
+    assert_eq!(context.vm.get_account(&authority_set).unwrap(), set_before);
+}
+
+#[tokio::test]
+async fn rejects_merkle_proof_changes_while_frozen() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let list_config = context.create_list(Mode::MerkleAllow);
+
+    // a tree of one member, whose leaf is the root and whose proof is empty
+    let member = Keypair::new();
+    let root = hashv(&[&[0], member.pubkey().as_ref()]).to_bytes();
+    let ix = token_acl_gate_client::instructions::UpdateMerkleRootBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(list_config)
+        .merkle_root(root)
+        .instruction();
+    assert!(send(&mut context, &authority, ix).is_ok());
+
+    let (merkle_proof, _) = MerkleProof::find_pda(&list_config, &member.pubkey());
+    let submit_ix = token_acl_gate_client::instructions::SubmitMerkleProofBuilder::new()
+        .payer(authority.pubkey())
+        .list_config(list_config)
+        .member(member.pubkey())
+        .merkle_proof(merkle_proof)
+        .proof(vec![])
+        .instruction();
+    assert!(send(&mut context, &authority, submit_ix.clone()).is_ok());
+    assert!(set_frozen(&mut context, &authority, &list_config, true).is_ok());
+    let proof_before = context.vm.get_account(&merkle_proof).unwrap();
+
+    assert_eq!(
+        send(&mut context, &authority, submit_ix).unwrap_err().err,
+        LIST_FROZEN
+    );
+
+    // neither the member nor the authority can close it
+    for signer in [&member, &authority] {
+        let ix = token_acl_gate_client::instructions::CloseMerkleProofBuilder::new()
+            .authority(signer.pubkey())
+            .list_config(list_config)
+            .merkle_proof(merkle_proof)
+            .instruction();
+        assert_eq!(send(&mut context, signer, ix).unwrap_err().err, LIST_FROZEN);
+    }
+
+    assert_eq!(context.vm.get_account(&merkle_proof).unwrap(), proof_before);
+}
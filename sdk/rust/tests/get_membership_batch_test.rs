@@ -0,0 +1,166 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::AccountMeta;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::WalletEntry, client::Membership, instructions::GetMembershipBatchBuilder, types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+fn get_membership_batch(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    accounts: &[Pubkey],
+) -> TransactionResult {
+    let ix = GetMembershipBatchBuilder::new()
+        .list_config(*list_config)
+        .add_remaining_accounts(
+            &accounts
+                .iter()
+                .map(|account| AccountMeta::new_readonly(*account, false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// The `(wallet_entry, owner)` pairs of `owners`, flattened in order.
+fn pairs(list_config: &Pubkey, owners: &[Pubkey]) -> Vec<Pubkey> {
+    owners
+        .iter()
+        .flat_map(|owner| {
+            [
+                WalletEntry::find_pda(&[0; 16], list_config, owner).0,
+                *owner,
+            ]
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn returns_a_mixed_bitmask() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+
+    // nine owners span two bytes, every third one blocked
+    let owners: Vec<Pubkey> = (0..9).map(|_| Pubkey::new_unique()).collect();
+    for owner in owners.iter().step_by(3) {
+        let _ = context.add_wallet_to_list(&list_config, owner);
+    }
+    let before = context.vm.get_account(&list_config).unwrap();
+
+    let meta =
+        get_membership_batch(&mut context, &list_config, &pairs(&list_config, &owners)).unwrap();
+
+    // owners 0, 3 and 6 are denied, the others allowed, lowest bit first
+    assert_eq!(meta.return_data.data, vec![0b1011_0110, 0b0000_0001]);
+    let membership = Membership::from_batch_return_data(&meta.return_data.data, owners.len());
+    assert_eq!(
+        membership.unwrap(),
+        (0..owners.len())
+            .map(|i| match i % 3 {
+                0 => Membership::Denied,
+                _ => Membership::Allowed,
+            })
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(context.vm.get_account(&list_config).unwrap(), before);
+}
+
+#[tokio::test]
+async fn matches_single_membership_on_an_allow_list() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let member = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list_config, &member);
+    let owners = [Pubkey::new_unique(), member];
+
+    let meta =
+        get_membership_batch(&mut context, &list_config, &pairs(&list_config, &owners)).unwrap();
+
+    assert_eq!(
+        Membership::from_batch_return_data(&meta.return_data.data, owners.len()).unwrap(),
+        vec![Membership::Denied, Membership::Allowed]
+    );
+}
+
+#[tokio::test]
+async fn checks_as_many_owners_as_a_transaction_can_lock() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+
+    // fee payer, program, list config and the pairs: every account lock used
+    let owners: Vec<Pubkey> = (0..GetMembershipBatchBuilder::MAX_OWNERS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    assert_eq!(3 + 2 * owners.len(), 127);
+
+    let meta =
+        get_membership_batch(&mut context, &list_config, &pairs(&list_config, &owners)).unwrap();
+    assert_eq!(
+        Membership::from_batch_return_data(&meta.return_data.data, owners.len()).unwrap(),
+        vec![Membership::Allowed; owners.len()]
+    );
+}
+
+#[tokio::test]
+async fn rejects_more_owners_than_a_transaction_can_lock() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+
+    // repeated pairs don't take more account locks, the count is still capped
+    let owners = vec![Pubkey::new_unique(); GetMembershipBatchBuilder::MAX_OWNERS + 1];
+
+    let res = get_membership_batch(&mut context, &list_config, &pairs(&list_config, &owners));
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
+
+#[tokio::test]
+async fn rejects_an_incomplete_pair() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let mut accounts = pairs(&list_config, &[Pubkey::new_unique()]);
+    accounts.push(Pubkey::new_unique());
+
+    let res = get_membership_batch(&mut context, &list_config, &accounts);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+}
+
+#[tokio::test]
+async fn rejects_another_wallets_entry() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+    let blocked = Pubkey::new_unique();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &blocked);
+
+    let res = get_membership_batch(
+        &mut context,
+        &list_config,
+        &[wallet_entry, Pubkey::new_unique()],
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
@@ -0,0 +1,109 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{accounts::WalletEntry, client::Membership, types::Mode};
+
+use crate::program_test::TestContext;
+
+fn get_membership(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    owner: &Pubkey,
+    wallet_entry: &Pubkey,
+) -> TransactionResult {
+    let ix = token_acl_gate_client::instructions::GetMembershipBuilder::new()
+        .list_config(*list_config)
+        .owner(*owner)
+        .wallet_entry(*wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// Membership of `owner`, checked against its own entry address.
+fn membership(context: &mut TestContext, list_config: &Pubkey, owner: &Pubkey) -> Membership {
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], list_config, owner);
+    let meta = get_membership(context, list_config, owner, &wallet_entry).unwrap();
+
+    Membership::from_return_data(&meta.return_data.data).unwrap()
+}
+
+#[tokio::test]
+async fn reports_allow_list_membership() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let member = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list_config, &member);
+
+    assert_eq!(
+        membership(&mut context, &list_config, &member),
+        Membership::Allowed
+    );
+    assert_eq!(
+        membership(&mut context, &list_config, &Pubkey::new_unique()),
+        Membership::Denied
+    );
+}
+
+#[tokio::test]
+async fn reports_block_list_membership() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+    let blocked = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list_config, &blocked);
+
+    assert_eq!(
+        membership(&mut context, &list_config, &blocked),
+        Membership::Denied
+    );
+    assert_eq!(
+        membership(&mut context, &list_config, &Pubkey::new_unique()),
+        Membership::Allowed
+    );
+}
+
+#[tokio::test]
+async fn leaves_the_list_untouched() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let member = Pubkey::new_unique();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &member);
+    let before = (
+        context.vm.get_account(&list_config).unwrap(),
+        context.vm.get_account(&wallet_entry).unwrap(),
+    );
+
+    let _ = membership(&mut context, &list_config, &member);
+
+    assert_eq!(context.vm.get_account(&list_config).unwrap(), before.0);
+    assert_eq!(context.vm.get_account(&wallet_entry).unwrap(), before.1);
+}
+
+#[tokio::test]
+async fn rejects_another_wallets_entry() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let res = get_membership(
+        &mut context,
+        &list_config,
+        &Pubkey::new_unique(),
+        &wallet_entry,
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
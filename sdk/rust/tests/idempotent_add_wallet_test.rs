@@ -0,0 +1,148 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{AuditLog, ListConfig, WalletEntry},
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+fn add_wallet_ix(
+    context: &TestContext,
+    list_config: &Pubkey,
+    wallet: &Pubkey,
+    idempotent: bool,
+) -> Instruction {
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], list_config, wallet);
+
+    token_acl_gate_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(idempotent)
+        .instruction()
+}
+
+fn send(context: &mut TestContext, ix: Instruction) -> TransactionResult {
+    // retries resend the same instruction, which needs a fresh blockhash here
+    context.vm.expire_blockhash();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn wallets_count(context: &TestContext, list_config: &Pubkey) -> u64 {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap().wallets_count
+}
+
+#[tokio::test]
+async fn adds_the_same_wallet_twice() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let wallet = Pubkey::new_unique();
+
+    let ix = add_wallet_ix(&context, &list_config, &wallet, true);
+    assert!(send(&mut context, ix.clone()).is_ok());
+
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet);
+    let entry = context.vm.get_account(&wallet_entry).unwrap();
+
+    assert!(send(&mut context, ix).is_ok());
+
+    assert_eq!(wallets_count(&context, &list_config), 1);
+    assert_eq!(context.vm.get_account(&wallet_entry).unwrap(), entry);
+}
+
+#[tokio::test]
+async fn fails_to_add_the_same_wallet_twice_without_the_flag() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let wallet = Pubkey::new_unique();
+
+    let ix = add_wallet_ix(&context, &list_config, &wallet, false);
+    assert!(send(&mut context, ix.clone()).is_ok());
+    assert!(send(&mut context, ix).is_err());
+
+    assert_eq!(wallets_count(&context, &list_config), 1);
+}
+
+#[tokio::test]
+async fn retry_is_not_logged_again() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let (audit_log, _) = AuditLog::find_pda(&list_config);
+    let ix = token_acl_gate_client::instructions::CreateAuditLogBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .audit_log(audit_log)
+        .instruction();
+    assert!(send(&mut context, ix).is_ok());
+
+    let mut ix = add_wallet_ix(&context, &list_config, &Pubkey::new_unique(), true);
+    ix.accounts.push(AccountMeta::new(audit_log, false));
+    assert!(send(&mut context, ix.clone()).is_ok());
+    assert!(send(&mut context, ix).is_ok());
+
+    let log = AuditLog::from_bytes(&context.vm.get_account(&audit_log).unwrap().data).unwrap();
+    assert_eq!(log.count, 1);
+}
+
+#[tokio::test]
+async fn rejects_an_invalid_flag() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let mut ix = add_wallet_ix(&context, &list_config, &Pubkey::new_unique(), true);
+    *ix.data.last_mut().unwrap() = 2;
+    assert_eq!(
+        send(&mut context, ix).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+    assert_eq!(wallets_count(&context, &list_config), 0);
+}
+
+#[tokio::test]
+async fn rejects_a_flag_on_version_2_data() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    // v2 data ends with the label, a trailing byte isn't read as the flag
+    let mut ix = add_wallet_ix(&context, &list_config, &Pubkey::new_unique(), true);
+    ix.data[1] = 2;
+    assert_eq!(
+        send(&mut context, ix).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+    assert_eq!(wallets_count(&context, &list_config), 0);
+}
+
+#[tokio::test]
+async fn rejects_a_negative_expiry() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let mut ix = add_wallet_ix(&context, &list_config, &Pubkey::new_unique(), false);
+    ix.data[2..10].copy_from_slice(&(-1i64).to_le_bytes());
+    assert_eq!(
+        send(&mut context, ix).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
@@ -0,0 +1,104 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey, pubkey::Pubkey, signer::Signer,
+    transaction::TransactionError,
+};
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::{TestContext, TokenAccountDataBuilder};
+
+const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+fn setup(context: &mut TestContext) -> (Pubkey, Keypair) {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    (list_config, Keypair::new())
+}
+
+fn can_thaw(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallet: &Pubkey,
+    ta: &Pubkey,
+) -> Result<(), TransactionError> {
+    let (wallet_entry, _) =
+        token_acl_gate_client::accounts::WalletEntry::find_pda(&[0; 16], list_config, wallet);
+
+    context
+        .can_thaw_permissionless(wallet, ta, &[(*list_config, wallet_entry)])
+        .map(|_| ())
+        .map_err(|res| res.err)
+}
+
+#[tokio::test]
+async fn thaws_legacy_token_account() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey()).build_legacy();
+    assert_eq!(data.len(), 165);
+    let ta = context.set_token_account_owned_by(data, &TOKEN_PROGRAM_ID);
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), &ta),
+        Ok(())
+    );
+}
+
+#[tokio::test]
+async fn checks_lists_for_legacy_token_account() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+    let _ = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey()).build_legacy();
+    let ta = context.set_token_account_owned_by(data, &TOKEN_PROGRAM_ID);
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), &ta),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(2)
+        ))
+    );
+}
+
+#[tokio::test]
+async fn fails_legacy_token_account_with_extension_data() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey())
+        .immutable_owner()
+        .build();
+    let ta = context.set_token_account_owned_by(data, &TOKEN_PROGRAM_ID);
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), &ta),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(4)
+        ))
+    );
+}
+
+#[tokio::test]
+async fn still_requires_immutable_owner_for_token_2022() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+
+    // the same base layout owned by token-2022 lacks the extension
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey()).build_legacy();
+    let ta = context.set_token_account(data);
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), &ta),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(10)
+        ))
+    );
+}
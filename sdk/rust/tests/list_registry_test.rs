@@ -0,0 +1,121 @@
+pub mod program_test;
+use solana_instruction::AccountMeta;
+use solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::Transaction};
+use token_acl_gate_client::{accounts::ListRegistry, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext};
+
+fn create_registered_list(context: &mut TestContext) -> Pubkey {
+    context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            register: true,
+            ..Default::default()
+        },
+    )
+}
+
+fn delete_registered_list(context: &mut TestContext, list_config: &Pubkey) {
+    let (list_registry, _) = ListRegistry::find_pda(&context.auth.pubkey());
+
+    let ix = token_acl_gate_client::instructions::DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list_config)
+        .add_remaining_account(AccountMeta::new(list_registry, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+}
+
+fn registered_lists(context: &TestContext) -> Vec<Pubkey> {
+    let (list_registry, _) = ListRegistry::find_pda(&context.auth.pubkey());
+    let account = context.vm.get_account(&list_registry).unwrap();
+    let registry = ListRegistry::from_bytes(&account.data).unwrap();
+
+    registry.lists[..registry.len as usize].to_vec()
+}
+
+#[tokio::test]
+async fn registers_created_lists() {
+    let mut context = TestContext::new();
+
+    let first = create_registered_list(&mut context);
+    let second = create_registered_list(&mut context);
+
+    assert_eq!(registered_lists(&context), vec![first, second]);
+}
+
+#[tokio::test]
+async fn unregisters_deleted_list() {
+    let mut context = TestContext::new();
+
+    let first = create_registered_list(&mut context);
+    let second = create_registered_list(&mut context);
+
+    delete_registered_list(&mut context, &first);
+
+    assert_eq!(registered_lists(&context), vec![second]);
+}
+
+#[tokio::test]
+async fn fails_to_register_list_when_registry_is_full() {
+    let mut context = TestContext::new();
+
+    for _ in 0..32 {
+        create_registered_list(&mut context);
+    }
+    assert_eq!(registered_lists(&context).len(), 32);
+
+    let (list_registry, _) = ListRegistry::find_pda(&context.auth.pubkey());
+    let seed = Pubkey::new_unique();
+    let (list_config, _) =
+        token_acl_gate_client::accounts::ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let ix = token_acl_gate_client::instructions::CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .mode(Mode::Allow)
+        .seed(seed)
+        .namespace([0; 16])
+        .enroll_window_secs(0)
+        .max_enrolls_per_window(0)
+        .key_by_token_account(false)
+        .warmup_secs(0)
+        .token_program(Pubkey::default())
+        .transfer_hook_check(0)
+        .appeal_period_secs(0)
+        .require_nonzero_balance(false)
+        .allowed_window_start(0)
+        .allowed_window_end(0)
+        .require_authority_cosign(false)
+        .allow_threshold(0)
+        .allow_freeze_authority_delegate(false)
+        .strict_block_mode(false)
+        .allow_precedence(false)
+        .sunset_at(0)
+        .max_wallets(0)
+        .allow_multisig_owner(false)
+        .owner_extensions(0)
+        .add_remaining_account(AccountMeta::new(list_registry, false))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    // the rejected list is not created either
+    assert!(context.vm.get_account(&list_config).is_none());
+}
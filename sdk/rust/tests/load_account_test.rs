@@ -0,0 +1,130 @@
+pub mod program_test;
+use solana_instruction::AccountMeta;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::ListConfig, programs::TOKEN_ACL_GATE_PROGRAM_ID, types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+fn remove_wallet(
+    context: &mut TestContext,
+    list_config: AccountMeta,
+    wallet_entry: &Pubkey,
+) -> Option<u32> {
+    let mut ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config.pubkey)
+        .wallet_entry(*wallet_entry)
+        .instruction();
+    ix.accounts[1] = list_config;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+
+    match context.vm.send_transaction(tx).unwrap_err().err {
+        TransactionError::InstructionError(0, InstructionError::Custom(code)) => Some(code),
+        _ => None,
+    }
+}
+
+fn set_list_config_data(context: &mut TestContext, owner: Pubkey, data: Vec<u8>) -> Pubkey {
+    let address = Pubkey::new_unique();
+    let res = context.vm.set_account(
+        address,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    assert!(res.is_ok());
+
+    address
+}
+
+#[tokio::test]
+async fn fails_to_load_account_with_wrong_owner() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    // same bytes as a valid list, owned by another program
+    let data = context.vm.get_account(&list_config).unwrap().data;
+    let fake_list = set_list_config_data(&mut context, Pubkey::new_unique(), data);
+
+    let code = remove_wallet(
+        &mut context,
+        AccountMeta::new(fake_list, false),
+        &wallet_entry,
+    );
+    assert_eq!(code, Some(22));
+}
+
+#[tokio::test]
+async fn fails_to_load_account_not_writable() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let code = remove_wallet(
+        &mut context,
+        AccountMeta::new_readonly(list_config, false),
+        &wallet_entry,
+    );
+    assert_eq!(code, Some(8));
+}
+
+#[tokio::test]
+async fn fails_to_load_account_with_wrong_discriminator() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let mut data = context.vm.get_account(&list_config).unwrap().data;
+    data[0] = token_acl_gate_client::accounts::WALLET_ENTRY_DISCRIMINATOR;
+    let fake_list = set_list_config_data(&mut context, TOKEN_ACL_GATE_PROGRAM_ID, data);
+
+    let code = remove_wallet(
+        &mut context,
+        AccountMeta::new(fake_list, false),
+        &wallet_entry,
+    );
+    assert_eq!(code, Some(4));
+}
+
+#[tokio::test]
+async fn fails_to_load_uninitialized_account() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let fake_list = set_list_config_data(
+        &mut context,
+        TOKEN_ACL_GATE_PROGRAM_ID,
+        vec![0; ListConfig::LEN],
+    );
+
+    let code = remove_wallet(
+        &mut context,
+        AccountMeta::new(fake_list, false),
+        &wallet_entry,
+    );
+    assert_eq!(code, Some(4));
+}
@@ -0,0 +1,185 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{ListConfig, WalletEntry},
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+const INVALID_AUTHORITY: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(1));
+
+fn send(context: &mut TestContext, signer: &Keypair, ix: Instruction) -> TransactionResult {
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), signer.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn set_manager(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    manager: &Pubkey,
+) -> TransactionResult {
+    let ix = token_acl_gate_client::instructions::SetManagerBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(*list_config)
+        .manager(*manager)
+        .instruction();
+    send(context, authority, ix)
+}
+
+fn add_wallet(
+    context: &mut TestContext,
+    signer: &Keypair,
+    list_config: &Pubkey,
+    wallet: &Pubkey,
+) -> (TransactionResult, Pubkey) {
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], list_config, wallet);
+    let ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
+        .authority(signer.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(false)
+        .instruction();
+    (send(context, signer, ix), wallet_entry)
+}
+
+fn get_list_config(context: &TestContext, list_config: &Pubkey) -> ListConfig {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn manager_can_add_but_not_update_mode() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let list_config = context.create_list(Mode::Allow);
+    let manager = Keypair::new();
+    assert_eq!(
+        get_list_config(&context, &list_config).manager,
+        Pubkey::default()
+    );
+
+    assert!(set_manager(&mut context, &authority, &list_config, &manager.pubkey()).is_ok());
+    assert_eq!(
+        get_list_config(&context, &list_config).manager,
+        manager.pubkey()
+    );
+
+    let (res, wallet_entry) =
+        add_wallet(&mut context, &manager, &list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+    assert_eq!(get_list_config(&context, &list_config).wallets_count, 1);
+
+    // the entry's rent goes to the list authority, not the manager
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(manager.pubkey())
+        .list_config(list_config)
+        .wallet_entry(wallet_entry)
+        .instruction();
+    assert_eq!(
+        send(&mut context, &manager, ix).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(3))
+    );
+
+    let entry_lamports = context.vm.get_balance(&wallet_entry).unwrap();
+    let authority_lamports = context.vm.get_balance(&authority.pubkey()).unwrap();
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(manager.pubkey())
+        .list_config(list_config)
+        .wallet_entry(wallet_entry)
+        .add_remaining_account(AccountMeta::new(authority.pubkey(), false))
+        .instruction();
+    // a separate fee payer keeps fees out of the authority's balance
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.token.auth.pubkey()),
+        &[
+            context.token.auth.insecure_clone(),
+            manager.insecure_clone(),
+        ],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+    assert!(context.vm.get_account(&wallet_entry).is_none());
+    assert_eq!(
+        context.vm.get_balance(&authority.pubkey()).unwrap(),
+        authority_lamports + entry_lamports
+    );
+
+    let ix = token_acl_gate_client::instructions::UpdateModeBuilder::new()
+        .authority(manager.pubkey())
+        .list_config(list_config)
+        .mode(Mode::Block)
+        .acknowledge(false)
+        .eoa_grace_period_secs(0)
+        .instruction();
+    assert_eq!(
+        send(&mut context, &manager, ix).unwrap_err().err,
+        INVALID_AUTHORITY
+    );
+
+    let ix = token_acl_gate_client::instructions::ProposeAuthorityTransferBuilder::new()
+        .authority(manager.pubkey())
+        .list_config(list_config)
+        .new_authority(manager.pubkey())
+        .instruction();
+    assert_eq!(
+        send(&mut context, &manager, ix).unwrap_err().err,
+        INVALID_AUTHORITY
+    );
+
+    let config = get_list_config(&context, &list_config);
+    assert_eq!(config.mode, Mode::Allow as u8);
+    assert_eq!(config.authority, authority.pubkey());
+}
+
+#[tokio::test]
+async fn only_the_authority_sets_the_manager() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let list_config = context.create_list(Mode::Allow);
+    let manager = Keypair::new();
+    assert!(set_manager(&mut context, &authority, &list_config, &manager.pubkey()).is_ok());
+
+    // the manager can't hand its role on
+    let res = set_manager(&mut context, &manager, &list_config, &Pubkey::new_unique());
+    assert_eq!(res.unwrap_err().err, INVALID_AUTHORITY);
+    assert_eq!(
+        get_list_config(&context, &list_config).manager,
+        manager.pubkey()
+    );
+}
+
+#[tokio::test]
+async fn removed_manager_can_no_longer_add() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let list_config = context.create_list(Mode::Allow);
+    let manager = Keypair::new();
+    assert!(set_manager(&mut context, &authority, &list_config, &manager.pubkey()).is_ok());
+    assert!(set_manager(&mut context, &authority, &list_config, &Pubkey::default()).is_ok());
+
+    let (res, _) = add_wallet(&mut context, &manager, &list_config, &Pubkey::new_unique());
+    assert_eq!(res.unwrap_err().err, INVALID_AUTHORITY);
+}
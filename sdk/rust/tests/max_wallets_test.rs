@@ -0,0 +1,141 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{accounts::ListConfig, client::PolicyUpdate, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext};
+
+fn create_capped_list(context: &mut TestContext, max_wallets: u32) -> Pubkey {
+    context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            max_wallets,
+            ..Default::default()
+        },
+    )
+}
+
+fn set_max_wallets(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    max_wallets: u32,
+) -> TransactionResult {
+    let ix = PolicyUpdate {
+        max_wallets: Some(max_wallets),
+        ..Default::default()
+    }
+    .builder()
+    .authority(authority.pubkey())
+    .list_config(*list_config)
+    .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), authority.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn get_list_config(context: &TestContext, list_config: &Pubkey) -> ListConfig {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn adds_up_to_the_cap() {
+    let mut context = TestContext::new();
+    let list_config = create_capped_list(&mut context, 2);
+
+    let _ = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+    assert_eq!(get_list_config(&context, &list_config).wallets_count, 2);
+
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(35))
+    );
+    assert_eq!(get_list_config(&context, &list_config).wallets_count, 2);
+}
+
+#[tokio::test]
+async fn reverts_batch_past_the_cap() {
+    let mut context = TestContext::new();
+    let list_config = create_capped_list(&mut context, 2);
+
+    let wallets = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let (res, wallet_entries) = context.try_add_wallets_to_list(&list_config, &wallets);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(35))
+    );
+    assert!(context.vm.get_account(&wallet_entries[0]).is_none());
+    assert_eq!(get_list_config(&context, &list_config).wallets_count, 0);
+}
+
+#[tokio::test]
+async fn zero_is_unlimited() {
+    let mut context = TestContext::new();
+    let list_config = create_capped_list(&mut context, 0);
+
+    let wallets = (0..5).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+    let (res, _) = context.try_add_wallets_to_list(&list_config, &wallets);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn updates_the_cap() {
+    let mut context = TestContext::new();
+    let list_config = create_capped_list(&mut context, 1);
+    let _ = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert!(res.is_err());
+
+    let authority = context.auth.insecure_clone();
+    let res = set_max_wallets(&mut context, &authority, &list_config, 2);
+    assert!(res.is_ok());
+    assert_eq!(get_list_config(&context, &list_config).max_wallets, 2);
+
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+
+    // lowering the cap below the count keeps the entries but blocks new ones
+    let res = set_max_wallets(&mut context, &authority, &list_config, 1);
+    assert!(res.is_ok());
+    assert_eq!(get_list_config(&context, &list_config).wallets_count, 2);
+
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(35))
+    );
+}
+
+#[tokio::test]
+async fn rejects_non_authority() {
+    let mut context = TestContext::new();
+    let list_config = create_capped_list(&mut context, 1);
+
+    let other = Keypair::new();
+    let res = set_max_wallets(&mut context, &other, &list_config, 0);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+    assert_eq!(get_list_config(&context, &list_config).max_wallets, 1);
+}
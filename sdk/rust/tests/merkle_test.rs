@@ -0,0 +1,862 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    hash::hashv,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use token_acl_gate_client::{
+    accounts::{ListConfig, MerkleProof, WalletEntry},
+    client::PolicyUpdate,
+    instructions::{
+        CloseMerkleProofBuilder, SubmitMerkleProofBuilder, UpdateMerkleRootBuilder,
+        VerifyMerkleMembershipBuilder,
+    },
+    types::Mode,
+};
+use token_acl_interface::instruction::CanThawPermissionlessInstruction;
+
+use crate::program_test::TestContext;
+
+fn leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[&[0x00], wallet.as_ref()]).to_bytes()
+}
+
+fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[0x01], left, right]).to_bytes()
+}
+
+/// Root of a tree of four members and the proof of each member.
+fn tree(members: &[Pubkey; 4]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    let leaves = members.map(|member| leaf(&member));
+    let nodes = [node(&leaves[0], &leaves[1]), node(&leaves[2], &leaves[3])];
+    let proofs = (0..4)
+        .map(|i| vec![leaves[i ^ 1], nodes[(i / 2) ^ 1]])
+        .collect();
+
+    (node(&nodes[0], &nodes[1]), proofs)
+}
+
+/// Most levels of a proof the program accepts, `MAX_MERKLE_PROOF_LEN`.
+const MAX_PROOF_DEPTH: usize = 24;
+
+/// Root and proof of `member` in a tree `depth` levels deep, with made up
+/// siblings.
+fn deep_proof(member: &Pubkey, depth: usize) -> ([u8; 32], Vec<[u8; 32]>) {
+    let proof: Vec<[u8; 32]> = (0..depth)
+        .map(|_| Pubkey::new_unique().to_bytes())
+        .collect();
+    let root = proof
+        .iter()
+        .fold(leaf(member), |node_hash, sibling| node(&node_hash, sibling));
+
+    (root, proof)
+}
+
+fn update_merkle_root(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    merkle_root: [u8; 32],
+) -> TransactionResult {
+    let ix = UpdateMerkleRootBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(*list_config)
+        .merkle_root(merkle_root)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), authority.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn replace_merkle_root(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    merkle_root: [u8; 32],
+    expected_root_version: u64,
+) -> TransactionResult {
+    let ix = UpdateMerkleRootBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list_config)
+        .merkle_root(merkle_root)
+        .instruction_expecting(expected_root_version);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// Runs VerifyMerkleMembership, returning its one byte on success.
+fn verify_merkle_membership(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    member: &Pubkey,
+    proof: &[[u8; 32]],
+) -> Result<Vec<u8>, TransactionError> {
+    let ix = VerifyMerkleMembershipBuilder::new()
+        .list_config(*list_config)
+        .member(*member)
+        .proof(proof.to_vec())
+        .instruction();
+
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context
+        .vm
+        .send_transaction(tx)
+        .map(|meta| meta.return_data.data)
+        .map_err(|failure| failure.err)
+}
+
+fn set_max_proof_depth(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    max_proof_depth: u8,
+) -> TransactionResult {
+    let ix = PolicyUpdate {
+        max_proof_depth: Some(max_proof_depth),
+        ..Default::default()
+    }
+    .builder()
+    .authority(context.auth.pubkey())
+    .list_config(*list_config)
+    .instruction();
+
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn set_max_cu_per_eval(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    max_cu_per_eval: u32,
+) -> TransactionResult {
+    let ix = PolicyUpdate {
+        max_cu_per_eval: Some(max_cu_per_eval),
+        ..Default::default()
+    }
+    .builder()
+    .authority(context.auth.pubkey())
+    .list_config(*list_config)
+    .instruction();
+
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn root_version(context: &TestContext, list_config: &Pubkey) -> u64 {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap().root_version
+}
+
+/// A merkle list of four members for the mint.
+fn setup(context: &mut TestContext) -> (Pubkey, [Keypair; 4], Vec<Vec<[u8; 32]>>) {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::MerkleAllow);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let members = [
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+        Keypair::new(),
+    ];
+    let (root, proofs) = tree(&members.each_ref().map(|member| member.pubkey()));
+
+    let authority = context.auth.insecure_clone();
+    assert!(update_merkle_root(context, &authority, &list_config, root).is_ok());
+
+    (list_config, members, proofs)
+}
+
+fn submit_merkle_proof(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    member: &Pubkey,
+    proof: &[[u8; 32]],
+) -> TransactionResult {
+    let (merkle_proof, _) = MerkleProof::find_pda(list_config, member);
+    let ix = SubmitMerkleProofBuilder::new()
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .member(*member)
+        .merkle_proof(merkle_proof)
+        .proof(proof.to_vec())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn close_merkle_proof(
+    context: &mut TestContext,
+    signer: &Keypair,
+    list_config: &Pubkey,
+    member: &Pubkey,
+) -> TransactionResult {
+    let (merkle_proof, _) = MerkleProof::find_pda(list_config, member);
+    let ix = CloseMerkleProofBuilder::new()
+        .authority(signer.pubkey())
+        .list_config(*list_config)
+        .merkle_proof(merkle_proof)
+        .instruction();
+
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), signer.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// Runs CanThaw for `wallet` with `account` paired to the list.
+fn can_thaw_with(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallet: &Keypair,
+    account: &Pubkey,
+) -> TransactionResult {
+    // the wallet's token account, created on its first thaw
+    let ta = get_associated_token_address_with_program_id(
+        &wallet.pubkey(),
+        &context.token.mint,
+        &spl_token_2022::ID,
+    );
+    if context.vm.get_account(&ta).is_none() {
+        context.create_token_account(wallet);
+    }
+
+    context.vm.expire_blockhash();
+    context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(*list_config, *account)])
+}
+
+/// Runs CanThaw for `wallet` with its proof account paired to the list.
+fn can_thaw(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallet: &Keypair,
+) -> TransactionResult {
+    let (merkle_proof, _) = MerkleProof::find_pda(list_config, &wallet.pubkey());
+    can_thaw_with(context, list_config, wallet, &merkle_proof)
+}
+
+const BLOCKED: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(2));
+
+const INVALID_PROOF: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(38));
+
+const PROOF_TOO_LONG: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(40));
+
+const EVAL_TOO_EXPENSIVE: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(43));
+
+#[tokio::test]
+async fn stores_the_root() {
+    let mut context = TestContext::new();
+    let (list_config, members, _) = setup(&mut context);
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.mode, Mode::MerkleAllow as u8);
+    assert_eq!(
+        config.merkle_root,
+        tree(&members.each_ref().map(|member| member.pubkey())).0
+    );
+}
+
+#[tokio::test]
+async fn stores_the_proof() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    let member = members[0].pubkey();
+    assert!(submit_merkle_proof(&mut context, &list_config, &member, &proofs[0]).is_ok());
+
+    let (address, _) = MerkleProof::find_pda(&list_config, &member);
+    let account = context.vm.get_account(&address).unwrap();
+    let merkle_proof = MerkleProof::from_bytes(&account.data).unwrap();
+    assert_eq!(merkle_proof.list_config, list_config);
+    assert_eq!(merkle_proof.member, member);
+    assert_eq!(merkle_proof.proof_len, 2);
+    assert_eq!(merkle_proof.proof[..2], proofs[0][..]);
+    assert!(merkle_proof.proof[2..]
+        .iter()
+        .all(|level| *level == [0; 32]));
+}
+
+#[tokio::test]
+async fn proves_membership() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    for (member, proof) in members.iter().zip(&proofs) {
+        assert!(submit_merkle_proof(&mut context, &list_config, &member.pubkey(), proof).is_ok());
+
+        let res = can_thaw(&mut context, &list_config, member);
+        assert!(res.is_ok());
+    }
+}
+
+#[tokio::test]
+async fn resolves_the_proof_account_from_the_extra_metas() {
+    let mut context = TestContext::new();
+    let (list_config, members, _) = setup(&mut context);
+    let block_list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config, block_list]);
+
+    let wallet = &members[0];
+    let ta = context.create_token_account(wallet);
+    let mut ix = context.can_thaw_permissionless_instruction(
+        &context.auth.pubkey(),
+        &wallet.pubkey(),
+        &ta,
+        &[],
+    );
+    let extra_metas = context.vm.get_account(&ix.accounts[5].pubkey).unwrap();
+
+    let vm = &context.vm;
+    ExtraAccountMetaList::add_to_instruction::<CanThawPermissionlessInstruction, _, _>(
+        &mut ix,
+        |address| {
+            let data = vm.get_account(&address).map(|account| account.data);
+            async move { Ok(data) }
+        },
+        &extra_metas.data,
+    )
+    .await
+    .unwrap();
+
+    // the merkle list resolves the proof account, the block list the entry
+    let resolved: Vec<Pubkey> = ix.accounts[6..].iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(
+        resolved,
+        vec![
+            list_config,
+            MerkleProof::find_pda(&list_config, &wallet.pubkey()).0,
+            block_list,
+            WalletEntry::find_pda(&[0; 16], &block_list, &wallet.pubkey()).0,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn rejects_non_member() {
+    let mut context = TestContext::new();
+    let (list_config, _, proofs) = setup(&mut context);
+
+    let wallet = Keypair::new();
+    let res = submit_merkle_proof(&mut context, &list_config, &wallet.pubkey(), &proofs[0]);
+    assert_eq!(res.unwrap_err().err, INVALID_PROOF);
+
+    let res = can_thaw(&mut context, &list_config, &wallet);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+}
+
+#[tokio::test]
+async fn rejects_member_without_its_proof() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    let res = can_thaw(&mut context, &list_config, &members[0]);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+
+    let member = members[0].pubkey();
+    let res = submit_merkle_proof(&mut context, &list_config, &member, &proofs[1]);
+    assert_eq!(res.unwrap_err().err, INVALID_PROOF);
+}
+
+#[tokio::test]
+async fn rejects_another_members_proof() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    let other = members[1].pubkey();
+    assert!(submit_merkle_proof(&mut context, &list_config, &other, &proofs[1]).is_ok());
+
+    let (merkle_proof, _) = MerkleProof::find_pda(&list_config, &other);
+    let res = can_thaw_with(&mut context, &list_config, &members[0], &merkle_proof);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+}
+
+#[tokio::test]
+async fn rejects_a_proof_of_another_list() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    let member = members[0].pubkey();
+    let list_b = context.create_list(Mode::Allow);
+    let res = submit_merkle_proof(&mut context, &list_b, &member, &proofs[0]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(16))
+    );
+
+    // the proof account of the list can't be passed for another address
+    let (merkle_proof, _) = MerkleProof::find_pda(&list_b, &member);
+    let ix = SubmitMerkleProofBuilder::new()
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .member(member)
+        .merkle_proof(merkle_proof)
+        .proof(proofs[0].clone())
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert_eq!(
+        context.vm.send_transaction(tx).unwrap_err().err,
+        INVALID_PROOF
+    );
+}
+
+#[tokio::test]
+async fn allows_wallet_entry_without_proof() {
+    let mut context = TestContext::new();
+    let (list_config, _, _) = setup(&mut context);
+
+    // extra metas written before the list became a merkle list resolve the entry
+    let wallet = Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+
+    let res = can_thaw_with(&mut context, &list_config, &wallet, &wallet_entry);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn rejects_a_proof_in_instruction_data() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    let wallet = &members[0];
+    let ta = context.create_token_account(wallet);
+    let (merkle_proof, _) = MerkleProof::find_pda(&list_config, &wallet.pubkey());
+    let mut ix = context.can_thaw_permissionless_instruction(
+        &context.auth.pubkey(),
+        &wallet.pubkey(),
+        &ta,
+        &[(list_config, merkle_proof)],
+    );
+    ix.data.extend(proofs[0].iter().flatten());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert_eq!(
+        context.vm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
+
+#[tokio::test]
+async fn replaces_the_members() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+    let member = members[0].pubkey();
+    assert!(submit_merkle_proof(&mut context, &list_config, &member, &proofs[0]).is_ok());
+
+    let other = Keypair::new();
+    let res = update_merkle_root(&mut context, &other, &list_config, [0; 32]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+
+    let (root, _) = tree(&[
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ]);
+    let authority = context.auth.insecure_clone();
+    assert!(update_merkle_root(&mut context, &authority, &list_config, root).is_ok());
+
+    // the stored proof no longer leads to the root
+    let res = can_thaw(&mut context, &list_config, &members[0]);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+
+    let res = submit_merkle_proof(&mut context, &list_config, &member, &proofs[0]);
+    assert_eq!(res.unwrap_err().err, INVALID_PROOF);
+}
+
+#[tokio::test]
+async fn replaces_the_whole_membership() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+    assert_eq!(root_version(&context, &list_config), 1);
+
+    let member = members[0].pubkey();
+    assert!(submit_merkle_proof(&mut context, &list_config, &member, &proofs[0]).is_ok());
+
+    // the recomputed set keeps the first member and drops the others
+    let recomputed = [
+        member,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let (root, new_proofs) = tree(&recomputed);
+    assert!(replace_merkle_root(&mut context, &list_config, root, 1).is_ok());
+    assert_eq!(root_version(&context, &list_config), 2);
+
+    // the proof stored against the old root is stale
+    let res = can_thaw(&mut context, &list_config, &members[0]);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+
+    let res = submit_merkle_proof(&mut context, &list_config, &member, &proofs[0]);
+    assert_eq!(res.unwrap_err().err, INVALID_PROOF);
+
+    assert!(submit_merkle_proof(&mut context, &list_config, &member, &new_proofs[0]).is_ok());
+    let account = context
+        .vm
+        .get_account(&MerkleProof::find_pda(&list_config, &member).0)
+        .unwrap();
+    assert_eq!(
+        MerkleProof::from_bytes(&account.data).unwrap().root_version,
+        2
+    );
+
+    let res = can_thaw(&mut context, &list_config, &members[0]);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn rejects_a_stale_proof_leading_to_the_root() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    let member = members[0].pubkey();
+    assert!(submit_merkle_proof(&mut context, &list_config, &member, &proofs[0]).is_ok());
+
+    // the same root again, the stored proof still leads to it
+    let (root, _) = tree(&members.each_ref().map(|member| member.pubkey()));
+    assert!(replace_merkle_root(&mut context, &list_config, root, 1).is_ok());
+
+    let res = can_thaw(&mut context, &list_config, &members[0]);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+}
+
+#[tokio::test]
+async fn rejects_a_replacement_of_another_root_version() {
+    let mut context = TestContext::new();
+    let (list_config, _, _) = setup(&mut context);
+
+    // a replacement computed against a version that moved on
+    let res = replace_merkle_root(&mut context, &list_config, [1; 32], 0);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(39))
+    );
+    assert_eq!(root_version(&context, &list_config), 1);
+
+    assert!(replace_merkle_root(&mut context, &list_config, [1; 32], 1).is_ok());
+    let res = replace_merkle_root(&mut context, &list_config, [2; 32], 1);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(39))
+    );
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    assert_eq!(
+        ListConfig::from_bytes(&account.data).unwrap().merkle_root,
+        [1; 32]
+    );
+}
+
+#[tokio::test]
+async fn replaces_the_root_of_merkle_lists_only() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let res = replace_merkle_root(&mut context, &list_config, [1; 32], 0);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(16))
+    );
+
+    let authority = context.auth.insecure_clone();
+    let res = update_merkle_root(&mut context, &authority, &list_config, [1; 32]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(16))
+    );
+    assert_eq!(root_version(&context, &list_config), 0);
+}
+
+#[tokio::test]
+async fn verifies_membership_without_a_thaw() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+    let before = context.vm.get_account(&list_config).unwrap();
+
+    for (member, proof) in members.iter().zip(&proofs) {
+        let res = verify_merkle_membership(&mut context, &list_config, &member.pubkey(), proof);
+        assert_eq!(res.unwrap(), vec![1]);
+    }
+
+    // another member's proof, no proof and a non-member
+    let member = members[0].pubkey();
+    let res = verify_merkle_membership(&mut context, &list_config, &member, &proofs[1]);
+    assert_eq!(res.unwrap(), vec![0]);
+    let res = verify_merkle_membership(&mut context, &list_config, &member, &[]);
+    assert_eq!(res.unwrap(), vec![0]);
+    let res = verify_merkle_membership(
+        &mut context,
+        &list_config,
+        &Pubkey::new_unique(),
+        &proofs[0],
+    );
+    assert_eq!(res.unwrap(), vec![0]);
+
+    // nothing is stored
+    assert_eq!(context.vm.get_account(&list_config).unwrap(), before);
+    let (merkle_proof, _) = MerkleProof::find_pda(&list_config, &member);
+    assert!(context.vm.get_account(&merkle_proof).is_none());
+}
+
+#[tokio::test]
+async fn verifies_membership_on_merkle_lists_only() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let res = verify_merkle_membership(&mut context, &list_config, &Pubkey::new_unique(), &[]);
+    assert_eq!(
+        res.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::Custom(16))
+    );
+}
+
+#[tokio::test]
+async fn accepts_a_proof_at_the_max_depth() {
+    let mut context = TestContext::new();
+    let (list_config, members, _) = setup(&mut context);
+
+    let member = &members[0];
+    let (root, proof) = deep_proof(&member.pubkey(), MAX_PROOF_DEPTH);
+    let authority = context.auth.insecure_clone();
+    assert!(update_merkle_root(&mut context, &authority, &list_config, root).is_ok());
+
+    let res = verify_merkle_membership(&mut context, &list_config, &member.pubkey(), &proof);
+    assert_eq!(res.unwrap(), vec![1]);
+
+    assert!(submit_merkle_proof(&mut context, &list_config, &member.pubkey(), &proof).is_ok());
+    let res = can_thaw(&mut context, &list_config, member);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn rejects_a_proof_over_the_max_depth_before_hashing() {
+    let mut context = TestContext::new();
+    let (list_config, members, _) = setup(&mut context);
+    let member = members[0].pubkey();
+    let authority = context.auth.insecure_clone();
+
+    let verify = |context: &mut TestContext, depth: usize| {
+        // the proof does lead to the root, whatever its length
+        let (root, proof) = deep_proof(&member, depth);
+        assert!(update_merkle_root(context, &authority, &list_config, root).is_ok());
+
+        let ix = VerifyMerkleMembershipBuilder::new()
+            .list_config(list_config)
+            .member(member)
+            .proof(proof)
+            .instruction();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.auth.pubkey()),
+            &[context.auth.insecure_clone()],
+            context.vm.latest_blockhash(),
+        );
+        context.vm.send_transaction(tx)
+    };
+
+    let verified = verify(&mut context, MAX_PROOF_DEPTH).unwrap();
+    let rejected = verify(&mut context, MAX_PROOF_DEPTH + 1).unwrap_err();
+    assert_eq!(rejected.err, PROOF_TOO_LONG);
+
+    // no level of the rejected proof was hashed
+    assert!(rejected.meta.compute_units_consumed < verified.compute_units_consumed);
+
+    let (_, proof) = deep_proof(&member, MAX_PROOF_DEPTH + 1);
+    let res = submit_merkle_proof(&mut context, &list_config, &member, &proof);
+    assert_eq!(res.unwrap_err().err, PROOF_TOO_LONG);
+}
+
+#[tokio::test]
+async fn applies_the_max_depth_of_the_list() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    // the tree is two levels deep
+    let member = members[0].pubkey();
+    assert!(submit_merkle_proof(&mut context, &list_config, &member, &proofs[0]).is_ok());
+    assert!(set_max_proof_depth(&mut context, &list_config, 2).is_ok());
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    assert_eq!(
+        ListConfig::from_bytes(&account.data)
+            .unwrap()
+            .max_proof_depth,
+        2
+    );
+    let res = can_thaw(&mut context, &list_config, &members[0]);
+    assert!(res.is_ok());
+
+    // lowered below the stored proof, which stops counting
+    assert!(set_max_proof_depth(&mut context, &list_config, 1).is_ok());
+    let res = can_thaw(&mut context, &list_config, &members[0]);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+
+    let res = verify_merkle_membership(&mut context, &list_config, &member, &proofs[0]);
+    assert_eq!(res.unwrap_err(), PROOF_TOO_LONG);
+
+    // the program's maximum bounds the list's
+    let res = set_max_proof_depth(&mut context, &list_config, MAX_PROOF_DEPTH as u8 + 1);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
+
+#[tokio::test]
+async fn closes_the_proof_for_the_member_or_the_authority() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+    let authority = context.auth.insecure_clone();
+    for (member, proof) in members.iter().zip(&proofs) {
+        assert!(submit_merkle_proof(&mut context, &list_config, &member.pubkey(), proof).is_ok());
+    }
+
+    // the member reclaims the rent of its own proof
+    let member = &members[0];
+    let (merkle_proof, _) = MerkleProof::find_pda(&list_config, &member.pubkey());
+    let rent = context.vm.get_account(&merkle_proof).unwrap().lamports;
+    assert!(close_merkle_proof(&mut context, member, &list_config, &member.pubkey()).is_ok());
+    assert!(context.vm.get_account(&merkle_proof).is_none());
+    assert_eq!(
+        context.vm.get_account(&member.pubkey()).unwrap().lamports,
+        rent
+    );
+    let res = can_thaw(&mut context, &list_config, member);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+
+    let res = close_merkle_proof(&mut context, &authority, &list_config, &members[1].pubkey());
+    assert!(res.is_ok());
+
+    // neither another member nor a stranger
+    let res = close_merkle_proof(
+        &mut context,
+        &members[3],
+        &list_config,
+        &members[2].pubkey(),
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+    let res = close_merkle_proof(
+        &mut context,
+        &Keypair::new(),
+        &list_config,
+        &members[2].pubkey(),
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+    assert!(can_thaw(&mut context, &list_config, &members[2]).is_ok());
+}
+
+#[tokio::test]
+async fn applies_the_cu_ceiling_of_the_list() {
+    let mut context = TestContext::new();
+    let (list_config, members, _) = setup(&mut context);
+    let member = &members[0];
+    let (root, proof) = deep_proof(&member.pubkey(), MAX_PROOF_DEPTH);
+    let authority = context.auth.insecure_clone();
+    assert!(update_merkle_root(&mut context, &authority, &list_config, root).is_ok());
+    assert!(submit_merkle_proof(&mut context, &list_config, &member.pubkey(), &proof).is_ok());
+
+    // estimated at 3750 units, above a low ceiling
+    assert!(set_max_cu_per_eval(&mut context, &list_config, 1_000).is_ok());
+    let account = context.vm.get_account(&list_config).unwrap();
+    assert_eq!(
+        ListConfig::from_bytes(&account.data)
+            .unwrap()
+            .max_cu_per_eval,
+        1_000
+    );
+    let res = can_thaw(&mut context, &list_config, member);
+    assert_eq!(res.unwrap_err().err, EVAL_TOO_EXPENSIVE);
+    let res = verify_merkle_membership(&mut context, &list_config, &member.pubkey(), &proof);
+    assert_eq!(res.unwrap_err(), EVAL_TOO_EXPENSIVE);
+    context.vm.expire_blockhash();
+    let res = submit_merkle_proof(&mut context, &list_config, &member.pubkey(), &proof);
+    assert_eq!(res.unwrap_err().err, EVAL_TOO_EXPENSIVE);
+
+    // and below a high one
+    assert!(set_max_cu_per_eval(&mut context, &list_config, 10_000).is_ok());
+    let res = can_thaw(&mut context, &list_config, member);
+    assert!(res.is_ok());
+    let res = verify_merkle_membership(&mut context, &list_config, &member.pubkey(), &proof);
+    assert_eq!(res.unwrap(), vec![1]);
+}
+
+#[tokio::test]
+async fn keeps_shallow_proofs_under_a_low_cu_ceiling() {
+    let mut context = TestContext::new();
+    let (list_config, members, proofs) = setup(&mut context);
+
+    // the tree is two levels deep, estimated at 450 units
+    assert!(set_max_cu_per_eval(&mut context, &list_config, 1_000).is_ok());
+    let member = &members[0];
+    assert!(submit_merkle_proof(&mut context, &list_config, &member.pubkey(), &proofs[0]).is_ok());
+    let res = can_thaw(&mut context, &list_config, member);
+    assert!(res.is_ok());
+}
@@ -0,0 +1,135 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{accounts::WalletEntry, client::PolicyUpdate, types::Mode};
+
+use crate::program_test::TestContext;
+
+/// Units, pairs and list modes of a `METER` log line.
+fn parse_meter(logs: &[String]) -> Option<(u64, usize, u8)> {
+    let line = logs
+        .iter()
+        .find_map(|log| log.strip_prefix("Program log: METER: "))?;
+
+    let mut fields = line.split(", ");
+    let units = fields.next()?.strip_suffix(" units")?.parse().ok()?;
+    let pairs = fields.next()?.strip_suffix(" pairs")?.parse().ok()?;
+    let modes = fields.next()?.strip_prefix("modes ")?.parse().ok()?;
+    Some((units, pairs, modes))
+}
+
+/// An allow list holding the wallet and a block list that doesn't.
+fn setup(context: &mut TestContext) -> (Vec<(Pubkey, Pubkey)>, Keypair, Pubkey) {
+    let _ = context.setup_token_acl();
+    let allow_list = context.create_list(Mode::Allow);
+    let block_list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[allow_list, block_list]);
+
+    let wallet = Keypair::new();
+    let allow_entry = context.add_wallet_to_list(&allow_list, &wallet.pubkey());
+    let block_entry = WalletEntry::find_pda(&[0; 16], &block_list, &wallet.pubkey()).0;
+    let ta = context.create_token_account(&wallet);
+
+    (
+        vec![(allow_list, allow_entry), (block_list, block_entry)],
+        wallet,
+        ta,
+    )
+}
+
+/// Has thaws checked against `list` log their compute units.
+fn set_meter(context: &mut TestContext, list: &Pubkey) {
+    let ix = PolicyUpdate {
+        meter: Some(true),
+        ..Default::default()
+    }
+    .builder()
+    .authority(context.auth.pubkey())
+    .list_config(*list)
+    .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+}
+
+#[tokio::test]
+async fn logs_metered_summary() {
+    let mut context = TestContext::new();
+    let (pairs, wallet, ta) = setup(&mut context);
+    set_meter(&mut context, &pairs[1].0);
+
+    let res = context
+        .can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs)
+        .unwrap();
+    let (units, pair_count, modes) = parse_meter(&res.logs).unwrap();
+
+    assert!(units > 0 && units <= res.compute_units_consumed);
+    assert_eq!(pair_count, 2);
+    assert_eq!(modes, 1 << Mode::Allow as u8 | 1 << Mode::Block as u8);
+}
+
+#[tokio::test]
+async fn logs_metered_summary_of_denial() {
+    let mut context = TestContext::new();
+    let (pairs, _, _) = setup(&mut context);
+    set_meter(&mut context, &pairs[0].0);
+
+    // a wallet on neither list is denied by the allow list
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let res = context
+        .can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs)
+        .unwrap_err();
+
+    assert_eq!(
+        parse_meter(&res.meta.logs).map(|(_, pairs, _)| pairs),
+        Some(2)
+    );
+}
+
+#[tokio::test]
+async fn does_not_meter_by_default() {
+    let mut context = TestContext::new();
+    let (pairs, wallet, ta) = setup(&mut context);
+
+    let res = context
+        .can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs)
+        .unwrap();
+    assert_eq!(parse_meter(&res.logs), None);
+}
+
+#[tokio::test]
+async fn rejects_the_meter_flag_in_instruction_data() {
+    let mut context = TestContext::new();
+    let (pairs, wallet, ta) = setup(&mut context);
+
+    // metering is a list setting now, a flags byte is malformed data
+    let mut ix = context.can_thaw_permissionless_instruction(
+        &context.auth.pubkey(),
+        &wallet.pubkey(),
+        &ta,
+        &pairs,
+    );
+    ix.data.push(1 << 1);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert_eq!(
+        context.vm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
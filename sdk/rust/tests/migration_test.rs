@@ -0,0 +1,259 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::Instruction;
+use solana_sdk::{
+    account::Account,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{ListConfig, WalletEntry},
+    instructions::{MigrateListConfigBuilder, MigrateWalletEntryBuilder},
+    programs::TOKEN_ACL_GATE_PROGRAM_ID,
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+/// Layout lengths of the first deployed version.
+const LEGACY_LIST_CONFIG_LEN: usize = 1 + 32 + 32 + 1 + 8;
+const LEGACY_WALLET_ENTRY_LEN: usize = 1 + 32 + 32;
+
+const NAMESPACE: [u8; 16] = *b"tenant-a\0\0\0\0\0\0\0\0";
+
+fn send(context: &mut TestContext, ix: Instruction) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// Cuts the account data at `address` down to its first `len` bytes.
+fn truncate(context: &mut TestContext, address: &Pubkey, len: usize) {
+    let mut account = context.vm.get_account(address).unwrap();
+    account.data.truncate(len);
+    context.vm.set_account(*address, account).unwrap();
+}
+
+fn migrate_list_config(context: &mut TestContext, list_config: &Pubkey) -> TransactionResult {
+    let ix = MigrateListConfigBuilder::new()
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .instruction();
+    send(context, ix)
+}
+
+fn migrate_wallet_entry(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    legacy_wallet_entry: &Pubkey,
+    wallet_entry: &Pubkey,
+) -> TransactionResult {
+    let ix = MigrateWalletEntryBuilder::new()
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .legacy_wallet_entry(*legacy_wallet_entry)
+        .wallet_entry(*wallet_entry)
+        .instruction();
+    send(context, ix)
+}
+
+/// Writes an entry of the first layout for `wallet` at its address without
+/// the namespace.
+fn set_legacy_wallet_entry(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallet: &Pubkey,
+) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[WalletEntry::PREFIX, list_config.as_ref(), wallet.as_ref()],
+        &TOKEN_ACL_GATE_PROGRAM_ID,
+    );
+
+    let mut data = vec![2u8];
+    data.extend_from_slice(wallet.as_ref());
+    data.extend_from_slice(list_config.as_ref());
+    let account = Account {
+        lamports: context
+            .vm
+            .minimum_balance_for_rent_exemption(LEGACY_WALLET_ENTRY_LEN),
+        data,
+        owner: TOKEN_ACL_GATE_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    context.vm.set_account(address, account).unwrap();
+
+    address
+}
+
+#[tokio::test]
+async fn migrates_a_legacy_list_config() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    truncate(&mut context, &list_config, LEGACY_LIST_CONFIG_LEN);
+    let legacy = context.vm.get_account(&list_config).unwrap();
+
+    // an unmigrated list can't be loaded
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(4))
+    );
+
+    assert!(migrate_list_config(&mut context, &list_config).is_ok());
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    assert_eq!(account.data.len(), ListConfig::LEN);
+    assert_eq!(account.data[..LEGACY_LIST_CONFIG_LEN], legacy.data[..]);
+    assert!(account.data[LEGACY_LIST_CONFIG_LEN..]
+        .iter()
+        .all(|b| *b == 0));
+    assert_eq!(
+        account.lamports,
+        context
+            .vm
+            .minimum_balance_for_rent_exemption(ListConfig::LEN)
+    );
+
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.mode, Mode::Block as u8);
+    assert_eq!(config.wallets_count, 1);
+    assert!(!config.paused);
+
+    context.vm.expire_blockhash();
+    let res = context.try_add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn rejects_a_current_list_config() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let res = migrate_list_config(&mut context, &list_config);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(4))
+    );
+}
+
+#[tokio::test]
+async fn moves_a_legacy_wallet_entry_to_its_namespaced_address() {
+    let mut context = TestContext::new();
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_in_namespace(Mode::Block, NAMESPACE);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let legacy_wallet_entry = set_legacy_wallet_entry(&mut context, &list_config, &wallet.pubkey());
+    let (wallet_entry, _) = WalletEntry::find_pda(&NAMESPACE, &list_config, &wallet.pubkey());
+
+    // the block isn't found at the legacy address
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+
+    let res = migrate_wallet_entry(
+        &mut context,
+        &list_config,
+        &legacy_wallet_entry,
+        &wallet_entry,
+    );
+    assert!(res.is_ok());
+
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry = WalletEntry::from_bytes(&account.data).unwrap();
+    assert_eq!(entry.wallet_address, wallet.pubkey());
+    assert_eq!(entry.list_config, list_config);
+    assert_eq!(entry.namespace, NAMESPACE);
+    assert_eq!(entry.expires_at, 0);
+    assert_eq!(entry.appeal_deadline, 0);
+
+    // the legacy account is closed
+    assert!(context
+        .vm
+        .get_account(&legacy_wallet_entry)
+        .is_none_or(|account| account.lamports == 0));
+
+    context.vm.expire_blockhash();
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn grows_a_namespaced_wallet_entry_in_place() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list_in_namespace(Mode::Allow, NAMESPACE);
+    let wallet = Pubkey::new_unique();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet);
+
+    // the layout once the namespace was added, before the later fields
+    let len = LEGACY_WALLET_ENTRY_LEN + 16;
+    truncate(&mut context, &wallet_entry, len);
+    let legacy = context.vm.get_account(&wallet_entry).unwrap();
+
+    let res = migrate_wallet_entry(&mut context, &list_config, &wallet_entry, &wallet_entry);
+    assert!(res.is_ok());
+
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    assert_eq!(account.data.len(), WalletEntry::LEN);
+    assert_eq!(account.data[..len], legacy.data[..]);
+    assert!(account.data[len..].iter().all(|b| *b == 0));
+    assert_eq!(
+        WalletEntry::from_bytes(&account.data).unwrap().namespace,
+        NAMESPACE
+    );
+}
+
+#[tokio::test]
+async fn rejects_a_legacy_wallet_entry_at_another_address() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+    let legacy_wallet_entry = set_legacy_wallet_entry(&mut context, &list_config, &wallet);
+
+    // the legacy data copied to an address that isn't the wallet's legacy entry
+    let copy = Pubkey::new_unique();
+    let account = context.vm.get_account(&legacy_wallet_entry).unwrap();
+    context.vm.set_account(copy, account).unwrap();
+
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet);
+    let res = migrate_wallet_entry(&mut context, &list_config, &copy, &wallet_entry);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
+
+#[tokio::test]
+async fn rejects_a_wallet_entry_of_an_unmigrated_list() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+    let legacy_wallet_entry = set_legacy_wallet_entry(&mut context, &list_config, &wallet);
+    truncate(&mut context, &list_config, LEGACY_LIST_CONFIG_LEN);
+
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet);
+    let res = migrate_wallet_entry(
+        &mut context,
+        &list_config,
+        &legacy_wallet_entry,
+        &wallet_entry,
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(4))
+    );
+}
@@ -0,0 +1,27 @@
+use token_acl_gate_client::{client::ParseModeError, types::Mode};
+
+#[test]
+fn round_trips_every_mode_through_strings() {
+    for mode in Mode::ALL {
+        assert_eq!(mode.to_string().parse::<Mode>(), Ok(mode));
+    }
+}
+
+#[test]
+fn uses_canonical_mode_names() {
+    assert_eq!(Mode::Allow.to_string(), "allow");
+    assert_eq!(Mode::AllowAllEoas.to_string(), "allow_all_eoas");
+    assert_eq!(Mode::Block.to_string(), "block");
+    assert_eq!(Mode::AllowAllPdas.to_string(), "allow_all_pdas");
+    assert_eq!(Mode::MerkleAllow.to_string(), "merkle_allow");
+}
+
+#[test]
+fn fails_to_parse_unknown_mode() {
+    let err = "allow-all-eoas".parse::<Mode>().unwrap_err();
+    assert_eq!(err, ParseModeError("allow-all-eoas".to_string()));
+    assert_eq!(
+        err.to_string(),
+        "unknown mode `allow-all-eoas`, expected one of allow, allow_all_eoas, block, allow_all_pdas, merkle_allow"
+    );
+}
@@ -0,0 +1,118 @@
+pub mod program_test;
+use solana_sdk::{
+    account::Account, instruction::InstructionError, program_pack::Pack, pubkey::Pubkey,
+    transaction::TransactionError,
+};
+use spl_token_2022::state::Multisig;
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext, TokenAccountDataBuilder};
+
+fn create_list(context: &mut TestContext, allow_multisig_owner: bool) -> Pubkey {
+    context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            allow_multisig_owner,
+            ..Default::default()
+        },
+    )
+}
+
+/// Stores an initialized 1 of 1 token-2022 multisig at a new address.
+fn create_multisig(context: &mut TestContext) -> Pubkey {
+    let multisig = Multisig {
+        m: 1,
+        n: 1,
+        is_initialized: true,
+        signers: [Pubkey::new_unique(); 11],
+    };
+    let mut data = vec![0u8; Multisig::LEN];
+    Multisig::pack(multisig, &mut data).unwrap();
+
+    let address = Pubkey::new_unique();
+    let res = context.vm.set_account(
+        address,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: spl_token_2022::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    assert!(res.is_ok());
+
+    address
+}
+
+/// Thaws a token account of `owner` without the immutable owner extension.
+fn can_thaw(
+    context: &mut TestContext,
+    lists: &[Pubkey],
+    owner: &Pubkey,
+) -> Result<(), TransactionError> {
+    let _ = context.setup_token_acl();
+    let _ = context.setup_extra_metas(lists);
+
+    let data = TokenAccountDataBuilder::new(&context.token.mint, owner).build();
+    let ta = context.set_token_account(data);
+    let pairs = lists
+        .iter()
+        .map(|list| (*list, WalletEntry::find_pda(&[0; 16], list, owner).0))
+        .collect::<Vec<_>>();
+
+    context
+        .can_thaw_permissionless(owner, &ta, &pairs)
+        .map(|_| ())
+        .map_err(|res| res.err)
+}
+
+const MISSING_EXTENSION: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(10));
+
+#[tokio::test]
+async fn waives_immutable_owner_for_multisig_owner() {
+    let mut context = TestContext::new();
+    let list = create_list(&mut context, true);
+    let multisig = create_multisig(&mut context);
+
+    assert_eq!(can_thaw(&mut context, &[list], &multisig), Ok(()));
+}
+
+#[tokio::test]
+async fn requires_immutable_owner_for_regular_owner() {
+    let mut context = TestContext::new();
+    let list = create_list(&mut context, true);
+
+    assert_eq!(
+        can_thaw(&mut context, &[list], &Pubkey::new_unique()),
+        Err(MISSING_EXTENSION)
+    );
+}
+
+#[tokio::test]
+async fn requires_immutable_owner_without_the_flag() {
+    let mut context = TestContext::new();
+    let list = create_list(&mut context, false);
+    let multisig = create_multisig(&mut context);
+
+    assert_eq!(
+        can_thaw(&mut context, &[list], &multisig),
+        Err(MISSING_EXTENSION)
+    );
+}
+
+#[tokio::test]
+async fn requires_every_list_to_waive() {
+    let mut context = TestContext::new();
+    let lists = [
+        create_list(&mut context, true),
+        create_list(&mut context, false),
+    ];
+    let multisig = create_multisig(&mut context);
+
+    assert_eq!(
+        can_thaw(&mut context, &lists, &multisig),
+        Err(MISSING_EXTENSION)
+    );
+}
@@ -0,0 +1,94 @@
+pub mod program_test;
+use solana_sdk::{
+    instruction::InstructionError,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{ListConfig, WalletEntry},
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+const NAMESPACE_A: [u8; 16] = *b"tenant-a\0\0\0\0\0\0\0\0";
+const NAMESPACE_B: [u8; 16] = *b"tenant-b\0\0\0\0\0\0\0\0";
+
+#[tokio::test]
+async fn scopes_wallet_entries_to_the_list_namespace() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list_in_namespace(Mode::Allow, NAMESPACE_A);
+    let wallet = solana_keypair::Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.namespace, NAMESPACE_A);
+
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry = WalletEntry::from_bytes(&account.data).unwrap();
+    assert_eq!(entry.namespace, NAMESPACE_A);
+
+    let (expected, _) = WalletEntry::find_pda(&NAMESPACE_A, &list_config, &wallet.pubkey());
+    assert_eq!(wallet_entry, expected);
+}
+
+#[tokio::test]
+async fn fails_to_thaw_with_entry_from_another_namespace() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_a = context.create_list_in_namespace(Mode::Allow, NAMESPACE_A);
+    let list_b = context.create_list_in_namespace(Mode::Allow, NAMESPACE_B);
+    let _ = context.setup_extra_metas(&[list_b]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let entry_a = context.add_wallet_to_list(&list_a, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_b, entry_a)]);
+    assert!(res.is_err());
+
+    let err = res.err().unwrap();
+    assert_eq!(
+        err.err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn fails_to_remove_entry_from_another_namespace() {
+    let mut context = TestContext::new();
+
+    let list_a = context.create_list_in_namespace(Mode::Block, NAMESPACE_A);
+    let list_b = context.create_list_in_namespace(Mode::Block, NAMESPACE_B);
+
+    let wallet = solana_keypair::Keypair::new();
+    let entry_a = context.add_wallet_to_list(&list_a, &wallet.pubkey());
+
+    let ix = token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_b)
+        .wallet_entry(entry_a)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let err = res.err().unwrap();
+    assert_eq!(
+        err.err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
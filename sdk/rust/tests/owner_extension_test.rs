@@ -0,0 +1,111 @@
+pub mod program_test;
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, transaction::TransactionError};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext, TokenAccountDataBuilder};
+
+const IMMUTABLE_OWNER: u8 = 1 << 0;
+const NON_TRANSFERABLE: u8 = 1 << 1;
+
+const MISSING_EXTENSION: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(10));
+
+fn create_list(context: &mut TestContext, owner_extensions: u8) -> Pubkey {
+    context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            owner_extensions,
+            ..Default::default()
+        },
+    )
+}
+
+/// Thaws a token account built by `build` against every list.
+fn can_thaw(
+    context: &mut TestContext,
+    lists: &[Pubkey],
+    build: fn(TokenAccountDataBuilder) -> TokenAccountDataBuilder,
+) -> Result<(), TransactionError> {
+    let _ = context.setup_token_acl();
+    let _ = context.setup_extra_metas(lists);
+
+    let owner = Pubkey::new_unique();
+    let data = build(TokenAccountDataBuilder::new(&context.token.mint, &owner)).build();
+    let ta = context.set_token_account(data);
+    let pairs = lists
+        .iter()
+        .map(|list| (*list, WalletEntry::find_pda(&[0; 16], list, &owner).0))
+        .collect::<Vec<_>>();
+
+    context
+        .can_thaw_permissionless(&owner, &ta, &pairs)
+        .map(|_| ())
+        .map_err(|res| res.err)
+}
+
+#[tokio::test]
+async fn accepts_immutable_owner_by_default() {
+    let mut context = TestContext::new();
+    let list = create_list(&mut context, 0);
+
+    assert_eq!(
+        can_thaw(&mut context, &[list], |data| data.immutable_owner()),
+        Ok(())
+    );
+    assert_eq!(
+        can_thaw(&mut context, &[list], |data| data.non_transferable()),
+        Err(MISSING_EXTENSION)
+    );
+}
+
+#[tokio::test]
+async fn accepts_non_transferable_when_allowed() {
+    let mut context = TestContext::new();
+    let list = create_list(&mut context, NON_TRANSFERABLE);
+
+    assert_eq!(
+        can_thaw(&mut context, &[list], |data| data.non_transferable()),
+        Ok(())
+    );
+    assert_eq!(
+        can_thaw(&mut context, &[list], |data| data.immutable_owner()),
+        Err(MISSING_EXTENSION)
+    );
+}
+
+#[tokio::test]
+async fn accepts_either_extension_when_both_allowed() {
+    let mut context = TestContext::new();
+    let list = create_list(&mut context, IMMUTABLE_OWNER | NON_TRANSFERABLE);
+
+    assert_eq!(
+        can_thaw(&mut context, &[list], |data| data.immutable_owner()),
+        Ok(())
+    );
+    assert_eq!(
+        can_thaw(&mut context, &[list], |data| data.non_transferable()),
+        Ok(())
+    );
+    assert_eq!(
+        can_thaw(&mut context, &[list], |data| data),
+        Err(MISSING_EXTENSION)
+    );
+}
+
+#[tokio::test]
+async fn strict_list_demands_immutable_owner() {
+    let mut context = TestContext::new();
+    let lists = [
+        create_list(&mut context, IMMUTABLE_OWNER | NON_TRANSFERABLE),
+        create_list(&mut context, IMMUTABLE_OWNER),
+    ];
+
+    assert_eq!(
+        can_thaw(&mut context, &lists, |data| data.non_transferable()),
+        Err(MISSING_EXTENSION)
+    );
+    assert_eq!(
+        can_thaw(&mut context, &lists, |data| data.immutable_owner()),
+        Ok(())
+    );
+}
@@ -0,0 +1,319 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::AccountMeta;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{ListConfig, WalletEntry},
+    client::{explain_thaw_denial, DenialReason, Membership, PolicyUpdate},
+    types::Mode,
+};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const BLOCKED: TransactionError =
+    TransactionError::InstructionError(0, InstructionError::Custom(2));
+
+fn set_paused(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    paused: bool,
+) -> TransactionResult {
+    let ix = token_acl_gate_client::instructions::SetPausedBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(*list_config)
+        .paused(paused)
+        .instruction();
+
+    // pausing twice sends the same transaction
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), authority.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// A list of `mode` gating the mint and an owner with a token account, on the
+/// list when `add` is set.
+fn setup(context: &mut TestContext, mode: Mode, add: bool) -> (Pubkey, Keypair, Pubkey, Pubkey) {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(mode);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    if add {
+        let _ = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    }
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    (list_config, wallet, wallet_entry, ta)
+}
+
+#[tokio::test]
+async fn pauses_and_resumes_thaws() {
+    let mut context = TestContext::new();
+    let (list_config, wallet, wallet_entry, ta) = setup(&mut context, Mode::Allow, true);
+    let authority = context.auth.insecure_clone();
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+
+    assert!(set_paused(&mut context, &authority, &list_config, true).is_ok());
+    let config =
+        ListConfig::from_bytes(&context.vm.get_account(&list_config).unwrap().data).unwrap();
+    assert!(config.paused);
+
+    context.vm.expire_blockhash();
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+
+    // entries survive the pause
+    assert!(context.vm.get_account(&wallet_entry).is_some());
+
+    assert!(set_paused(&mut context, &authority, &list_config, false).is_ok());
+    context.vm.expire_blockhash();
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn pauses_regardless_of_mode() {
+    for (mode, add) in [(Mode::AllowAllEoas, false), (Mode::Block, false)] {
+        let mut context = TestContext::new();
+        let (list_config, wallet, wallet_entry, ta) = setup(&mut context, mode, add);
+        let authority = context.auth.insecure_clone();
+
+        assert!(set_paused(&mut context, &authority, &list_config, true).is_ok());
+
+        let res =
+            context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+        assert_eq!(res.unwrap_err().err, BLOCKED);
+    }
+}
+
+/// `(list, wallet_entry)` pairs of `wallet` on `lists`, adding it to the
+/// first `added` lists.
+fn pairs(
+    context: &mut TestContext,
+    lists: &[Pubkey],
+    wallet: &Pubkey,
+    added: usize,
+) -> Vec<(Pubkey, Pubkey)> {
+    lists
+        .iter()
+        .enumerate()
+        .map(|(i, list)| {
+            if i < added {
+                let _ = context.add_wallet_to_list(list, wallet);
+            }
+            (*list, WalletEntry::find_pda(&[0; 16], list, wallet).0)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn denies_despite_allow_precedence() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let _ = context.setup_token_acl();
+    let allow_list = context.create_list(Mode::Allow);
+    let block_list = context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            allow_precedence: true,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[allow_list, block_list]);
+
+    // allowed on the allow list, blocked on the block list yielding to it
+    let wallet = Keypair::new();
+    let pairs = pairs(&mut context, &[allow_list, block_list], &wallet.pubkey(), 2);
+    let ta = context.create_token_account(&wallet);
+    assert!(context
+        .can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs)
+        .is_ok());
+
+    assert!(set_paused(&mut context, &authority, &block_list, true).is_ok());
+    context.vm.expire_blockhash();
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+}
+
+#[tokio::test]
+async fn denies_despite_allow_threshold() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let _ = context.setup_token_acl();
+    let lists = (0..3)
+        .map(|_| {
+            context.create_list_with_config(
+                Mode::Allow,
+                ListOptions {
+                    allow_threshold: 2,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let _ = context.setup_extra_metas(&lists);
+
+    // on two of the three lists, meeting the threshold
+    let wallet = Keypair::new();
+    let pairs = pairs(&mut context, &lists, &wallet.pubkey(), 2);
+    let ta = context.create_token_account(&wallet);
+    assert!(context
+        .can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs)
+        .is_ok());
+
+    // the paused list doesn't just stop counting, it denies
+    assert!(set_paused(&mut context, &authority, &lists[2], true).is_ok());
+    context.vm.expire_blockhash();
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+}
+
+#[tokio::test]
+async fn denies_despite_any_list() {
+    let mut context = TestContext::new();
+    let authority = context.auth.insecure_clone();
+    let _ = context.setup_token_acl();
+    let lists = [
+        context.create_list(Mode::Allow),
+        context.create_list(Mode::Allow),
+    ];
+    let _ = context.setup_extra_metas(&lists);
+
+    let wallet = Keypair::new();
+    let pairs = pairs(&mut context, &lists, &wallet.pubkey(), 2);
+    let ta = context.create_token_account(&wallet);
+    for list in &lists {
+        let ix = PolicyUpdate {
+            any_list: Some(true),
+            ..Default::default()
+        }
+        .builder()
+        .authority(authority.pubkey())
+        .list_config(*list)
+        .instruction();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&authority.pubkey()),
+            &[authority.insecure_clone()],
+            context.vm.latest_blockhash(),
+        );
+        assert!(context.vm.send_transaction(tx).is_ok());
+    }
+    assert!(set_paused(&mut context, &authority, &lists[1], true).is_ok());
+
+    // the first list alone would allow the thaw
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &pairs);
+    assert_eq!(res.unwrap_err().err, BLOCKED);
+}
+
+#[tokio::test]
+async fn reports_members_of_paused_list_as_denied() {
+    let mut context = TestContext::new();
+    let (list_config, wallet, wallet_entry, _) = setup(&mut context, Mode::Allow, true);
+    let authority = context.auth.insecure_clone();
+    assert!(set_paused(&mut context, &authority, &list_config, true).is_ok());
+
+    let ix = token_acl_gate_client::instructions::GetMembershipBuilder::new()
+        .list_config(list_config)
+        .owner(wallet.pubkey())
+        .wallet_entry(wallet_entry)
+        .instruction();
+    let batch_ix = token_acl_gate_client::instructions::GetMembershipBatchBuilder::new()
+        .list_config(list_config)
+        .add_remaining_accounts(&[
+            AccountMeta::new_readonly(wallet_entry, false),
+            AccountMeta::new_readonly(wallet.pubkey(), false),
+        ])
+        .instruction();
+
+    let [single, batch] = [ix, batch_ix].map(|ix| {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.auth.pubkey()),
+            &[context.auth.insecure_clone()],
+            context.vm.latest_blockhash(),
+        );
+        context.vm.send_transaction(tx).unwrap().return_data.data
+    });
+
+    assert_eq!(
+        Membership::from_return_data(&single),
+        Some(Membership::Denied)
+    );
+    assert_eq!(
+        Membership::from_batch_return_data(&batch, 1),
+        Some(vec![Membership::Denied])
+    );
+}
+
+#[tokio::test]
+async fn explains_paused_list() {
+    let mut context = TestContext::new();
+    let (list_config, wallet, wallet_entry, ta) = setup(&mut context, Mode::Allow, true);
+    let authority = context.auth.insecure_clone();
+    assert!(set_paused(&mut context, &authority, &list_config, true).is_ok());
+
+    let pairs = [(list_config, wallet_entry)];
+    let diagnosis = context.diagnose_thaw(&wallet.pubkey(), &ta, &pairs);
+    let explanation = explain_thaw_denial(&pairs, &diagnosis, |pubkey| {
+        context.vm.get_account(pubkey).map(|a| a.data)
+    })
+    .unwrap();
+
+    assert_eq!(explanation.reason, DenialReason::Paused);
+    assert_eq!(explanation.error_code(), 2);
+}
+
+#[tokio::test]
+async fn rejects_non_authority() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let res = set_paused(&mut context, &Keypair::new(), &list_config, true);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn rejects_invalid_flag() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let mut ix = token_acl_gate_client::instructions::SetPausedBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config)
+        .paused(true)
+        .instruction();
+    ix.data[1] = 2;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert_eq!(
+        context.vm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
@@ -0,0 +1,219 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_sdk::{
+    clock::Clock,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{AuditLog, ListConfig, PolicyArchive, WalletEntry},
+    client::PolicyUpdate,
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+fn send(context: &mut TestContext, ix: Instruction) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn archive_policy_ix(
+    context: &TestContext,
+    list_config: &Pubkey,
+    seed: &Pubkey,
+    audit_log: Option<&Pubkey>,
+) -> Instruction {
+    let (policy_archive, _) = PolicyArchive::find_pda(list_config, seed);
+
+    token_acl_gate_client::instructions::ArchivePolicyBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .policy_archive(policy_archive)
+        .seed(*seed)
+        .add_remaining_accounts(
+            &audit_log
+                .map(|audit_log| AccountMeta::new_readonly(*audit_log, false))
+                .into_iter()
+                .collect::<Vec<_>>(),
+        )
+        .instruction()
+}
+
+fn archive_policy(context: &mut TestContext, list_config: &Pubkey) -> Pubkey {
+    let seed = Pubkey::new_unique();
+    let ix = archive_policy_ix(context, list_config, &seed, None);
+    assert!(send(context, ix).is_ok());
+
+    PolicyArchive::find_pda(list_config, &seed).0
+}
+
+fn get_archive(context: &TestContext, policy_archive: &Pubkey) -> PolicyArchive {
+    let account = context.vm.get_account(policy_archive).unwrap();
+    PolicyArchive::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn archives_the_policy() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let policy_archive = archive_policy(&mut context, &list_config);
+
+    let archive = get_archive(&context, &policy_archive);
+    assert_eq!(archive.list_config, list_config);
+    assert_eq!(archive.slot, context.vm.get_sysvar::<Clock>().slot);
+    assert_eq!(archive.membership_hash, [0; 32]);
+    assert_eq!(archive.membership_records, 0);
+
+    let live = context.vm.get_account(&list_config).unwrap();
+    assert_eq!(archive.policy.as_slice(), live.data.as_slice());
+    let policy = ListConfig::from_bytes(&archive.policy).unwrap();
+    assert_eq!(policy.mode, Mode::Block as u8);
+    assert_eq!(policy.wallets_count, 1);
+}
+
+#[tokio::test]
+async fn keeps_the_snapshot_when_the_list_changes() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let policy_archive = archive_policy(&mut context, &list_config);
+
+    let _ = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+
+    let policy = ListConfig::from_bytes(&get_archive(&context, &policy_archive).policy).unwrap();
+    assert_eq!(policy.wallets_count, 0);
+}
+
+#[tokio::test]
+async fn archives_the_membership_hash() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let (audit_log, _) = AuditLog::find_pda(&list_config);
+    let ix = token_acl_gate_client::instructions::CreateAuditLogBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .audit_log(audit_log)
+        .instruction();
+    assert!(send(&mut context, ix).is_ok());
+
+    let wallet = Pubkey::new_unique();
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet);
+    let ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .wallet(wallet)
+        .wallet_entry(wallet_entry)
+        .add_remaining_account(AccountMeta::new(audit_log, false))
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(false)
+        .instruction();
+    assert!(send(&mut context, ix).is_ok());
+
+    let seed = Pubkey::new_unique();
+    let ix = archive_policy_ix(&context, &list_config, &seed, Some(&audit_log));
+    assert!(send(&mut context, ix).is_ok());
+
+    let log = AuditLog::from_bytes(&context.vm.get_account(&audit_log).unwrap().data).unwrap();
+    let archive = get_archive(&context, &PolicyArchive::find_pda(&list_config, &seed).0);
+    assert_eq!(archive.membership_hash, log.head);
+    assert_eq!(archive.membership_records, 1);
+}
+
+#[tokio::test]
+async fn rejects_non_authority() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let mut ix = archive_policy_ix(&context, &list_config, &Pubkey::new_unique(), None);
+    let other = Keypair::new();
+    ix.accounts[0] = AccountMeta::new_readonly(other.pubkey(), true);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), other],
+        context.vm.latest_blockhash(),
+    );
+    assert_eq!(
+        context.vm.send_transaction(tx).unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn no_instruction_mutates_the_archive() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list_config, &Pubkey::new_unique());
+    let seed = Pubkey::new_unique();
+    let ix = archive_policy_ix(&context, &list_config, &seed, None);
+    assert!(send(&mut context, ix).is_ok());
+
+    let (policy_archive, _) = PolicyArchive::find_pda(&list_config, &seed);
+    let before = context.vm.get_account(&policy_archive).unwrap();
+    let authority = context.auth.pubkey();
+
+    let attempts = [
+        // archiving again into the same account
+        archive_policy_ix(&context, &list_config, &seed, None),
+        // the archive in place of the list
+        token_acl_gate_client::instructions::UpdateModeBuilder::new()
+            .authority(authority)
+            .list_config(policy_archive)
+            .mode(Mode::Block)
+            .acknowledge(false)
+            .eoa_grace_period_secs(0)
+            .instruction(),
+        PolicyUpdate {
+            max_wallets: Some(1),
+            ..Default::default()
+        }
+        .builder()
+        .authority(authority)
+        .list_config(policy_archive)
+        .instruction(),
+        token_acl_gate_client::instructions::UpdateMerkleRootBuilder::new()
+            .authority(authority)
+            .list_config(policy_archive)
+            .merkle_root([1; 32])
+            .instruction(),
+        token_acl_gate_client::instructions::DeleteListBuilder::new()
+            .authority(authority)
+            .list_config(policy_archive)
+            .instruction(),
+        // the archive in place of a wallet entry
+        token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+            .authority(authority)
+            .list_config(list_config)
+            .wallet_entry(policy_archive)
+            .instruction(),
+        // the archive as a trailing account
+        token_acl_gate_client::instructions::RemoveWalletBuilder::new()
+            .authority(authority)
+            .list_config(list_config)
+            .wallet_entry(wallet_entry)
+            .add_remaining_account(AccountMeta::new(policy_archive, false))
+            .instruction(),
+    ];
+
+    for ix in attempts {
+        context.vm.expire_blockhash();
+        assert!(send(&mut context, ix).is_err());
+        assert_eq!(context.vm.get_account(&policy_archive).unwrap(), before);
+    }
+}
@@ -2,17 +2,24 @@ use litesvm::types::TransactionResult;
 use litesvm::LiteSVM;
 use solana_instruction::{AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
+use solana_sdk::account::Account;
+use solana_sdk::program_option::COption;
+use solana_sdk::program_pack::Pack;
 use solana_sdk::transaction::Transaction;
 use solana_sdk::{signature::Keypair, signer::Signer};
 use solana_system_interface::instruction::create_account;
 use solana_system_interface::program::ID;
 use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
 use spl_associated_token_account_client::instruction::create_associated_token_account;
+use spl_discriminator::SplDiscriminate;
 use spl_token_2022::extension::default_account_state::instruction::initialize_default_account_state;
-use spl_token_2022::extension::ExtensionType;
+use spl_token_2022::extension::{transfer_hook, ExtensionType};
 use spl_token_2022::instruction::initialize_mint2;
-use spl_token_2022::state::{AccountState, Mint};
-use token_acl_gate_client::types::Mode;
+use spl_token_2022::state::{Account as TokenAccount, AccountState, Mint};
+use token_acl_gate_client::{client::DiagnoseThawReturnData, types::Mode};
+use token_acl_interface::instruction::{
+    CanFreezePermissionlessInstruction, CanThawPermissionlessInstruction,
+};
 
 pub struct TestContext {
     pub vm: LiteSVM,
@@ -20,6 +27,32 @@ pub struct TestContext {
     pub auth: Keypair,
 }
 
+/// Optional list settings, zeroed by default.
+#[derive(Default)]
+pub struct ListOptions {
+    pub namespace: [u8; 16],
+    pub enroll_window_secs: u64,
+    pub max_enrolls_per_window: u32,
+    pub key_by_token_account: bool,
+    pub warmup_secs: u64,
+    pub token_program: Pubkey,
+    pub transfer_hook_check: u8,
+    pub appeal_period_secs: u64,
+    pub register: bool,
+    pub require_nonzero_balance: bool,
+    pub allowed_window_start: u32,
+    pub allowed_window_end: u32,
+    pub require_authority_cosign: bool,
+    pub allow_threshold: u8,
+    pub allow_freeze_authority_delegate: bool,
+    pub strict_block_mode: bool,
+    pub allow_precedence: bool,
+    pub sunset_at: i64,
+    pub max_wallets: u32,
+    pub allow_multisig_owner: bool,
+    pub owner_extensions: u8,
+}
+
 pub struct TokenContext {
     pub mint: Pubkey,
     pub auth: Keypair,
@@ -33,6 +66,12 @@ impl Default for TestContext {
 
 impl TestContext {
     pub fn new() -> Self {
+        Self::new_with_transfer_hook(None)
+    }
+
+    /// Sets up a context whose mint also has a transfer hook extension pointing
+    /// at `transfer_hook_program_id`.
+    pub fn new_with_transfer_hook(transfer_hook_program_id: Option<Pubkey>) -> Self {
         let mut vm = LiteSVM::new();
 
         // current path
@@ -55,21 +94,30 @@ impl TestContext {
 
         let _ = vm.airdrop(&auth_pubkey, 1_000_000_000_000);
 
-        let token = Self::create_token(&mut vm);
+        let token = Self::create_token_with_transfer_hook(&mut vm, transfer_hook_program_id);
 
         Self { vm, token, auth }
     }
 
     pub fn create_token(vm: &mut LiteSVM) -> TokenContext {
+        Self::create_token_with_transfer_hook(vm, None)
+    }
+
+    pub fn create_token_with_transfer_hook(
+        vm: &mut LiteSVM,
+        transfer_hook_program_id: Option<Pubkey>,
+    ) -> TokenContext {
         let auth = Keypair::new();
         let auth_pubkey = auth.pubkey();
 
         let res = vm.airdrop(&auth_pubkey, 1_000_000_000_000);
         assert!(res.is_ok());
 
-        let mint_size =
-            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::DefaultAccountState])
-                .unwrap();
+        let mut extensions = vec![ExtensionType::DefaultAccountState];
+        if transfer_hook_program_id.is_some() {
+            extensions.push(ExtensionType::TransferHook);
+        }
+        let mint_size = ExtensionType::try_calculate_account_len::<Mint>(&extensions).unwrap();
         let mint_kp = Keypair::new();
         let mint_pk = mint_kp.pubkey();
         let token_program_id = &spl_token_2022::ID;
@@ -96,9 +144,23 @@ impl TestContext {
         )
         .unwrap();
 
+        let mut ixs = vec![ix1, ix2];
+        if let Some(program_id) = transfer_hook_program_id {
+            ixs.push(
+                transfer_hook::instruction::initialize(
+                    token_program_id,
+                    &mint_pk,
+                    Some(auth_pubkey),
+                    Some(program_id),
+                )
+                .unwrap(),
+            );
+        }
+        ixs.push(ix3);
+
         let block_hash = vm.latest_blockhash();
         let tx = Transaction::new_signed_with_payer(
-            &[ix1, ix2, ix3],
+            &ixs,
             Some(&payer_pk),
             &[auth.insecure_clone(), mint_kp],
             block_hash,
@@ -128,9 +190,9 @@ impl TestContext {
         }
 
         let token_account =
-            get_associated_token_address_with_program_id(&owner, mint, token_program_id);
+            get_associated_token_address_with_program_id(owner, mint, token_program_id);
 
-        let ix = create_associated_token_account(&payer_pk, &owner, mint, token_program_id);
+        let ix = create_associated_token_account(&payer_pk, owner, mint, token_program_id);
 
         let block_hash = vm.latest_blockhash();
         let tx = Transaction::new_signed_with_payer(
@@ -166,18 +228,76 @@ impl TestContext {
     }
 
     pub fn create_list(&mut self, mode: Mode) -> Pubkey {
+        self.create_list_in_namespace(mode, [0; 16])
+    }
+
+    pub fn create_list_in_namespace(&mut self, mode: Mode, namespace: [u8; 16]) -> Pubkey {
+        self.create_list_with_config(
+            mode,
+            ListOptions {
+                namespace,
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn create_list_with_enroll_rate(
+        &mut self,
+        mode: Mode,
+        enroll_window_secs: u64,
+        max_enrolls_per_window: u32,
+    ) -> Pubkey {
+        self.create_list_with_config(
+            mode,
+            ListOptions {
+                enroll_window_secs,
+                max_enrolls_per_window,
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn create_list_with_config(&mut self, mode: Mode, options: ListOptions) -> Pubkey {
         let seed = Pubkey::new_unique();
 
         let (list_config_address, _) =
             token_acl_gate_client::accounts::ListConfig::find_pda(&self.auth.pubkey(), &seed);
 
-        let ix = token_acl_gate_client::instructions::CreateListBuilder::new()
+        let mut builder = token_acl_gate_client::instructions::CreateListBuilder::new();
+        builder
             .authority(self.auth.pubkey())
             .payer(self.auth.pubkey())
             .list_config(list_config_address)
             .mode(mode)
             .seed(seed)
-            .instruction();
+            .namespace(options.namespace)
+            .enroll_window_secs(options.enroll_window_secs)
+            .max_enrolls_per_window(options.max_enrolls_per_window)
+            .key_by_token_account(options.key_by_token_account)
+            .warmup_secs(options.warmup_secs)
+            .token_program(options.token_program)
+            .transfer_hook_check(options.transfer_hook_check)
+            .appeal_period_secs(options.appeal_period_secs)
+            .require_nonzero_balance(options.require_nonzero_balance)
+            .allowed_window_start(options.allowed_window_start)
+            .allowed_window_end(options.allowed_window_end)
+            .require_authority_cosign(options.require_authority_cosign)
+            .allow_threshold(options.allow_threshold)
+            .allow_freeze_authority_delegate(options.allow_freeze_authority_delegate)
+            .strict_block_mode(options.strict_block_mode)
+            .allow_precedence(options.allow_precedence)
+            .sunset_at(options.sunset_at)
+            .max_wallets(options.max_wallets)
+            .allow_multisig_owner(options.allow_multisig_owner)
+            .owner_extensions(options.owner_extensions);
+
+        if options.register {
+            let (list_registry, _) =
+                token_acl_gate_client::accounts::ListRegistry::find_pda(&self.auth.pubkey());
+            builder.add_remaining_account(AccountMeta::new(list_registry, false));
+        }
+
+        let ix = builder.instruction();
 
         let tx = Transaction::new_signed_with_payer(
             &[ix],
@@ -227,9 +347,93 @@ impl TestContext {
         extra_metas
     }
 
+    /// SetupFreezeExtraMetas, writing the CanFreeze extra metas of the lists.
+    pub fn setup_freeze_extra_metas(&mut self, lists: &[Pubkey]) -> Pubkey {
+        let (mint_cfg_pk, _) = token_acl_client::accounts::MintConfig::find_pda(&self.token.mint);
+
+        let freeze_extra_metas = token_acl_interface::get_freeze_extra_account_metas_address(
+            &self.token.mint,
+            &token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID,
+        );
+
+        let ix = token_acl_gate_client::instructions::SetupFreezeExtraMetasBuilder::new()
+            .authority(self.token.auth.pubkey())
+            .payer(self.token.auth.pubkey())
+            .mint(self.token.mint)
+            .freeze_extra_metas(freeze_extra_metas)
+            .token_acl_mint_config(mint_cfg_pk)
+            .add_remaining_accounts(
+                lists
+                    .iter()
+                    .map(|list| AccountMeta::new_readonly(*list, false))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            )
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.token.auth.pubkey()),
+            &[self.token.auth.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+
+        let res = self.vm.send_transaction(tx);
+        assert!(res.is_ok());
+
+        freeze_extra_metas
+    }
+
     pub fn add_wallet_to_list(&mut self, list: &Pubkey, wallet_address: &Pubkey) -> Pubkey {
-        let (wallet_entry, _) =
-            token_acl_gate_client::accounts::WalletEntry::find_pda(&list, &wallet_address);
+        let res = self.try_add_wallet_to_list(list, wallet_address);
+        assert!(res.is_ok());
+
+        token_acl_gate_client::accounts::WalletEntry::find_pda(
+            &self.list_namespace(list),
+            list,
+            wallet_address,
+        )
+        .0
+    }
+
+    pub fn try_add_wallet_to_list(
+        &mut self,
+        list: &Pubkey,
+        wallet_address: &Pubkey,
+    ) -> TransactionResult {
+        self.try_add_wallet_to_list_with_expiry(list, wallet_address, 0)
+    }
+
+    pub fn try_add_wallet_to_list_with_expiry(
+        &mut self,
+        list: &Pubkey,
+        wallet_address: &Pubkey,
+        expires_at: i64,
+    ) -> TransactionResult {
+        self.try_add_wallet_to_list_with(list, wallet_address, expires_at, [0; 32])
+    }
+
+    pub fn try_add_wallet_to_list_with_label(
+        &mut self,
+        list: &Pubkey,
+        wallet_address: &Pubkey,
+        label: [u8; 32],
+    ) -> TransactionResult {
+        self.try_add_wallet_to_list_with(list, wallet_address, 0, label)
+    }
+
+    fn try_add_wallet_to_list_with(
+        &mut self,
+        list: &Pubkey,
+        wallet_address: &Pubkey,
+        expires_at: i64,
+        label: [u8; 32],
+    ) -> TransactionResult {
+        let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+            &self.list_namespace(list),
+            list,
+            wallet_address,
+        );
 
         let ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
             .authority(self.auth.pubkey())
@@ -237,6 +441,9 @@ impl TestContext {
             .list_config(*list)
             .wallet(*wallet_address)
             .wallet_entry(wallet_entry)
+            .expires_at(expires_at)
+            .label(label)
+            .idempotent(false)
             .instruction();
 
         let tx = Transaction::new_signed_with_payer(
@@ -245,10 +452,105 @@ impl TestContext {
             &[self.auth.insecure_clone()],
             self.vm.latest_blockhash(),
         );
-        let res = self.vm.send_transaction(tx);
-        assert!(res.is_ok());
+        self.vm.send_transaction(tx)
+    }
 
-        wallet_entry
+    /// Adds `wallets` to `list` in a single batch, returning their entries.
+    pub fn try_add_wallets_to_list(
+        &mut self,
+        list: &Pubkey,
+        wallets: &[Pubkey],
+    ) -> (TransactionResult, Vec<Pubkey>) {
+        let namespace = self.list_namespace(list);
+        let wallet_entries = wallets
+            .iter()
+            .map(|wallet| {
+                token_acl_gate_client::accounts::WalletEntry::find_pda(&namespace, list, wallet).0
+            })
+            .collect::<Vec<_>>();
+
+        let remaining_accounts = wallet_entries
+            .iter()
+            .map(|wallet_entry| AccountMeta::new(*wallet_entry, false))
+            .collect::<Vec<_>>();
+
+        let ix = token_acl_gate_client::instructions::AddWalletBatchBuilder::new()
+            .authority(self.auth.pubkey())
+            .payer(self.auth.pubkey())
+            .list_config(*list)
+            .wallets(wallets.to_vec())
+            .add_remaining_accounts(&remaining_accounts)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.auth.pubkey()),
+            &[self.auth.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        (self.vm.send_transaction(tx), wallet_entries)
+    }
+
+    pub fn enqueue_report(
+        &mut self,
+        reporter: &Keypair,
+        list: &Pubkey,
+        wallet_address: &Pubkey,
+    ) -> TransactionResult {
+        let (review_queue, _) = token_acl_gate_client::accounts::ReviewQueue::find_pda(list);
+
+        let ix = token_acl_gate_client::instructions::EnqueueReportBuilder::new()
+            .reporter(reporter.pubkey())
+            .list_config(*list)
+            .review_queue(review_queue)
+            .wallet(*wallet_address)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&reporter.pubkey()),
+            &[reporter.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        self.vm.send_transaction(tx)
+    }
+
+    pub fn drain_queue(
+        &mut self,
+        list: &Pubkey,
+        confirmed: u16,
+        wallet_entries: &[Pubkey],
+    ) -> TransactionResult {
+        let (review_queue, _) = token_acl_gate_client::accounts::ReviewQueue::find_pda(list);
+
+        let remaining_accounts = wallet_entries
+            .iter()
+            .map(|wallet_entry| AccountMeta::new(*wallet_entry, false))
+            .collect::<Vec<_>>();
+
+        let ix = token_acl_gate_client::instructions::DrainQueueBuilder::new()
+            .authority(self.auth.pubkey())
+            .payer(self.auth.pubkey())
+            .list_config(*list)
+            .review_queue(review_queue)
+            .confirmed(confirmed)
+            .add_remaining_accounts(&remaining_accounts)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.auth.pubkey()),
+            &[self.auth.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        self.vm.send_transaction(tx)
+    }
+
+    pub fn list_namespace(&self, list: &Pubkey) -> [u8; 16] {
+        let account = self.vm.get_account(list).unwrap();
+        token_acl_gate_client::accounts::ListConfig::from_bytes(&account.data)
+            .unwrap()
+            .namespace
     }
 
     pub async fn get_thaw_permissionless_ix(
@@ -299,6 +601,138 @@ impl TestContext {
         self.vm.send_transaction(tx)
     }
 
+    /// Calls the gate program's thaw check directly, bypassing token-acl, so
+    /// tests can hand it arbitrary (list, wallet_entry) pairs.
+    pub fn can_thaw_permissionless(
+        &mut self,
+        owner: &Pubkey,
+        token_account: &Pubkey,
+        pairs: &[(Pubkey, Pubkey)],
+    ) -> TransactionResult {
+        let authority = self.auth.insecure_clone();
+        self.can_thaw_permissionless_signed_by(&authority, owner, token_account, pairs)
+    }
+
+    pub fn can_thaw_permissionless_signed_by(
+        &mut self,
+        authority: &Keypair,
+        owner: &Pubkey,
+        token_account: &Pubkey,
+        pairs: &[(Pubkey, Pubkey)],
+    ) -> TransactionResult {
+        let ix = self.can_thaw_permissionless_instruction(
+            &authority.pubkey(),
+            owner,
+            token_account,
+            pairs,
+        );
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&authority.pubkey()),
+            &[authority.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        self.vm.send_transaction(tx)
+    }
+
+    pub fn can_thaw_permissionless_instruction(
+        &self,
+        authority: &Pubkey,
+        owner: &Pubkey,
+        token_account: &Pubkey,
+        pairs: &[(Pubkey, Pubkey)],
+    ) -> Instruction {
+        let (mint_cfg_pk, _) = token_acl_client::accounts::MintConfig::find_pda(&self.token.mint);
+        let extra_metas = token_acl_interface::get_thaw_extra_account_metas_address(
+            &self.token.mint,
+            &token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID,
+        );
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*token_account, false),
+            AccountMeta::new_readonly(self.token.mint, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(mint_cfg_pk, false),
+            AccountMeta::new_readonly(extra_metas, false),
+        ];
+        for (list, wallet_entry) in pairs {
+            accounts.push(AccountMeta::new_readonly(*list, false));
+            accounts.push(AccountMeta::new_readonly(*wallet_entry, false));
+        }
+
+        Instruction {
+            program_id: token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID,
+            accounts,
+            data: CanThawPermissionlessInstruction::SPL_DISCRIMINATOR_SLICE.to_vec(),
+        }
+    }
+
+    /// CanFreezePermissionless, called with the same accounts as a thaw but
+    /// the freeze extra metas.
+    pub fn can_freeze_permissionless(
+        &mut self,
+        owner: &Pubkey,
+        token_account: &Pubkey,
+        pairs: &[(Pubkey, Pubkey)],
+    ) -> TransactionResult {
+        let authority = self.auth.insecure_clone();
+        let mut ix = self.can_thaw_permissionless_instruction(
+            &authority.pubkey(),
+            owner,
+            token_account,
+            pairs,
+        );
+        ix.accounts[5].pubkey = token_acl_interface::get_freeze_extra_account_metas_address(
+            &self.token.mint,
+            &token_acl_gate_client::programs::TOKEN_ACL_GATE_PROGRAM_ID,
+        );
+        ix.data = CanFreezePermissionlessInstruction::SPL_DISCRIMINATOR_SLICE.to_vec();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&authority.pubkey()),
+            &[authority.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        self.vm.send_transaction(tx)
+    }
+
+    /// Runs `DiagnoseThaw` over the pairs and decodes its verdicts.
+    pub fn diagnose_thaw(
+        &mut self,
+        owner: &Pubkey,
+        token_account: &Pubkey,
+        pairs: &[(Pubkey, Pubkey)],
+    ) -> DiagnoseThawReturnData {
+        let ix = token_acl_gate_client::instructions::DiagnoseThawBuilder::new()
+            .token_account(*token_account)
+            .mint(self.token.mint)
+            .owner(*owner)
+            .add_remaining_accounts(
+                &pairs
+                    .iter()
+                    .flat_map(|(list, wallet_entry)| {
+                        [
+                            AccountMeta::new_readonly(*list, false),
+                            AccountMeta::new_readonly(*wallet_entry, false),
+                        ]
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.auth.pubkey()),
+            &[self.auth.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        let meta = self.vm.send_transaction(tx).unwrap();
+        DiagnoseThawReturnData::from_bytes(&meta.return_data.data).unwrap()
+    }
+
     pub fn setup_token_acl(&mut self) -> Pubkey {
         let (mint_cfg_pk, _) = token_acl_client::accounts::MintConfig::find_pda(&self.token.mint);
 
@@ -330,4 +764,108 @@ impl TestContext {
 
         mint_cfg_pk
     }
+
+    /// Stores raw token account data, e.g. from [`TokenAccountDataBuilder`],
+    /// at a new address owned by token-2022.
+    pub fn set_token_account(&mut self, data: Vec<u8>) -> Pubkey {
+        self.set_token_account_owned_by(data, &spl_token_2022::ID)
+    }
+
+    /// Stores raw token account data at a new address owned by
+    /// `token_program`.
+    pub fn set_token_account_owned_by(&mut self, data: Vec<u8>, token_program: &Pubkey) -> Pubkey {
+        let address = Pubkey::new_unique();
+        let res = self.vm.set_account(
+            address,
+            Account {
+                lamports: 1_000_000_000,
+                data,
+                owner: *token_program,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+        assert!(res.is_ok());
+
+        address
+    }
+}
+
+/// Builds the data of a frozen token-2022 account with an arbitrary set of
+/// extensions: the base account, the account type byte and one TLV entry per
+/// extension, in insertion order.
+pub struct TokenAccountDataBuilder {
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+    delegate: Option<Pubkey>,
+    extensions: Vec<(u16, Vec<u8>)>,
+}
+
+impl TokenAccountDataBuilder {
+    pub fn new(mint: &Pubkey, owner: &Pubkey) -> Self {
+        Self {
+            mint: *mint,
+            owner: *owner,
+            amount: 0,
+            delegate: None,
+            extensions: Vec::new(),
+        }
+    }
+
+    pub fn amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    pub fn delegate(mut self, delegate: &Pubkey) -> Self {
+        self.delegate = Some(*delegate);
+        self
+    }
+
+    pub fn immutable_owner(self) -> Self {
+        self.extension(ExtensionType::ImmutableOwner as u16, &[])
+    }
+
+    pub fn non_transferable(self) -> Self {
+        self.extension(ExtensionType::NonTransferableAccount as u16, &[])
+    }
+
+    pub fn extension(mut self, extension_type: u16, data: &[u8]) -> Self {
+        self.extensions.push((extension_type, data.to_vec()));
+        self
+    }
+
+    /// Base account only, as laid out by the original SPL Token program.
+    pub fn build_legacy(&self) -> Vec<u8> {
+        let mut data = self.build();
+        data.truncate(TokenAccount::LEN);
+        data
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let account = TokenAccount {
+            mint: self.mint,
+            owner: self.owner,
+            amount: self.amount,
+            delegate: self.delegate.into(),
+            state: AccountState::Frozen,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+
+        let mut data = vec![0u8; TokenAccount::LEN];
+        TokenAccount::pack(account, &mut data).unwrap();
+
+        // account type, followed by type (2) + length (2) + value entries
+        data.push(spl_token_2022::extension::AccountType::Account as u8);
+        for (extension_type, value) in &self.extensions {
+            data.extend_from_slice(&extension_type.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+
+        data
+    }
 }
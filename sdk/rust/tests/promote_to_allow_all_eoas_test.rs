@@ -0,0 +1,297 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::AccountMeta;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{AuditLog, AuthoritySet, ListConfig},
+    types::Mode,
+};
+
+use crate::program_test::{ListOptions, TestContext};
+
+fn promote(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    wallet_entries: &[Pubkey],
+) -> TransactionResult {
+    promote_with(context, authority, list_config, &[], wallet_entries, &[])
+}
+
+/// Promotes with `leading_accounts` passed before the entries, such as the
+/// list authority receiving the rent, and `trailing_accounts` after them.
+fn promote_with(
+    context: &mut TestContext,
+    authority: &Keypair,
+    list_config: &Pubkey,
+    leading_accounts: &[Pubkey],
+    wallet_entries: &[Pubkey],
+    trailing_accounts: &[Pubkey],
+) -> TransactionResult {
+    let remaining_accounts = leading_accounts
+        .iter()
+        .chain(wallet_entries)
+        .chain(trailing_accounts)
+        .map(|account| AccountMeta::new(*account, false))
+        .collect::<Vec<_>>();
+
+    let ix = token_acl_gate_client::instructions::PromoteToAllowAllEoasBuilder::new()
+        .authority(authority.pubkey())
+        .list_config(*list_config)
+        .add_remaining_accounts(&remaining_accounts)
+        .instruction();
+
+    // a separate fee payer keeps fees out of the authority's balance
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.token.auth.pubkey()),
+        &[
+            context.token.auth.insecure_clone(),
+            authority.insecure_clone(),
+        ],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn set_delegate(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    delegate: &Pubkey,
+    permissions: u16,
+) -> Pubkey {
+    let (authority_set, _) = AuthoritySet::find_pda(list_config);
+    let ix = token_acl_gate_client::instructions::SetDelegateBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(*list_config)
+        .authority_set(authority_set)
+        .delegate(*delegate)
+        .permissions(permissions)
+        .instruction();
+
+    context.vm.expire_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+
+    authority_set
+}
+
+fn get_list_config(context: &TestContext, list_config: &Pubkey) -> ListConfig {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn reclaims_eoa_entries_and_keeps_pda_entries() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let eoa = Keypair::new().pubkey();
+    let (pda, _) = Pubkey::find_program_address(&[b"pda"], &Pubkey::new_unique());
+    let eoa_entry = context.add_wallet_to_list(&list_config, &eoa);
+    let pda_entry = context.add_wallet_to_list(&list_config, &pda);
+
+    let eoa_entry_lamports = context.vm.get_account(&eoa_entry).unwrap().lamports;
+    let authority_lamports = context.vm.get_balance(&context.auth.pubkey()).unwrap();
+
+    let authority = context.auth.insecure_clone();
+    let res = promote(
+        &mut context,
+        &authority,
+        &list_config,
+        &[eoa_entry, pda_entry],
+    );
+    assert!(res.is_ok());
+
+    let config = get_list_config(&context, &list_config);
+    assert_eq!(config.mode, Mode::AllowAllEoas as u8);
+    assert_eq!(config.wallets_count, 1);
+
+    // the eoa entry's rent went back to the authority
+    assert!(context.vm.get_account(&eoa_entry).is_none());
+    assert_eq!(
+        context.vm.get_balance(&context.auth.pubkey()).unwrap(),
+        authority_lamports + eoa_entry_lamports
+    );
+
+    assert!(context.vm.get_account(&pda_entry).is_some());
+}
+
+#[tokio::test]
+async fn reclaims_in_follow_up_calls() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let eoa_entry = context.add_wallet_to_list(&list_config, &Keypair::new().pubkey());
+
+    let authority = context.auth.insecure_clone();
+    let res = promote(&mut context, &authority, &list_config, &[]);
+    assert!(res.is_ok());
+    assert!(context.vm.get_account(&eoa_entry).is_some());
+
+    let res = promote(&mut context, &authority, &list_config, &[eoa_entry]);
+    assert!(res.is_ok());
+    assert!(context.vm.get_account(&eoa_entry).is_none());
+    assert_eq!(get_list_config(&context, &list_config).wallets_count, 0);
+}
+
+#[tokio::test]
+async fn rejects_block_list() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+
+    let authority = context.auth.insecure_clone();
+    let res = promote(&mut context, &authority, &list_config, &[]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(16))
+    );
+}
+
+#[tokio::test]
+async fn rejects_non_authority() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let other = Keypair::new();
+    let res = promote(&mut context, &other, &list_config, &[]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+    assert_eq!(
+        get_list_config(&context, &list_config).mode,
+        Mode::Allow as u8
+    );
+}
+
+#[tokio::test]
+async fn delegate_needs_update_mode_and_remove() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let eoa_entry = context.add_wallet_to_list(&list_config, &Keypair::new().pubkey());
+
+    // update mode (4) alone isn't enough to close entries
+    let delegate = Keypair::new();
+    let authority = context.auth.pubkey();
+    let authority_set = set_delegate(&mut context, &list_config, &delegate.pubkey(), 4);
+    let res = promote_with(
+        &mut context,
+        &delegate,
+        &list_config,
+        &[authority],
+        &[eoa_entry],
+        &[authority_set],
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+
+    // with remove (2) as well, passing the authority to receive the rent
+    set_delegate(&mut context, &list_config, &delegate.pubkey(), 4 | 2);
+    let res = promote_with(
+        &mut context,
+        &delegate,
+        &list_config,
+        &[],
+        &[eoa_entry],
+        &[authority_set],
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(3))
+    );
+
+    let eoa_entry_lamports = context.vm.get_account(&eoa_entry).unwrap().lamports;
+    let authority_lamports = context.vm.get_balance(&authority).unwrap();
+    let res = promote_with(
+        &mut context,
+        &delegate,
+        &list_config,
+        &[authority],
+        &[eoa_entry],
+        &[authority_set],
+    );
+    assert!(res.is_ok());
+    assert!(context.vm.get_account(&eoa_entry).is_none());
+    assert_eq!(
+        context.vm.get_balance(&authority).unwrap(),
+        authority_lamports + eoa_entry_lamports
+    );
+
+    let config = get_list_config(&context, &list_config);
+    assert_eq!(config.mode, Mode::AllowAllEoas as u8);
+    assert_eq!(config.last_actor, delegate.pubkey());
+}
+
+#[tokio::test]
+async fn records_the_promotion_and_reclaimed_entries() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let eoa_entry = context.add_wallet_to_list(&list_config, &Keypair::new().pubkey());
+
+    let (audit_log, _) = AuditLog::find_pda(&list_config);
+    let ix = token_acl_gate_client::instructions::CreateAuditLogBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .audit_log(audit_log)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+
+    let authority = context.auth.insecure_clone();
+    let res = promote_with(
+        &mut context,
+        &authority,
+        &list_config,
+        &[],
+        &[eoa_entry],
+        &[audit_log],
+    );
+    assert!(res.is_ok());
+
+    // the mode change and the closed entry
+    let account = context.vm.get_account(&audit_log).unwrap();
+    assert_eq!(AuditLog::from_bytes(&account.data).unwrap().count, 2);
+}
+
+#[tokio::test]
+async fn rejects_a_list_with_an_allow_threshold() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            allow_threshold: 2,
+            ..Default::default()
+        },
+    );
+
+    let authority = context.auth.insecure_clone();
+    let res = promote(&mut context, &authority, &list_config, &[]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+    assert_eq!(
+        get_list_config(&context, &list_config).mode,
+        Mode::Allow as u8
+    );
+}
@@ -0,0 +1,143 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::{
+    accounts::{ListConfig, ReviewQueue, WalletEntry},
+    types::Mode,
+};
+
+use crate::program_test::TestContext;
+
+fn new_reporter(context: &mut TestContext) -> Keypair {
+    let reporter = Keypair::new();
+    let res = context.vm.airdrop(&reporter.pubkey(), 1_000_000_000);
+    assert!(res.is_ok());
+    reporter
+}
+
+fn get_queue(context: &TestContext, list_config: &Pubkey) -> ReviewQueue {
+    let (review_queue, _) = ReviewQueue::find_pda(list_config);
+    let account = context.vm.get_account(&review_queue).unwrap();
+    ReviewQueue::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn drains_confirmed_reports_into_block_entries() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Block);
+
+    let wallets = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    for wallet in &wallets {
+        let reporter = new_reporter(&mut context);
+        let res = context.enqueue_report(&reporter, &list_config, wallet);
+        assert!(res.is_ok());
+    }
+
+    let queue = get_queue(&context, &list_config);
+    assert_eq!(queue.list_config, list_config);
+    assert_eq!(queue.len, 3);
+    assert_eq!(&queue.reports[..3], &wallets);
+
+    let entries = wallets.map(|wallet| WalletEntry::find_pda(&[0; 16], &list_config, &wallet).0);
+
+    // confirm the first two reports, dismiss the third
+    let res = context.drain_queue(&list_config, 0b011, &entries[..2]);
+    assert!(res.is_ok());
+
+    assert!(context.vm.get_account(&entries[0]).is_some());
+    assert!(context.vm.get_account(&entries[1]).is_some());
+    assert!(context.vm.get_account(&entries[2]).is_none());
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.wallets_count, 2);
+
+    let queue = get_queue(&context, &list_config);
+    assert_eq!(queue.len, 0);
+}
+
+#[tokio::test]
+async fn ignores_duplicate_reports() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+
+    let reporter = new_reporter(&mut context);
+    assert!(context
+        .enqueue_report(&reporter, &list_config, &wallet)
+        .is_ok());
+
+    let reporter = new_reporter(&mut context);
+    assert!(context
+        .enqueue_report(&reporter, &list_config, &wallet)
+        .is_ok());
+
+    let queue = get_queue(&context, &list_config);
+    assert_eq!(queue.len, 1);
+}
+
+#[tokio::test]
+async fn fails_to_report_to_allow_list() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Allow);
+
+    let reporter = new_reporter(&mut context);
+    let res = context.enqueue_report(&reporter, &list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(16))
+    );
+}
+
+#[tokio::test]
+async fn fails_to_report_to_full_queue() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Block);
+
+    let reporter = new_reporter(&mut context);
+    for _ in 0..16 {
+        let res = context.enqueue_report(&reporter, &list_config, &Pubkey::new_unique());
+        assert!(res.is_ok());
+    }
+
+    let res = context.enqueue_report(&reporter, &list_config, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(19))
+    );
+}
+
+#[tokio::test]
+async fn fails_to_drain_with_stale_confirmations() {
+    let mut context = TestContext::new();
+
+    let list_config = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+
+    let reporter = new_reporter(&mut context);
+    assert!(context
+        .enqueue_report(&reporter, &list_config, &wallet)
+        .is_ok());
+
+    let (entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet);
+
+    // confirms a second report that is not in the queue
+    let res = context.drain_queue(&list_config, 0b11, &[entry]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(14))
+    );
+
+    let queue = get_queue(&context, &list_config);
+    assert_eq!(queue.len, 1);
+}
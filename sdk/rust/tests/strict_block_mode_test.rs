@@ -0,0 +1,108 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext};
+
+fn setup_list(context: &mut TestContext, strict_block_mode: bool) -> Pubkey {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            strict_block_mode,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    list_config
+}
+
+/// Thaws an account of a wallet that isn't on the list, passing `wallet_entry`
+/// as its entry, or the wallet's own entry address when `None`.
+fn can_thaw_with_entry(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallet_entry: Option<Pubkey>,
+) -> TransactionResult {
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let wallet_entry = wallet_entry.unwrap_or_else(|| {
+        WalletEntry::find_pda(
+            &context.list_namespace(list_config),
+            list_config,
+            &wallet.pubkey(),
+        )
+        .0
+    });
+
+    context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(*list_config, wallet_entry)])
+}
+
+#[tokio::test]
+async fn thaws_with_owner_entry_address() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, true);
+
+    let res = can_thaw_with_entry(&mut context, &list_config, None);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn rejects_wrong_uninitialized_entry() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, true);
+
+    let res = can_thaw_with_entry(&mut context, &list_config, Some(Pubkey::new_unique()));
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
+
+#[tokio::test]
+async fn rejects_entry_of_another_wallet() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, true);
+
+    let (other_entry, _) = WalletEntry::find_pda(
+        &context.list_namespace(&list_config),
+        &list_config,
+        &Pubkey::new_unique(),
+    );
+    let res = can_thaw_with_entry(&mut context, &list_config, Some(other_entry));
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
+
+#[tokio::test]
+async fn still_blocks_listed_wallet() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, true);
+
+    let wallet = Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn default_mode_accepts_wrong_uninitialized_entry() {
+    let mut context = TestContext::new();
+    let list_config = setup_list(&mut context, false);
+
+    let res = can_thaw_with_entry(&mut context, &list_config, Some(Pubkey::new_unique()));
+    assert!(res.is_ok());
+}
@@ -0,0 +1,88 @@
+pub mod program_test;
+use solana_sdk::{
+    clock::Clock, instruction::InstructionError, pubkey::Pubkey, signer::Signer,
+    transaction::TransactionError,
+};
+use token_acl_gate_client::{accounts::ListConfig, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const SUNSET_IN_SECS: i64 = 3600;
+
+fn create_sunsetting_list(context: &mut TestContext, mode: Mode) -> Pubkey {
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(
+        mode,
+        ListOptions {
+            sunset_at: now + SUNSET_IN_SECS,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.sunset_at, now + SUNSET_IN_SECS);
+
+    list_config
+}
+
+fn advance_to_sunset(context: &mut TestContext) {
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += SUNSET_IN_SECS;
+    context.vm.set_sysvar::<Clock>(&clock);
+}
+
+#[tokio::test]
+async fn enforces_list_before_sunset() {
+    let mut context = TestContext::new();
+    let list_config = create_sunsetting_list(&mut context, Mode::Allow);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let wallet_entry = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
+        &list_config,
+        &wallet.pubkey(),
+    )
+    .0;
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn thaws_any_wallet_after_sunset() {
+    let mut context = TestContext::new();
+    let _ = create_sunsetting_list(&mut context, Mode::Allow);
+    advance_to_sunset(&mut context);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn thaws_blocked_wallet_after_sunset() {
+    let mut context = TestContext::new();
+    let list_config = create_sunsetting_list(&mut context, Mode::Block);
+
+    let wallet = solana_keypair::Keypair::new();
+    let _ = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+
+    advance_to_sunset(&mut context);
+    context.vm.expire_blockhash();
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
@@ -0,0 +1,148 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, program_pack::Pack, pubkey::Pubkey, signer::Signer,
+    transaction::TransactionError,
+};
+use spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::Account as TokenAccount,
+};
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::{TestContext, TokenAccountDataBuilder};
+
+const CUSTOM_EXTENSION: u16 = 0xFF00;
+
+fn setup(context: &mut TestContext) -> (Pubkey, Keypair) {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    (list_config, Keypair::new())
+}
+
+fn can_thaw(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallet: &Pubkey,
+    data: Vec<u8>,
+) -> Result<(), Option<u32>> {
+    let ta = context.set_token_account(data);
+    let (wallet_entry, _) =
+        token_acl_gate_client::accounts::WalletEntry::find_pda(&[0; 16], list_config, wallet);
+
+    match context.can_thaw_permissionless(wallet, &ta, &[(*list_config, wallet_entry)]) {
+        Ok(_) => Ok(()),
+        Err(res) => match res.err {
+            TransactionError::InstructionError(0, InstructionError::Custom(code)) => {
+                Err(Some(code))
+            }
+            _ => Err(None),
+        },
+    }
+}
+
+#[tokio::test]
+async fn builds_token_account_with_extensions() {
+    let data = TokenAccountDataBuilder::new(&Pubkey::new_unique(), &Pubkey::new_unique())
+        .immutable_owner()
+        .non_transferable()
+        .build();
+
+    let account = StateWithExtensions::<TokenAccount>::unpack(&data).unwrap();
+    assert_eq!(
+        account.get_extension_types().unwrap(),
+        vec![
+            ExtensionType::ImmutableOwner,
+            ExtensionType::NonTransferableAccount
+        ]
+    );
+}
+
+#[tokio::test]
+async fn finds_immutable_owner_extension() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey())
+        .immutable_owner()
+        .build();
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), data),
+        Ok(())
+    );
+}
+
+#[tokio::test]
+async fn finds_immutable_owner_extension_after_other_extensions() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey())
+        .non_transferable()
+        .extension(CUSTOM_EXTENSION, &[1, 2, 3])
+        .immutable_owner()
+        .build();
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), data),
+        Ok(())
+    );
+}
+
+#[tokio::test]
+async fn fails_without_immutable_owner_extension() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey())
+        .non_transferable()
+        .extension(CUSTOM_EXTENSION, &[1, 2, 3])
+        .build();
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), data),
+        Err(Some(10))
+    );
+}
+
+#[tokio::test]
+async fn fails_with_extension_length_past_the_data() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+
+    let mut data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey())
+        .extension(CUSTOM_EXTENSION, &[0; 4])
+        .immutable_owner()
+        .build();
+
+    // the custom extension now claims to span the immutable owner entry
+    let length_offset = TokenAccount::LEN + 1 + 2;
+    data[length_offset..length_offset + 2].copy_from_slice(&u16::MAX.to_le_bytes());
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), data),
+        Err(Some(10))
+    );
+}
+
+#[tokio::test]
+async fn fails_with_truncated_extension_header() {
+    let mut context = TestContext::new();
+    let (list_config, wallet) = setup(&mut context);
+
+    let mut data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey())
+        .non_transferable()
+        .immutable_owner()
+        .build();
+
+    // drop the length of the immutable owner entry, leaving only its type
+    data.truncate(data.len() - 2);
+
+    assert_eq!(
+        can_thaw(&mut context, &list_config, &wallet.pubkey(), data),
+        Err(Some(10))
+    );
+}
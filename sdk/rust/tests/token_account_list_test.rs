@@ -0,0 +1,66 @@
+pub mod program_test;
+use solana_sdk::{instruction::InstructionError, signer::Signer, transaction::TransactionError};
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::{ListOptions, TestContext};
+
+fn token_account_list_options() -> ListOptions {
+    ListOptions {
+        key_by_token_account: true,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn thaws_token_account_on_token_account_list() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(Mode::Allow, token_account_list_options());
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let _ = context.add_wallet_to_list(&list_config, &ta);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_thaw_with_owner_entry_on_token_account_list() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(Mode::Allow, token_account_list_options());
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let owner_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, owner_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
+
+#[tokio::test]
+async fn fails_to_thaw_blocked_token_account() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(Mode::Block, token_account_list_options());
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let _ = context.add_wallet_to_list(&list_config, &ta);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+}
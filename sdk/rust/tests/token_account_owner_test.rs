@@ -0,0 +1,125 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    account::Account, instruction::InstructionError, program_option::COption, program_pack::Pack,
+    pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use spl_token_2022::state::{Account as TokenAccount, AccountState};
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::TestContext;
+
+fn set_base_token_account(context: &mut TestContext, owner: &Pubkey) -> Pubkey {
+    let acc = TokenAccount {
+        mint: context.token.mint,
+        owner: *owner,
+        amount: 0,
+        delegate: COption::None,
+        state: AccountState::Frozen,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+
+    let mut data = vec![0u8; TokenAccount::LEN];
+    TokenAccount::pack(acc, &mut data).unwrap();
+
+    let address = Pubkey::new_unique();
+    let res = context.vm.set_account(
+        address,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: spl_token_2022::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    assert!(res.is_ok());
+
+    address
+}
+
+fn custom_error(res: litesvm::types::TransactionResult) -> Option<u32> {
+    match res.unwrap_err().err {
+        TransactionError::InstructionError(0, InstructionError::Custom(code)) => Some(code),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn reads_owner_from_extended_token_account() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
+        &list_config,
+        &wallet.pubkey(),
+    );
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+
+    let other = Keypair::new();
+    let res = context.can_thaw_permissionless(&other.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(custom_error(res), Some(17));
+}
+
+#[tokio::test]
+async fn reads_owner_from_base_token_account() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let ta = set_base_token_account(&mut context, &wallet.pubkey());
+    let (wallet_entry, _) = token_acl_gate_client::accounts::WalletEntry::find_pda(
+        &[0; 16],
+        &list_config,
+        &wallet.pubkey(),
+    );
+
+    // the owner matches, so the check moves on to the missing immutable owner extension
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(custom_error(res), Some(10));
+
+    let other = Keypair::new();
+    let res = context.can_thaw_permissionless(&other.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(custom_error(res), Some(17));
+}
+
+#[tokio::test]
+async fn fails_to_read_owner_from_short_account() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let ta = Pubkey::new_unique();
+    let res = context.vm.set_account(
+        ta,
+        Account {
+            lamports: 1_000_000_000,
+            data: vec![0u8; 63],
+            owner: spl_token_2022::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    assert!(res.is_ok());
+
+    let res = context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[]);
+    assert_eq!(custom_error(res), Some(4));
+}
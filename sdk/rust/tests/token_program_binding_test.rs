@@ -0,0 +1,60 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::{
+    accounts::{ListConfig, WalletEntry},
+    types::Mode,
+};
+
+use crate::program_test::{ListOptions, TestContext};
+
+fn create_bound_list(context: &mut TestContext, token_program: Pubkey) -> Pubkey {
+    context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            token_program,
+            ..Default::default()
+        },
+    )
+}
+
+#[tokio::test]
+async fn thaws_with_matching_token_program() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = create_bound_list(&mut context, spl_token_2022::ID);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.token_program, spl_token_2022::ID);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_thaw_with_mismatched_token_program() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = create_bound_list(&mut context, Pubkey::new_unique());
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet.pubkey());
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
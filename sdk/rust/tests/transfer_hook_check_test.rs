@@ -0,0 +1,73 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::{
+    accounts::WalletEntry, programs::TOKEN_ACL_GATE_PROGRAM_ID, types::Mode,
+};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const WARN: u8 = 1;
+const ERROR: u8 = 2;
+
+fn setup(transfer_hook_program_id: Pubkey, transfer_hook_check: u8) -> (TestContext, Pubkey) {
+    let mut context = TestContext::new_with_transfer_hook(Some(transfer_hook_program_id));
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            transfer_hook_check,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    (context, list_config)
+}
+
+#[tokio::test]
+async fn thaws_with_matching_transfer_hook() {
+    let (mut context, _) = setup(TOKEN_ACL_GATE_PROGRAM_ID, ERROR);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_thaw_with_mismatched_transfer_hook() {
+    let (mut context, list_config) = setup(Pubkey::new_unique(), ERROR);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet.pubkey());
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(21))
+    );
+}
+
+#[tokio::test]
+async fn warns_on_mismatched_transfer_hook() {
+    let (mut context, list_config) = setup(Pubkey::new_unique(), WARN);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet.pubkey());
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    let meta = res.unwrap();
+    assert!(meta
+        .logs
+        .iter()
+        .any(|log| log.contains("Mint transfer hook mismatch")));
+}
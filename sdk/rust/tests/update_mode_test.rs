@@ -0,0 +1,300 @@
+pub mod program_test;
+use litesvm::types::TransactionResult;
+use solana_instruction::Instruction;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    clock::Clock,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{
+    accounts::{ListConfig, WalletEntry},
+    types::Mode,
+};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const GRACE_PERIOD_SECS: u64 = 3600;
+
+fn update_mode_ix(authority: &Pubkey, list_config: &Pubkey, mode: Mode) -> Instruction {
+    update_mode_ix_with(authority, list_config, mode, false, 0)
+}
+
+fn update_mode_ix_with(
+    authority: &Pubkey,
+    list_config: &Pubkey,
+    mode: Mode,
+    acknowledge: bool,
+    eoa_grace_period_secs: u64,
+) -> Instruction {
+    token_acl_gate_client::instructions::UpdateModeBuilder::new()
+        .authority(*authority)
+        .list_config(*list_config)
+        .mode(mode)
+        .acknowledge(acknowledge)
+        .eoa_grace_period_secs(eoa_grace_period_secs)
+        .instruction()
+}
+
+fn send(context: &mut TestContext, ix: Instruction, authority: &Keypair) -> TransactionResult {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone(), authority.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn get_list_config(context: &TestContext, list_config: &Pubkey) -> ListConfig {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap()
+}
+
+fn get_mode(context: &TestContext, list_config: &Pubkey) -> u8 {
+    get_list_config(context, list_config).mode
+}
+
+#[tokio::test]
+async fn switches_between_every_mode() {
+    for from in Mode::ALL {
+        for to in Mode::ALL {
+            // merkle lists stay merkle lists
+            if from == to || (from == Mode::MerkleAllow) != (to == Mode::MerkleAllow) {
+                continue;
+            }
+
+            let mut context = TestContext::new();
+            let list_config = context.create_list(from);
+
+            // acknowledged, as moving from AllowAllEoas to Allow requires
+            let authority = context.auth.insecure_clone();
+            let ix = update_mode_ix_with(&authority.pubkey(), &list_config, to, true, 0);
+            let res = send(&mut context, ix, &authority);
+            assert!(res.is_ok(), "{from:?} to {to:?}");
+            assert_eq!(get_mode(&context, &list_config), to as u8);
+        }
+    }
+}
+
+#[tokio::test]
+async fn rejects_switching_into_or_out_of_merkle_allow() {
+    for (from, to) in [
+        (Mode::Allow, Mode::MerkleAllow),
+        (Mode::Block, Mode::MerkleAllow),
+        (Mode::MerkleAllow, Mode::Allow),
+        (Mode::MerkleAllow, Mode::AllowAllEoas),
+    ] {
+        let mut context = TestContext::new();
+        let list_config = context.create_list(from);
+
+        let authority = context.auth.insecure_clone();
+        let ix = update_mode_ix_with(&authority.pubkey(), &list_config, to, true, 0);
+        let res = send(&mut context, ix, &authority);
+        assert_eq!(
+            res.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::Custom(16)),
+            "{from:?} to {to:?}"
+        );
+        assert_eq!(get_mode(&context, &list_config), from as u8);
+    }
+}
+
+#[tokio::test]
+async fn reinterprets_entries() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+
+    // the allowed wallet is blocked once the list blocks
+    let authority = context.auth.insecure_clone();
+    let ix = update_mode_ix(&authority.pubkey(), &list_config, Mode::Block);
+    assert!(send(&mut context, ix, &authority).is_ok());
+
+    context.vm.expire_blockhash();
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn rejects_invalid_mode() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let authority = context.auth.insecure_clone();
+    let mut ix = update_mode_ix(&authority.pubkey(), &list_config, Mode::Block);
+    ix.data[1] = Mode::MerkleAllow as u8 + 1;
+
+    let res = send(&mut context, ix, &authority);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+    assert_eq!(get_mode(&context, &list_config), Mode::Allow as u8);
+}
+
+#[tokio::test]
+async fn rejects_leaving_allow_mode_with_threshold() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            allow_threshold: 1,
+            ..Default::default()
+        },
+    );
+
+    let authority = context.auth.insecure_clone();
+    let ix = update_mode_ix(&authority.pubkey(), &list_config, Mode::Block);
+    let res = send(&mut context, ix, &authority);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
+
+#[tokio::test]
+async fn rejects_non_authority() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let other = Keypair::new();
+    let ix = update_mode_ix(&other.pubkey(), &list_config, Mode::Block);
+    let res = send(&mut context, ix, &other);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn requires_acknowledgment_to_stop_allowing_all_eoas() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::AllowAllEoas);
+
+    let authority = context.auth.insecure_clone();
+    let ix = update_mode_ix(&authority.pubkey(), &list_config, Mode::Allow);
+    let res = send(&mut context, ix, &authority);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(41))
+    );
+    assert_eq!(get_mode(&context, &list_config), Mode::AllowAllEoas as u8);
+
+    let ix = update_mode_ix_with(&authority.pubkey(), &list_config, Mode::Allow, true, 0);
+    let meta = send(&mut context, ix, &authority).unwrap();
+    assert_eq!(get_mode(&context, &list_config), Mode::Allow as u8);
+    assert!(meta
+        .logs
+        .iter()
+        .any(|log| log.contains("no longer allows all EOAs")));
+}
+
+#[tokio::test]
+async fn lets_eoas_through_during_the_grace_period() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::AllowAllEoas);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = WalletEntry::find_pda(
+        &context.list_namespace(&list_config),
+        &list_config,
+        &wallet.pubkey(),
+    );
+
+    let authority = context.auth.insecure_clone();
+    let ix = update_mode_ix_with(
+        &authority.pubkey(),
+        &list_config,
+        Mode::Allow,
+        true,
+        GRACE_PERIOD_SECS,
+    );
+    assert!(send(&mut context, ix, &authority).is_ok());
+
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let eoa_grace_until = now + GRACE_PERIOD_SECS as i64;
+    assert_eq!(
+        get_list_config(&context, &list_config).eoa_grace_until,
+        eoa_grace_until
+    );
+
+    // the EOA without an entry still thaws until the grace period ends
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp = eoa_grace_until;
+    context.vm.set_sysvar::<Clock>(&clock);
+    context.vm.expire_blockhash();
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
+
+#[tokio::test]
+async fn ends_the_grace_period_on_the_next_update() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::AllowAllEoas);
+
+    let authority = context.auth.insecure_clone();
+    let ix = update_mode_ix_with(
+        &authority.pubkey(),
+        &list_config,
+        Mode::Allow,
+        true,
+        GRACE_PERIOD_SECS,
+    );
+    assert!(send(&mut context, ix, &authority).is_ok());
+
+    context.vm.expire_blockhash();
+    let ix = update_mode_ix(&authority.pubkey(), &list_config, Mode::Allow);
+    assert!(send(&mut context, ix, &authority).is_ok());
+    assert_eq!(get_list_config(&context, &list_config).eoa_grace_until, 0);
+}
+
+#[tokio::test]
+async fn rejects_a_grace_period_on_other_switches() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+
+    let authority = context.auth.insecure_clone();
+    let ix = update_mode_ix_with(
+        &authority.pubkey(),
+        &list_config,
+        Mode::Allow,
+        true,
+        GRACE_PERIOD_SECS,
+    );
+    let res = send(&mut context, ix, &authority);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+    assert_eq!(get_mode(&context, &list_config), Mode::Block as u8);
+}
@@ -0,0 +1,116 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use token_acl_gate_client::{
+    accounts::{ListConfig, WalletEntry},
+    client::{find_thaw_wallet_entry, list_config_seeds, wallet_entry_seeds},
+    programs::TOKEN_ACL_GATE_PROGRAM_ID,
+    types::Mode,
+};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const NAMESPACE: [u8; 16] = *b"tenant\0\0\0\0\0\0\0\0\0\0";
+
+/// Data of the wallet entry the program created at `address`.
+fn get_wallet_entry(context: &TestContext, address: &Pubkey) -> WalletEntry {
+    let account = context.vm.get_account(address).unwrap();
+    WalletEntry::from_bytes(&account.data).unwrap()
+}
+
+fn get_list_config(context: &TestContext, list_config: &Pubkey) -> ListConfig {
+    let account = context.vm.get_account(list_config).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn finds_owner_entry_created_by_the_program() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            namespace: NAMESPACE,
+            ..Default::default()
+        },
+    );
+
+    let owner = Keypair::new().pubkey();
+    let _ = context.add_wallet_to_list(&list_config, &owner);
+
+    let config = get_list_config(&context, &list_config);
+    let (address, _) = find_thaw_wallet_entry(&list_config, &config, &owner, &Pubkey::new_unique());
+    let entry = get_wallet_entry(&context, &address);
+    assert_eq!(entry.wallet_address, owner);
+    assert_eq!(entry.namespace, NAMESPACE);
+}
+
+#[tokio::test]
+async fn finds_token_account_entry_created_by_the_program() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            key_by_token_account: true,
+            ..Default::default()
+        },
+    );
+
+    let token_account = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list_config, &token_account);
+
+    let config = get_list_config(&context, &list_config);
+    let (address, _) =
+        find_thaw_wallet_entry(&list_config, &config, &Pubkey::new_unique(), &token_account);
+    let entry = get_wallet_entry(&context, &address);
+    assert_eq!(entry.wallet_address, token_account);
+    assert_eq!(entry.list_config, list_config);
+}
+
+/// Address derived from raw `seeds`, as tooling outside Rust would do.
+fn find_address(seeds: &[Vec<u8>]) -> Pubkey {
+    let seeds = seeds.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    Pubkey::find_program_address(&seeds, &TOKEN_ACL_GATE_PROGRAM_ID).0
+}
+
+#[tokio::test]
+async fn wallet_entry_seeds_reproduce_program_address() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list_with_config(
+        Mode::Block,
+        ListOptions {
+            namespace: NAMESPACE,
+            ..Default::default()
+        },
+    );
+
+    let wallet = Keypair::new().pubkey();
+    let _ = context.add_wallet_to_list(&list_config, &wallet);
+
+    let seeds = wallet_entry_seeds(&NAMESPACE, &list_config, &wallet);
+    assert_eq!(
+        seeds,
+        vec![
+            b"wallet_entry".to_vec(),
+            NAMESPACE.to_vec(),
+            list_config.to_bytes().to_vec(),
+            wallet.to_bytes().to_vec(),
+        ]
+    );
+
+    // the program created the entry at the address derived from the seeds
+    let address = find_address(&seeds);
+    let entry = get_wallet_entry(&context, &address);
+    assert_eq!(entry.wallet_address, wallet);
+    assert_eq!(entry.list_config, list_config);
+}
+
+#[tokio::test]
+async fn list_config_seeds_reproduce_program_address() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let config = get_list_config(&context, &list_config);
+    let seeds = list_config_seeds(&config.authority, &config.seed);
+    assert_eq!(seeds[0], b"list_config".to_vec());
+    assert_eq!(find_address(&seeds), list_config);
+}
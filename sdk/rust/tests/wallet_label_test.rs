@@ -0,0 +1,110 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, TransactionError},
+};
+use token_acl_gate_client::{accounts::WalletEntry, types::Mode};
+
+use crate::program_test::TestContext;
+
+fn label(text: &str) -> [u8; 32] {
+    let mut label = [0; 32];
+    label[..text.len()].copy_from_slice(text.as_bytes());
+    label
+}
+
+fn get_entry(context: &TestContext, list_config: &Pubkey, wallet: &Pubkey) -> WalletEntry {
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], list_config, wallet);
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    WalletEntry::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn stores_the_label() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+
+    let res = context.try_add_wallet_to_list_with_label(
+        &list_config,
+        &wallet,
+        label("sanctioned: case 2026-114"),
+    );
+    assert!(res.is_ok());
+
+    let entry = get_entry(&context, &list_config, &wallet);
+    assert_eq!(entry.label, label("sanctioned: case 2026-114"));
+    assert_eq!(entry.label_str(), "sanctioned: case 2026-114");
+    assert_eq!(entry.wallet_address, wallet);
+}
+
+#[tokio::test]
+async fn defaults_to_an_empty_label() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+    let wallet = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list_config, &wallet);
+
+    // v1 data carries no label
+    let other = Pubkey::new_unique();
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &other);
+    let mut ix = token_acl_gate_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .payer(context.auth.pubkey())
+        .list_config(list_config)
+        .wallet(other)
+        .wallet_entry(wallet_entry)
+        .expires_at(0)
+        .label([0; 32])
+        .idempotent(false)
+        .instruction();
+    ix.data = [2, 1].into_iter().chain(0i64.to_le_bytes()).collect();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+
+    for wallet in [wallet, other] {
+        let entry = get_entry(&context, &list_config, &wallet);
+        assert_eq!(entry.label, [0; 32]);
+        assert_eq!(entry.label_str(), "");
+    }
+}
+
+#[tokio::test]
+async fn rejects_a_label_that_is_not_utf8() {
+    let mut context = TestContext::new();
+    let list_config = context.create_list(Mode::Allow);
+
+    let res =
+        context.try_add_wallet_to_list_with_label(&list_config, &Pubkey::new_unique(), [0xff; 32]);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(11))
+    );
+}
+
+#[tokio::test]
+async fn thaws_regardless_of_the_label() {
+    let mut context = TestContext::new();
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let res =
+        context.try_add_wallet_to_list_with_label(&list_config, &wallet.pubkey(), label("kyc"));
+    assert!(res.is_ok());
+    let ta = context.create_token_account(&wallet);
+    let (wallet_entry, _) = WalletEntry::find_pda(&[0; 16], &list_config, &wallet.pubkey());
+
+    let res =
+        context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(list_config, wallet_entry)]);
+    assert!(res.is_ok());
+}
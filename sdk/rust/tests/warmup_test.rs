@@ -0,0 +1,61 @@
+pub mod program_test;
+use solana_sdk::{clock::Clock, signer::Signer};
+use token_acl_gate_client::{accounts::ListConfig, types::Mode};
+
+use crate::program_test::{ListOptions, TestContext};
+
+const WARMUP_SECS: u64 = 3600;
+
+fn create_warming_up_list(context: &mut TestContext) -> solana_sdk::pubkey::Pubkey {
+    context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            warmup_secs: WARMUP_SECS,
+            ..Default::default()
+        },
+    )
+}
+
+#[tokio::test]
+async fn thaws_any_wallet_during_warmup() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = create_warming_up_list(&mut context);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let account = context.vm.get_account(&list_config).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.warmup_until, now + WARMUP_SECS as i64);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn enforces_list_after_warmup() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = create_warming_up_list(&mut context);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += WARMUP_SECS as i64;
+    context.vm.set_sysvar::<Clock>(&clock);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+
+    let _ = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    context.vm.expire_blockhash();
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
@@ -0,0 +1,74 @@
+pub mod program_test;
+use solana_keypair::Keypair;
+use solana_sdk::{
+    instruction::InstructionError, pubkey::Pubkey, signer::Signer, transaction::TransactionError,
+};
+use token_acl_gate_client::types::Mode;
+
+use crate::program_test::{ListOptions, TestContext, TokenAccountDataBuilder};
+
+fn setup_allowed_wallet(
+    context: &mut TestContext,
+    require_nonzero_balance: bool,
+) -> (Pubkey, Pubkey, Keypair) {
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list_with_config(
+        Mode::Allow,
+        ListOptions {
+            require_nonzero_balance,
+            ..Default::default()
+        },
+    );
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+
+    (list_config, wallet_entry, wallet)
+}
+
+fn can_thaw_with_amount(
+    context: &mut TestContext,
+    list_config: &Pubkey,
+    wallet_entry: &Pubkey,
+    wallet: &Keypair,
+    amount: u64,
+) -> litesvm::types::TransactionResult {
+    let data = TokenAccountDataBuilder::new(&context.token.mint, &wallet.pubkey())
+        .amount(amount)
+        .immutable_owner()
+        .build();
+    let ta = context.set_token_account(data);
+
+    context.can_thaw_permissionless(&wallet.pubkey(), &ta, &[(*list_config, *wallet_entry)])
+}
+
+#[tokio::test]
+async fn denies_zero_balance_when_required() {
+    let mut context = TestContext::new();
+    let (list_config, wallet_entry, wallet) = setup_allowed_wallet(&mut context, true);
+
+    let res = can_thaw_with_amount(&mut context, &list_config, &wallet_entry, &wallet, 0);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(29))
+    );
+}
+
+#[tokio::test]
+async fn allows_nonzero_balance_when_required() {
+    let mut context = TestContext::new();
+    let (list_config, wallet_entry, wallet) = setup_allowed_wallet(&mut context, true);
+
+    let res = can_thaw_with_amount(&mut context, &list_config, &wallet_entry, &wallet, 1);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn allows_zero_balance_by_default() {
+    let mut context = TestContext::new();
+    let (list_config, wallet_entry, wallet) = setup_allowed_wallet(&mut context, false);
+
+    let res = can_thaw_with_amount(&mut context, &list_config, &wallet_entry, &wallet, 0);
+    assert!(res.is_ok());
+}
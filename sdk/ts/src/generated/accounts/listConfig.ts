@@ -13,10 +13,20 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -32,6 +42,7 @@ import {
   type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 import { findListConfigPda, ListConfigSeeds } from '../pdas';
 
@@ -47,6 +58,43 @@ export type ListConfig = {
   seed: Address;
   mode: number;
   walletsCount: bigint;
+  namespace: ReadonlyUint8Array;
+  enrollWindowSecs: bigint;
+  maxEnrollsPerWindow: number;
+  windowStart: bigint;
+  windowEnrolls: number;
+  keyByTokenAccount: boolean;
+  warmupUntil: bigint;
+  tokenProgram: Address;
+  transferHookCheck: number;
+  appealPeriodSecs: bigint;
+  requireNonzeroBalance: boolean;
+  allowedWindowStart: number;
+  allowedWindowEnd: number;
+  requireAuthorityCosign: boolean;
+  allowThreshold: number;
+  allowFreezeAuthorityDelegate: boolean;
+  strictBlockMode: boolean;
+  allowPrecedence: boolean;
+  sunsetAt: bigint;
+  maxWallets: number;
+  allowMultisigOwner: boolean;
+  ownerExtensions: number;
+  merkleRoot: ReadonlyUint8Array;
+  pendingAuthority: Address;
+  updatedAt: bigint;
+  lastActor: Address;
+  paused: boolean;
+  anyList: boolean;
+  meter: boolean;
+  rootVersion: bigint;
+  maxProofDepth: number;
+  denyExpiredEntries: boolean;
+  eoaGraceUntil: bigint;
+  frozen: boolean;
+  manager: Address;
+  maxCuPerEval: number;
+  reserved: ReadonlyUint8Array;
 };
 
 export type ListConfigArgs = {
@@ -54,6 +102,43 @@ export type ListConfigArgs = {
   seed: Address;
   mode: number;
   walletsCount: number | bigint;
+  namespace: ReadonlyUint8Array;
+  enrollWindowSecs: number | bigint;
+  maxEnrollsPerWindow: number;
+  windowStart: number | bigint;
+  windowEnrolls: number;
+  keyByTokenAccount: boolean;
+  warmupUntil: number | bigint;
+  tokenProgram: Address;
+  transferHookCheck: number;
+  appealPeriodSecs: number | bigint;
+  requireNonzeroBalance: boolean;
+  allowedWindowStart: number;
+  allowedWindowEnd: number;
+  requireAuthorityCosign: boolean;
+  allowThreshold: number;
+  allowFreezeAuthorityDelegate: boolean;
+  strictBlockMode: boolean;
+  allowPrecedence: boolean;
+  sunsetAt: number | bigint;
+  maxWallets: number;
+  allowMultisigOwner: boolean;
+  ownerExtensions: number;
+  merkleRoot: ReadonlyUint8Array;
+  pendingAuthority: Address;
+  updatedAt: number | bigint;
+  lastActor: Address;
+  paused: boolean;
+  anyList: boolean;
+  meter: boolean;
+  rootVersion: number | bigint;
+  maxProofDepth: number;
+  denyExpiredEntries: boolean;
+  eoaGraceUntil: number | bigint;
+  frozen: boolean;
+  manager: Address;
+  maxCuPerEval: number;
+  reserved: ReadonlyUint8Array;
 };
 
 export function getListConfigEncoder(): FixedSizeEncoder<ListConfigArgs> {
@@ -64,6 +149,43 @@ export function getListConfigEncoder(): FixedSizeEncoder<ListConfigArgs> {
       ['seed', getAddressEncoder()],
       ['mode', getU8Encoder()],
       ['walletsCount', getU64Encoder()],
+      ['namespace', fixEncoderSize(getBytesEncoder(), 16)],
+      ['enrollWindowSecs', getU64Encoder()],
+      ['maxEnrollsPerWindow', getU32Encoder()],
+      ['windowStart', getI64Encoder()],
+      ['windowEnrolls', getU32Encoder()],
+      ['keyByTokenAccount', getBooleanEncoder()],
+      ['warmupUntil', getI64Encoder()],
+      ['tokenProgram', getAddressEncoder()],
+      ['transferHookCheck', getU8Encoder()],
+      ['appealPeriodSecs', getU64Encoder()],
+      ['requireNonzeroBalance', getBooleanEncoder()],
+      ['allowedWindowStart', getU32Encoder()],
+      ['allowedWindowEnd', getU32Encoder()],
+      ['requireAuthorityCosign', getBooleanEncoder()],
+      ['allowThreshold', getU8Encoder()],
+      ['allowFreezeAuthorityDelegate', getBooleanEncoder()],
+      ['strictBlockMode', getBooleanEncoder()],
+      ['allowPrecedence', getBooleanEncoder()],
+      ['sunsetAt', getI64Encoder()],
+      ['maxWallets', getU32Encoder()],
+      ['allowMultisigOwner', getBooleanEncoder()],
+      ['ownerExtensions', getU8Encoder()],
+      ['merkleRoot', fixEncoderSize(getBytesEncoder(), 32)],
+      ['pendingAuthority', getAddressEncoder()],
+      ['updatedAt', getI64Encoder()],
+      ['lastActor', getAddressEncoder()],
+      ['paused', getBooleanEncoder()],
+      ['anyList', getBooleanEncoder()],
+      ['meter', getBooleanEncoder()],
+      ['rootVersion', getU64Encoder()],
+      ['maxProofDepth', getU8Encoder()],
+      ['denyExpiredEntries', getBooleanEncoder()],
+      ['eoaGraceUntil', getI64Encoder()],
+      ['frozen', getBooleanEncoder()],
+      ['manager', getAddressEncoder()],
+      ['maxCuPerEval', getU32Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 28)],
     ]),
     (value) => ({ ...value, discriminator: LIST_CONFIG_DISCRIMINATOR })
   );
@@ -76,6 +198,43 @@ export function getListConfigDecoder(): FixedSizeDecoder<ListConfig> {
     ['seed', getAddressDecoder()],
     ['mode', getU8Decoder()],
     ['walletsCount', getU64Decoder()],
+    ['namespace', fixDecoderSize(getBytesDecoder(), 16)],
+    ['enrollWindowSecs', getU64Decoder()],
+    ['maxEnrollsPerWindow', getU32Decoder()],
+    ['windowStart', getI64Decoder()],
+    ['windowEnrolls', getU32Decoder()],
+    ['keyByTokenAccount', getBooleanDecoder()],
+    ['warmupUntil', getI64Decoder()],
+    ['tokenProgram', getAddressDecoder()],
+    ['transferHookCheck', getU8Decoder()],
+    ['appealPeriodSecs', getU64Decoder()],
+    ['requireNonzeroBalance', getBooleanDecoder()],
+    ['allowedWindowStart', getU32Decoder()],
+    ['allowedWindowEnd', getU32Decoder()],
+    ['requireAuthorityCosign', getBooleanDecoder()],
+    ['allowThreshold', getU8Decoder()],
+    ['allowFreezeAuthorityDelegate', getBooleanDecoder()],
+    ['strictBlockMode', getBooleanDecoder()],
+    ['allowPrecedence', getBooleanDecoder()],
+    ['sunsetAt', getI64Decoder()],
+    ['maxWallets', getU32Decoder()],
+    ['allowMultisigOwner', getBooleanDecoder()],
+    ['ownerExtensions', getU8Decoder()],
+    ['merkleRoot', fixDecoderSize(getBytesDecoder(), 32)],
+    ['pendingAuthority', getAddressDecoder()],
+    ['updatedAt', getI64Decoder()],
+    ['lastActor', getAddressDecoder()],
+    ['paused', getBooleanDecoder()],
+    ['anyList', getBooleanDecoder()],
+    ['meter', getBooleanDecoder()],
+    ['rootVersion', getU64Decoder()],
+    ['maxProofDepth', getU8Decoder()],
+    ['denyExpiredEntries', getBooleanDecoder()],
+    ['eoaGraceUntil', getI64Decoder()],
+    ['frozen', getBooleanDecoder()],
+    ['manager', getAddressDecoder()],
+    ['maxCuPerEval', getU32Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 28)],
   ]);
 }
 
@@ -140,7 +299,7 @@ export async function fetchAllMaybeListConfig(
 }
 
 export function getListConfigSize(): number {
-  return 74;
+  return 382;
 }
 
 export async function fetchListConfigFromSeeds(
@@ -13,8 +13,14 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -30,6 +36,7 @@ import {
   type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 import { findWalletEntryPda, WalletEntrySeeds } from '../pdas';
 
@@ -43,9 +50,24 @@ export type WalletEntry = {
   discriminator: number;
   walletAddress: Address;
   listConfig: Address;
+  namespace: ReadonlyUint8Array;
+  appealDeadline: bigint;
+  expiresAt: bigint;
+  label: ReadonlyUint8Array;
+  createdAt: bigint;
+  addedBy: Address;
 };
 
-export type WalletEntryArgs = { walletAddress: Address; listConfig: Address };
+export type WalletEntryArgs = {
+  walletAddress: Address;
+  listConfig: Address;
+  namespace: ReadonlyUint8Array;
+  appealDeadline: number | bigint;
+  expiresAt: number | bigint;
+  label: ReadonlyUint8Array;
+  createdAt: number | bigint;
+  addedBy: Address;
+};
 
 export function getWalletEntryEncoder(): FixedSizeEncoder<WalletEntryArgs> {
   return transformEncoder(
@@ -53,6 +75,12 @@ export function getWalletEntryEncoder(): FixedSizeEncoder<WalletEntryArgs> {
       ['discriminator', getU8Encoder()],
       ['walletAddress', getAddressEncoder()],
       ['listConfig', getAddressEncoder()],
+      ['namespace', fixEncoderSize(getBytesEncoder(), 16)],
+      ['appealDeadline', getI64Encoder()],
+      ['expiresAt', getI64Encoder()],
+      ['label', fixEncoderSize(getBytesEncoder(), 32)],
+      ['createdAt', getI64Encoder()],
+      ['addedBy', getAddressEncoder()],
     ]),
     (value) => ({ ...value, discriminator: WALLET_ENTRY_DISCRIMINATOR })
   );
@@ -63,6 +91,12 @@ export function getWalletEntryDecoder(): FixedSizeDecoder<WalletEntry> {
     ['discriminator', getU8Decoder()],
     ['walletAddress', getAddressDecoder()],
     ['listConfig', getAddressDecoder()],
+    ['namespace', fixDecoderSize(getBytesDecoder(), 16)],
+    ['appealDeadline', getI64Decoder()],
+    ['expiresAt', getI64Decoder()],
+    ['label', fixDecoderSize(getBytesDecoder(), 32)],
+    ['createdAt', getI64Decoder()],
+    ['addedBy', getAddressDecoder()],
   ]);
 }
 
@@ -127,7 +161,7 @@ export async function fetchAllMaybeWalletEntry(
 }
 
 export function getWalletEntrySize(): number {
-  return 65;
+  return 169;
 }
 
 export async function fetchWalletEntryFromSeeds(
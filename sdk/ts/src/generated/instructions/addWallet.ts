@@ -8,6 +8,14 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -29,13 +37,8 @@ import {
   type WritableAccount,
   type WritableSignerAccount,
 } from '@solana/kit';
-import { findWalletEntryPda } from '../pdas';
 import { TOKEN_ACL_GATE_PROGRAM_PROGRAM_ADDRESS } from '../programs';
-import {
-  expectAddress,
-  getAccountMetaFactory,
-  type ResolvedAccount,
-} from '../shared';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
 export const ADD_WALLET_DISCRIMINATOR = 2;
 
@@ -82,19 +85,45 @@ export type AddWalletInstruction<
     ]
   >;
 
-export type AddWalletInstructionData = { discriminator: number };
+export type AddWalletInstructionData = {
+  discriminator: number;
+  version: number;
+  expiresAt: bigint;
+  label: ReadonlyUint8Array;
+  idempotent: boolean;
+};
 
-export type AddWalletInstructionDataArgs = {};
+export type AddWalletInstructionDataArgs = {
+  expiresAt: number | bigint;
+  label: ReadonlyUint8Array;
+  idempotent: boolean;
+};
 
 export function getAddWalletInstructionDataEncoder(): FixedSizeEncoder<AddWalletInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
-    (value) => ({ ...value, discriminator: ADD_WALLET_DISCRIMINATOR })
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['version', getU8Encoder()],
+      ['expiresAt', getI64Encoder()],
+      ['label', fixEncoderSize(getBytesEncoder(), 32)],
+      ['idempotent', getBooleanEncoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADD_WALLET_DISCRIMINATOR,
+      version: 3,
+    })
   );
 }
 
 export function getAddWalletInstructionDataDecoder(): FixedSizeDecoder<AddWalletInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['version', getU8Decoder()],
+    ['expiresAt', getI64Decoder()],
+    ['label', fixDecoderSize(getBytesDecoder(), 32)],
+    ['idempotent', getBooleanDecoder()],
+  ]);
 }
 
 export function getAddWalletInstructionDataCodec(): FixedSizeCodec<
@@ -107,105 +136,6 @@ export function getAddWalletInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
-export type AddWalletAsyncInput<
-  TAccountAuthority extends string = string,
-  TAccountPayer extends string = string,
-  TAccountListConfig extends string = string,
-  TAccountWallet extends string = string,
-  TAccountWalletEntry extends string = string,
-  TAccountSystemProgram extends string = string,
-> = {
-  authority: TransactionSigner<TAccountAuthority>;
-  payer: TransactionSigner<TAccountPayer>;
-  listConfig: Address<TAccountListConfig>;
-  wallet: Address<TAccountWallet>;
-  walletEntry?: Address<TAccountWalletEntry>;
-  systemProgram?: Address<TAccountSystemProgram>;
-};
-
-export async function getAddWalletInstructionAsync<
-  TAccountAuthority extends string,
-  TAccountPayer extends string,
-  TAccountListConfig extends string,
-  TAccountWallet extends string,
-  TAccountWalletEntry extends string,
-  TAccountSystemProgram extends string,
-  TProgramAddress extends
-    Address = typeof TOKEN_ACL_GATE_PROGRAM_PROGRAM_ADDRESS,
->(
-  input: AddWalletAsyncInput<
-    TAccountAuthority,
-    TAccountPayer,
-    TAccountListConfig,
-    TAccountWallet,
-    TAccountWalletEntry,
-    TAccountSystemProgram
-  >,
-  config?: { programAddress?: TProgramAddress }
-): Promise<
-  AddWalletInstruction<
-    TProgramAddress,
-    TAccountAuthority,
-    TAccountPayer,
-    TAccountListConfig,
-    TAccountWallet,
-    TAccountWalletEntry,
-    TAccountSystemProgram
-  >
-> {
-  // Program address.
-  const programAddress =
-    config?.programAddress ?? TOKEN_ACL_GATE_PROGRAM_PROGRAM_ADDRESS;
-
-  // Original accounts.
-  const originalAccounts = {
-    authority: { value: input.authority ?? null, isWritable: false },
-    payer: { value: input.payer ?? null, isWritable: true },
-    listConfig: { value: input.listConfig ?? null, isWritable: true },
-    wallet: { value: input.wallet ?? null, isWritable: false },
-    walletEntry: { value: input.walletEntry ?? null, isWritable: true },
-    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
-  };
-  const accounts = originalAccounts as Record<
-    keyof typeof originalAccounts,
-    ResolvedAccount
-  >;
-
-  // Resolve default values.
-  if (!accounts.walletEntry.value) {
-    accounts.walletEntry.value = await findWalletEntryPda({
-      listConfig: expectAddress(accounts.listConfig.value),
-      wallet: expectAddress(accounts.wallet.value),
-    });
-  }
-  if (!accounts.systemProgram.value) {
-    accounts.systemProgram.value =
-      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
-  }
-
-  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-  return Object.freeze({
-    accounts: [
-      getAccountMeta(accounts.authority),
-      getAccountMeta(accounts.payer),
-      getAccountMeta(accounts.listConfig),
-      getAccountMeta(accounts.wallet),
-      getAccountMeta(accounts.walletEntry),
-      getAccountMeta(accounts.systemProgram),
-    ],
-    data: getAddWalletInstructionDataEncoder().encode({}),
-    programAddress,
-  } as AddWalletInstruction<
-    TProgramAddress,
-    TAccountAuthority,
-    TAccountPayer,
-    TAccountListConfig,
-    TAccountWallet,
-    TAccountWalletEntry,
-    TAccountSystemProgram
-  >);
-}
-
 export type AddWalletInput<
   TAccountAuthority extends string = string,
   TAccountPayer extends string = string,
@@ -220,6 +150,9 @@ export type AddWalletInput<
   wallet: Address<TAccountWallet>;
   walletEntry: Address<TAccountWalletEntry>;
   systemProgram?: Address<TAccountSystemProgram>;
+  expiresAt: AddWalletInstructionDataArgs['expiresAt'];
+  label: AddWalletInstructionDataArgs['label'];
+  idempotent: AddWalletInstructionDataArgs['idempotent'];
 };
 
 export function getAddWalletInstruction<
@@ -268,6 +201,9 @@ export function getAddWalletInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -284,7 +220,9 @@ export function getAddWalletInstruction<
       getAccountMeta(accounts.walletEntry),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getAddWalletInstructionDataEncoder().encode({}),
+    data: getAddWalletInstructionDataEncoder().encode(
+      args as AddWalletInstructionDataArgs
+    ),
     programAddress,
   } as AddWalletInstruction<
     TProgramAddress,
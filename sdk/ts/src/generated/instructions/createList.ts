@@ -8,10 +8,22 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -85,28 +97,118 @@ export type CreateListInstruction<
 
 export type CreateListInstructionData = {
   discriminator: number;
+  version: number;
   mode: Mode;
   seed: Address;
+  namespace: ReadonlyUint8Array;
+  enrollWindowSecs: bigint;
+  maxEnrollsPerWindow: number;
+  keyByTokenAccount: boolean;
+  warmupSecs: bigint;
+  tokenProgram: Address;
+  transferHookCheck: number;
+  appealPeriodSecs: bigint;
+  requireNonzeroBalance: boolean;
+  allowedWindowStart: number;
+  allowedWindowEnd: number;
+  requireAuthorityCosign: boolean;
+  allowThreshold: number;
+  allowFreezeAuthorityDelegate: boolean;
+  strictBlockMode: boolean;
+  allowPrecedence: boolean;
+  sunsetAt: bigint;
+  maxWallets: number;
+  allowMultisigOwner: boolean;
+  ownerExtensions: number;
 };
 
-export type CreateListInstructionDataArgs = { mode: ModeArgs; seed: Address };
+export type CreateListInstructionDataArgs = {
+  mode: ModeArgs;
+  seed: Address;
+  namespace: ReadonlyUint8Array;
+  enrollWindowSecs: number | bigint;
+  maxEnrollsPerWindow: number;
+  keyByTokenAccount: boolean;
+  warmupSecs: number | bigint;
+  tokenProgram: Address;
+  transferHookCheck: number;
+  appealPeriodSecs: number | bigint;
+  requireNonzeroBalance: boolean;
+  allowedWindowStart: number;
+  allowedWindowEnd: number;
+  requireAuthorityCosign: boolean;
+  allowThreshold: number;
+  allowFreezeAuthorityDelegate: boolean;
+  strictBlockMode: boolean;
+  allowPrecedence: boolean;
+  sunsetAt: number | bigint;
+  maxWallets: number;
+  allowMultisigOwner: boolean;
+  ownerExtensions: number;
+};
 
 export function getCreateListInstructionDataEncoder(): FixedSizeEncoder<CreateListInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
+      ['version', getU8Encoder()],
       ['mode', getModeEncoder()],
       ['seed', getAddressEncoder()],
+      ['namespace', fixEncoderSize(getBytesEncoder(), 16)],
+      ['enrollWindowSecs', getU64Encoder()],
+      ['maxEnrollsPerWindow', getU32Encoder()],
+      ['keyByTokenAccount', getBooleanEncoder()],
+      ['warmupSecs', getU64Encoder()],
+      ['tokenProgram', getAddressEncoder()],
+      ['transferHookCheck', getU8Encoder()],
+      ['appealPeriodSecs', getU64Encoder()],
+      ['requireNonzeroBalance', getBooleanEncoder()],
+      ['allowedWindowStart', getU32Encoder()],
+      ['allowedWindowEnd', getU32Encoder()],
+      ['requireAuthorityCosign', getBooleanEncoder()],
+      ['allowThreshold', getU8Encoder()],
+      ['allowFreezeAuthorityDelegate', getBooleanEncoder()],
+      ['strictBlockMode', getBooleanEncoder()],
+      ['allowPrecedence', getBooleanEncoder()],
+      ['sunsetAt', getI64Encoder()],
+      ['maxWallets', getU32Encoder()],
+      ['allowMultisigOwner', getBooleanEncoder()],
+      ['ownerExtensions', getU8Encoder()],
     ]),
-    (value) => ({ ...value, discriminator: CREATE_LIST_DISCRIMINATOR })
+    (value) => ({
+      ...value,
+      discriminator: CREATE_LIST_DISCRIMINATOR,
+      version: 1,
+    })
   );
 }
 
 export function getCreateListInstructionDataDecoder(): FixedSizeDecoder<CreateListInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['version', getU8Decoder()],
     ['mode', getModeDecoder()],
     ['seed', getAddressDecoder()],
+    ['namespace', fixDecoderSize(getBytesDecoder(), 16)],
+    ['enrollWindowSecs', getU64Decoder()],
+    ['maxEnrollsPerWindow', getU32Decoder()],
+    ['keyByTokenAccount', getBooleanDecoder()],
+    ['warmupSecs', getU64Decoder()],
+    ['tokenProgram', getAddressDecoder()],
+    ['transferHookCheck', getU8Decoder()],
+    ['appealPeriodSecs', getU64Decoder()],
+    ['requireNonzeroBalance', getBooleanDecoder()],
+    ['allowedWindowStart', getU32Decoder()],
+    ['allowedWindowEnd', getU32Decoder()],
+    ['requireAuthorityCosign', getBooleanDecoder()],
+    ['allowThreshold', getU8Decoder()],
+    ['allowFreezeAuthorityDelegate', getBooleanDecoder()],
+    ['strictBlockMode', getBooleanDecoder()],
+    ['allowPrecedence', getBooleanDecoder()],
+    ['sunsetAt', getI64Decoder()],
+    ['maxWallets', getU32Decoder()],
+    ['allowMultisigOwner', getBooleanDecoder()],
+    ['ownerExtensions', getU8Decoder()],
   ]);
 }
 
@@ -132,6 +234,26 @@ export type CreateListAsyncInput<
   systemProgram?: Address<TAccountSystemProgram>;
   mode: CreateListInstructionDataArgs['mode'];
   seed: CreateListInstructionDataArgs['seed'];
+  namespace: CreateListInstructionDataArgs['namespace'];
+  enrollWindowSecs: CreateListInstructionDataArgs['enrollWindowSecs'];
+  maxEnrollsPerWindow: CreateListInstructionDataArgs['maxEnrollsPerWindow'];
+  keyByTokenAccount: CreateListInstructionDataArgs['keyByTokenAccount'];
+  warmupSecs: CreateListInstructionDataArgs['warmupSecs'];
+  tokenProgram: CreateListInstructionDataArgs['tokenProgram'];
+  transferHookCheck: CreateListInstructionDataArgs['transferHookCheck'];
+  appealPeriodSecs: CreateListInstructionDataArgs['appealPeriodSecs'];
+  requireNonzeroBalance: CreateListInstructionDataArgs['requireNonzeroBalance'];
+  allowedWindowStart: CreateListInstructionDataArgs['allowedWindowStart'];
+  allowedWindowEnd: CreateListInstructionDataArgs['allowedWindowEnd'];
+  requireAuthorityCosign: CreateListInstructionDataArgs['requireAuthorityCosign'];
+  allowThreshold: CreateListInstructionDataArgs['allowThreshold'];
+  allowFreezeAuthorityDelegate: CreateListInstructionDataArgs['allowFreezeAuthorityDelegate'];
+  strictBlockMode: CreateListInstructionDataArgs['strictBlockMode'];
+  allowPrecedence: CreateListInstructionDataArgs['allowPrecedence'];
+  sunsetAt: CreateListInstructionDataArgs['sunsetAt'];
+  maxWallets: CreateListInstructionDataArgs['maxWallets'];
+  allowMultisigOwner: CreateListInstructionDataArgs['allowMultisigOwner'];
+  ownerExtensions: CreateListInstructionDataArgs['ownerExtensions'];
 };
 
 export async function getCreateListInstructionAsync<
@@ -222,6 +344,26 @@ export type CreateListInput<
   systemProgram?: Address<TAccountSystemProgram>;
   mode: CreateListInstructionDataArgs['mode'];
   seed: CreateListInstructionDataArgs['seed'];
+  namespace: CreateListInstructionDataArgs['namespace'];
+  enrollWindowSecs: CreateListInstructionDataArgs['enrollWindowSecs'];
+  maxEnrollsPerWindow: CreateListInstructionDataArgs['maxEnrollsPerWindow'];
+  keyByTokenAccount: CreateListInstructionDataArgs['keyByTokenAccount'];
+  warmupSecs: CreateListInstructionDataArgs['warmupSecs'];
+  tokenProgram: CreateListInstructionDataArgs['tokenProgram'];
+  transferHookCheck: CreateListInstructionDataArgs['transferHookCheck'];
+  appealPeriodSecs: CreateListInstructionDataArgs['appealPeriodSecs'];
+  requireNonzeroBalance: CreateListInstructionDataArgs['requireNonzeroBalance'];
+  allowedWindowStart: CreateListInstructionDataArgs['allowedWindowStart'];
+  allowedWindowEnd: CreateListInstructionDataArgs['allowedWindowEnd'];
+  requireAuthorityCosign: CreateListInstructionDataArgs['requireAuthorityCosign'];
+  allowThreshold: CreateListInstructionDataArgs['allowThreshold'];
+  allowFreezeAuthorityDelegate: CreateListInstructionDataArgs['allowFreezeAuthorityDelegate'];
+  strictBlockMode: CreateListInstructionDataArgs['strictBlockMode'];
+  allowPrecedence: CreateListInstructionDataArgs['allowPrecedence'];
+  sunsetAt: CreateListInstructionDataArgs['sunsetAt'];
+  maxWallets: CreateListInstructionDataArgs['maxWallets'];
+  allowMultisigOwner: CreateListInstructionDataArgs['allowMultisigOwner'];
+  ownerExtensions: CreateListInstructionDataArgs['ownerExtensions'];
 };
 
 export function getCreateListInstruction<
@@ -6,8 +6,35 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptAuthorityTransfer';
 export * from './addWallet';
+export * from './addWalletBatch';
+export * from './archivePolicy';
+export * from './auditEntries';
+export * from './closeMerkleProof';
+export * from './compactAuthoritySet';
+export * from './configurePolicy';
+export * from './createAuditLog';
 export * from './createList';
 export * from './deleteList';
+export * from './diagnoseThaw';
+export * from './drainQueue';
+export * from './enqueueReport';
+export * from './getMembership';
+export * from './getMembershipBatch';
+export * from './migrateListConfig';
+export * from './migrateWalletEntry';
+export * from './promoteToAllowAllEoas';
+export * from './proposeAuthorityTransfer';
+export * from './reaffirmBlock';
 export * from './removeWallet';
+export * from './setDelegate';
+export * from './setFrozen';
+export * from './setManager';
+export * from './setPaused';
 export * from './setupExtraMetas';
+export * from './setupFreezeExtraMetas';
+export * from './submitMerkleProof';
+export * from './updateMerkleRoot';
+export * from './updateMode';
+export * from './verifyMerkleMembership';
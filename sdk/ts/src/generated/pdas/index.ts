@@ -6,5 +6,11 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './auditLog';
+export * from './authoritySet';
 export * from './listConfig';
+export * from './listRegistry';
+export * from './merkleProof';
+export * from './policyArchive';
+export * from './reviewQueue';
 export * from './walletEntry';
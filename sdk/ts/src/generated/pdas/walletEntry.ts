@@ -7,14 +7,18 @@
  */
 
 import {
+  fixEncoderSize,
   getAddressEncoder,
+  getBytesEncoder,
   getProgramDerivedAddress,
   getUtf8Encoder,
   type Address,
   type ProgramDerivedAddress,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 
 export type WalletEntrySeeds = {
+  namespace: ReadonlyUint8Array;
   listConfig: Address;
   wallet: Address;
 };
@@ -30,6 +34,7 @@ export async function findWalletEntryPda(
     programAddress,
     seeds: [
       getUtf8Encoder().encode('wallet_entry'),
+      fixEncoderSize(getBytesEncoder(), 16).encode(seeds.namespace),
       getAddressEncoder().encode(seeds.listConfig),
       getAddressEncoder().encode(seeds.wallet),
     ],
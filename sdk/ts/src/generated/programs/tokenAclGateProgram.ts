@@ -13,11 +13,38 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptAuthorityTransferInstruction,
   type ParsedAddWalletInstruction,
+  type ParsedAddWalletBatchInstruction,
+  type ParsedArchivePolicyInstruction,
+  type ParsedAuditEntriesInstruction,
+  type ParsedCloseMerkleProofInstruction,
+  type ParsedCompactAuthoritySetInstruction,
+  type ParsedConfigurePolicyInstruction,
+  type ParsedCreateAuditLogInstruction,
   type ParsedCreateListInstruction,
   type ParsedDeleteListInstruction,
+  type ParsedDiagnoseThawInstruction,
+  type ParsedDrainQueueInstruction,
+  type ParsedEnqueueReportInstruction,
+  type ParsedGetMembershipInstruction,
+  type ParsedGetMembershipBatchInstruction,
+  type ParsedMigrateListConfigInstruction,
+  type ParsedMigrateWalletEntryInstruction,
+  type ParsedPromoteToAllowAllEoasInstruction,
+  type ParsedProposeAuthorityTransferInstruction,
+  type ParsedReaffirmBlockInstruction,
   type ParsedRemoveWalletInstruction,
+  type ParsedSetDelegateInstruction,
+  type ParsedSetFrozenInstruction,
+  type ParsedSetManagerInstruction,
+  type ParsedSetPausedInstruction,
   type ParsedSetupExtraMetasInstruction,
+  type ParsedSetupFreezeExtraMetasInstruction,
+  type ParsedSubmitMerkleProofInstruction,
+  type ParsedUpdateMerkleRootInstruction,
+  type ParsedUpdateModeInstruction,
+  type ParsedVerifyMerkleMembershipInstruction,
 } from '../instructions';
 
 export const TOKEN_ACL_GATE_PROGRAM_PROGRAM_ADDRESS =
@@ -26,6 +53,12 @@ export const TOKEN_ACL_GATE_PROGRAM_PROGRAM_ADDRESS =
 export enum TokenAclGateProgramAccount {
   ListConfig,
   WalletEntry,
+  ReviewQueue,
+  ListRegistry,
+  AuthoritySet,
+  AuditLog,
+  PolicyArchive,
+  MerkleProof,
 }
 
 export function identifyTokenAclGateProgramAccount(
@@ -38,6 +71,24 @@ export function identifyTokenAclGateProgramAccount(
   if (containsBytes(data, getU8Encoder().encode(2), 0)) {
     return TokenAclGateProgramAccount.WalletEntry;
   }
+  if (containsBytes(data, getU8Encoder().encode(3), 0)) {
+    return TokenAclGateProgramAccount.ReviewQueue;
+  }
+  if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+    return TokenAclGateProgramAccount.ListRegistry;
+  }
+  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+    return TokenAclGateProgramAccount.AuthoritySet;
+  }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return TokenAclGateProgramAccount.AuditLog;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return TokenAclGateProgramAccount.PolicyArchive;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return TokenAclGateProgramAccount.MerkleProof;
+  }
   throw new Error(
     'The provided account could not be identified as a token-acl-gate-program account.'
   );
@@ -49,6 +100,33 @@ export enum TokenAclGateProgramInstruction {
   RemoveWallet,
   SetupExtraMetas,
   DeleteList,
+  EnqueueReport,
+  DrainQueue,
+  ReaffirmBlock,
+  SetDelegate,
+  DiagnoseThaw,
+  CreateAuditLog,
+  AuditEntries,
+  ConfigurePolicy,
+  AddWalletBatch,
+  ProposeAuthorityTransfer,
+  AcceptAuthorityTransfer,
+  PromoteToAllowAllEoas,
+  UpdateMode,
+  CompactAuthoritySet,
+  UpdateMerkleRoot,
+  GetMembership,
+  ArchivePolicy,
+  SetPaused,
+  GetMembershipBatch,
+  MigrateListConfig,
+  MigrateWalletEntry,
+  SubmitMerkleProof,
+  VerifyMerkleMembership,
+  SetFrozen,
+  SetManager,
+  CloseMerkleProof,
+  SetupFreezeExtraMetas,
 }
 
 export function identifyTokenAclGateProgramInstruction(
@@ -70,6 +148,87 @@ export function identifyTokenAclGateProgramInstruction(
   if (containsBytes(data, getU8Encoder().encode(5), 0)) {
     return TokenAclGateProgramInstruction.DeleteList;
   }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return TokenAclGateProgramInstruction.EnqueueReport;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return TokenAclGateProgramInstruction.DrainQueue;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return TokenAclGateProgramInstruction.ReaffirmBlock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return TokenAclGateProgramInstruction.SetDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return TokenAclGateProgramInstruction.DiagnoseThaw;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return TokenAclGateProgramInstruction.CreateAuditLog;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return TokenAclGateProgramInstruction.AuditEntries;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return TokenAclGateProgramInstruction.ConfigurePolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return TokenAclGateProgramInstruction.AddWalletBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return TokenAclGateProgramInstruction.ProposeAuthorityTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return TokenAclGateProgramInstruction.AcceptAuthorityTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return TokenAclGateProgramInstruction.PromoteToAllowAllEoas;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return TokenAclGateProgramInstruction.UpdateMode;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return TokenAclGateProgramInstruction.CompactAuthoritySet;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return TokenAclGateProgramInstruction.UpdateMerkleRoot;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return TokenAclGateProgramInstruction.GetMembership;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return TokenAclGateProgramInstruction.ArchivePolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return TokenAclGateProgramInstruction.SetPaused;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return TokenAclGateProgramInstruction.GetMembershipBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return TokenAclGateProgramInstruction.MigrateListConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return TokenAclGateProgramInstruction.MigrateWalletEntry;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return TokenAclGateProgramInstruction.SubmitMerkleProof;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return TokenAclGateProgramInstruction.VerifyMerkleMembership;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return TokenAclGateProgramInstruction.SetFrozen;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return TokenAclGateProgramInstruction.SetManager;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return TokenAclGateProgramInstruction.CloseMerkleProof;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return TokenAclGateProgramInstruction.SetupFreezeExtraMetas;
+  }
   throw new Error(
     'The provided instruction could not be identified as a token-acl-gate-program instruction.'
   );
@@ -92,4 +251,85 @@ export type ParsedTokenAclGateProgramInstruction<
     } & ParsedSetupExtraMetasInstruction<TProgram>)
   | ({
       instructionType: TokenAclGateProgramInstruction.DeleteList;
-    } & ParsedDeleteListInstruction<TProgram>);
+    } & ParsedDeleteListInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.EnqueueReport;
+    } & ParsedEnqueueReportInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.DrainQueue;
+    } & ParsedDrainQueueInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.ReaffirmBlock;
+    } & ParsedReaffirmBlockInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.SetDelegate;
+    } & ParsedSetDelegateInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.DiagnoseThaw;
+    } & ParsedDiagnoseThawInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.CreateAuditLog;
+    } & ParsedCreateAuditLogInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.AuditEntries;
+    } & ParsedAuditEntriesInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.ConfigurePolicy;
+    } & ParsedConfigurePolicyInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.AddWalletBatch;
+    } & ParsedAddWalletBatchInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.ProposeAuthorityTransfer;
+    } & ParsedProposeAuthorityTransferInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.AcceptAuthorityTransfer;
+    } & ParsedAcceptAuthorityTransferInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.PromoteToAllowAllEoas;
+    } & ParsedPromoteToAllowAllEoasInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.UpdateMode;
+    } & ParsedUpdateModeInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.CompactAuthoritySet;
+    } & ParsedCompactAuthoritySetInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.UpdateMerkleRoot;
+    } & ParsedUpdateMerkleRootInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.GetMembership;
+    } & ParsedGetMembershipInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.ArchivePolicy;
+    } & ParsedArchivePolicyInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.SetPaused;
+    } & ParsedSetPausedInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.GetMembershipBatch;
+    } & ParsedGetMembershipBatchInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.MigrateListConfig;
+    } & ParsedMigrateListConfigInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.MigrateWalletEntry;
+    } & ParsedMigrateWalletEntryInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.SubmitMerkleProof;
+    } & ParsedSubmitMerkleProofInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.VerifyMerkleMembership;
+    } & ParsedVerifyMerkleMembershipInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.SetFrozen;
+    } & ParsedSetFrozenInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.SetManager;
+    } & ParsedSetManagerInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.CloseMerkleProof;
+    } & ParsedCloseMerkleProofInstruction<TProgram>)
+  | ({
+      instructionType: TokenAclGateProgramInstruction.SetupFreezeExtraMetas;
+    } & ParsedSetupFreezeExtraMetasInstruction<TProgram>);
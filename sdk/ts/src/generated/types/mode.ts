@@ -19,6 +19,8 @@ export enum Mode {
   Allow,
   AllowAllEoas,
   Block,
+  AllowAllPdas,
+  MerkleAllow,
 }
 
 export type ModeArgs = Mode;